@@ -0,0 +1,532 @@
+use crate::store::{ApiError, Store};
+use async_trait::async_trait;
+use std::sync::{Mutex, MutexGuard};
+use togglrust::api::TogglApi;
+use togglrust::client::Error;
+use togglrust::endpoints;
+use togglrust::models::{ExportFormat, ReportFilters, ReportKind};
+use tokio::io::AsyncWrite;
+
+impl From<ApiError> for Error {
+    fn from(err: ApiError) -> Self {
+        Error::Api {
+            status: err.status,
+            message: err.message,
+        }
+    }
+}
+
+/// Endpoints the store has no data for, answered like [`crate::MockServer`] answers their routes
+fn unsupported<T>() -> Result<T, Error> {
+    Err(ApiError::not_found("Not found").into())
+}
+
+/// A [`TogglApi`] that works on a [`Store`] directly, without HTTP in between.
+///
+/// It validates and answers requests exactly like [`crate::MockServer`] does, so the same
+/// seeding works for both. Use the server to test HTTP behaviour and faults, and this for fast
+/// unit tests of code that only depends on the trait. Endpoints the store doesn't model, like
+/// organizations, reports, reminders and webhooks, fail with a 404 like the server's unknown
+/// routes.
+#[derive(Debug, Default)]
+pub struct Fake {
+    store: Mutex<Store>,
+}
+
+impl Fake {
+    pub fn new(store: Store) -> Self {
+        Self {
+            store: Mutex::new(store),
+        }
+    }
+
+    pub fn store(&self) -> MutexGuard<'_, Store> {
+        self.store.lock().unwrap()
+    }
+}
+
+#[async_trait]
+impl TogglApi for Fake {
+    async fn get_me(&self) -> Result<endpoints::get_me::ResponseBody, Error> {
+        Ok(self.store().me())
+    }
+
+    async fn get_logged(&self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    async fn get_orgs(&self) -> Result<endpoints::get_orgs::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn get_current_time_entry(
+        &self,
+    ) -> Result<endpoints::get_current_time_entry::ResponseBody, Error> {
+        let store = self.store();
+        Ok(endpoints::get_current_time_entry::ResponseBody(
+            store.current_time_entry().cloned(),
+        ))
+    }
+
+    async fn get_workspaces(
+        &self,
+        params: endpoints::get_workspaces::RequestBody,
+    ) -> Result<endpoints::get_workspaces::ResponseBody, Error> {
+        let store = self.store();
+        let workspaces = store.list_workspaces(&params).into_iter().cloned();
+        Ok(endpoints::get_workspaces::ResponseBody(
+            workspaces.collect(),
+        ))
+    }
+
+    async fn get_clients(
+        &self,
+        params: endpoints::get_clients::RequestBody,
+    ) -> Result<endpoints::get_clients::ResponseBody, Error> {
+        let store = self.store();
+        let clients = store.list_clients(&params).into_iter().cloned();
+        Ok(endpoints::get_clients::ResponseBody(clients.collect()))
+    }
+
+    async fn get_projects(
+        &self,
+        params: endpoints::get_projects::RequestBody,
+    ) -> Result<endpoints::get_projects::ResponseBody, Error> {
+        let store = self.store();
+        let projects = store.list_projects(&params).into_iter().cloned();
+        Ok(endpoints::get_projects::ResponseBody(projects.collect()))
+    }
+
+    async fn get_tasks(
+        &self,
+        params: endpoints::get_tasks::RequestBody,
+    ) -> Result<endpoints::get_tasks::ResponseBody, Error> {
+        let store = self.store();
+        let tasks = store.list_tasks(&params).into_iter().cloned();
+        Ok(endpoints::get_tasks::ResponseBody(tasks.collect()))
+    }
+
+    async fn get_tags(
+        &self,
+        params: endpoints::get_tags::RequestBody,
+    ) -> Result<endpoints::get_tags::ResponseBody, Error> {
+        let store = self.store();
+        let tags = store.list_tags(&params).into_iter().cloned();
+        Ok(endpoints::get_tags::ResponseBody(tags.collect()))
+    }
+
+    async fn get_time_entries(
+        &self,
+        params: endpoints::get_time_entries::RequestBody,
+    ) -> Result<endpoints::get_time_entries::ResponseBody, Error> {
+        let store = self.store();
+        let entries = store.list_time_entries(&params)?.into_iter().cloned();
+        Ok(endpoints::get_time_entries::ResponseBody(entries.collect()))
+    }
+
+    async fn post_time_entries(
+        &self,
+        params: endpoints::post_time_entries::PathParams,
+        body: endpoints::post_time_entries::RequestBody,
+    ) -> Result<endpoints::post_time_entries::ResponseBody, Error> {
+        let mut store = self.store();
+        let entry = store.create_time_entry(params.workspace_id, body)?;
+        Ok(endpoints::post_time_entries::ResponseBody(Some(
+            entry.clone(),
+        )))
+    }
+
+    async fn put_time_entries(
+        &self,
+        params: endpoints::put_time_entries::PathParams,
+        body: endpoints::put_time_entries::RequestBody,
+    ) -> Result<endpoints::put_time_entries::ResponseBody, Error> {
+        let mut store = self.store();
+        let entry = store.update_time_entry(params.workspace_id, params.time_entry_id, body)?;
+        Ok(endpoints::put_time_entries::ResponseBody(entry.clone()))
+    }
+
+    async fn delete_time_entries(
+        &self,
+        params: endpoints::delete_time_entries::PathParams,
+    ) -> Result<(), Error> {
+        let mut store = self.store();
+        Ok(store.remove_time_entry(params.workspace_id, params.time_entry_id)?)
+    }
+
+    async fn post_clients(
+        &self,
+        params: endpoints::post_clients::PathParams,
+        body: endpoints::post_clients::RequestBody,
+    ) -> Result<endpoints::post_clients::ResponseBody, Error> {
+        let mut store = self.store();
+        let client = store.create_client(params.workspace_id, body)?;
+        Ok(endpoints::post_clients::ResponseBody(client.clone()))
+    }
+
+    async fn post_projects(
+        &self,
+        params: endpoints::post_projects::PathParams,
+        body: endpoints::post_projects::RequestBody,
+    ) -> Result<endpoints::post_projects::ResponseBody, Error> {
+        let mut store = self.store();
+        let project = store.create_project(params.workspace_id, body)?;
+        Ok(endpoints::post_projects::ResponseBody(project.clone()))
+    }
+
+    async fn post_tasks(
+        &self,
+        params: endpoints::post_tasks::PathParams,
+        body: endpoints::post_tasks::RequestBody,
+    ) -> Result<endpoints::post_tasks::ResponseBody, Error> {
+        let mut store = self.store();
+        let task = store.create_task(params.workspace_id, params.project_id, body)?;
+        Ok(endpoints::post_tasks::ResponseBody(task.clone()))
+    }
+
+    async fn post_tags(
+        &self,
+        params: endpoints::post_tags::PathParams,
+        body: endpoints::post_tags::RequestBody,
+    ) -> Result<endpoints::post_tags::ResponseBody, Error> {
+        let mut store = self.store();
+        let tag = store.create_tag(params.workspace_id, body)?;
+        Ok(endpoints::post_tags::ResponseBody(tag.clone()))
+    }
+
+    async fn get_organization_users(
+        &self,
+        _params: endpoints::get_organization_users::PathParams,
+    ) -> Result<endpoints::get_organization_users::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn post_organization_invitation(
+        &self,
+        _params: endpoints::post_organization_invitation::PathParams,
+        _body: endpoints::post_organization_invitation::RequestBody,
+    ) -> Result<endpoints::post_organization_invitation::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn put_organization_users(
+        &self,
+        _params: endpoints::put_organization_users::PathParams,
+        _body: endpoints::put_organization_users::RequestBody,
+    ) -> Result<(), Error> {
+        unsupported()
+    }
+
+    async fn patch_organization_users(
+        &self,
+        _params: endpoints::patch_organization_users::PathParams,
+        _body: endpoints::patch_organization_users::RequestBody,
+    ) -> Result<(), Error> {
+        unsupported()
+    }
+
+    async fn get_workspace_users(
+        &self,
+        _params: endpoints::get_workspace_users::PathParams,
+    ) -> Result<endpoints::get_workspace_users::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn put_workspace_users(
+        &self,
+        _params: endpoints::put_workspace_users::PathParams,
+        _body: endpoints::put_workspace_users::RequestBody,
+    ) -> Result<endpoints::put_workspace_users::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn delete_workspace_users(
+        &self,
+        _params: endpoints::delete_workspace_users::PathParams,
+    ) -> Result<(), Error> {
+        unsupported()
+    }
+
+    async fn get_organization_groups(
+        &self,
+        _params: endpoints::get_organization_groups::PathParams,
+    ) -> Result<endpoints::get_organization_groups::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn post_organization_groups(
+        &self,
+        _params: endpoints::post_organization_groups::PathParams,
+        _body: endpoints::post_organization_groups::RequestBody,
+    ) -> Result<endpoints::post_organization_groups::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn put_organization_groups(
+        &self,
+        _params: endpoints::put_organization_groups::PathParams,
+        _body: endpoints::put_organization_groups::RequestBody,
+    ) -> Result<endpoints::put_organization_groups::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn patch_organization_groups(
+        &self,
+        _params: endpoints::patch_organization_groups::PathParams,
+        _body: endpoints::patch_organization_groups::RequestBody,
+    ) -> Result<endpoints::patch_organization_groups::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn delete_organization_groups(
+        &self,
+        _params: endpoints::delete_organization_groups::PathParams,
+    ) -> Result<(), Error> {
+        unsupported()
+    }
+
+    async fn get_workspace_groups(
+        &self,
+        _params: endpoints::get_workspace_groups::PathParams,
+    ) -> Result<endpoints::get_workspace_groups::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn get_project_users(
+        &self,
+        _params: endpoints::get_project_users::PathParams,
+        _query: endpoints::get_project_users::RequestBody,
+    ) -> Result<endpoints::get_project_users::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn post_project_users(
+        &self,
+        _params: endpoints::post_project_users::PathParams,
+        _body: endpoints::post_project_users::RequestBody,
+    ) -> Result<endpoints::post_project_users::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn put_project_users(
+        &self,
+        _params: endpoints::put_project_users::PathParams,
+        _body: endpoints::put_project_users::RequestBody,
+    ) -> Result<endpoints::put_project_users::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn patch_project_users(
+        &self,
+        _params: endpoints::patch_project_users::PathParams,
+        _body: endpoints::patch_project_users::RequestBody,
+    ) -> Result<endpoints::patch_project_users::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn delete_project_users(
+        &self,
+        _params: endpoints::delete_project_users::PathParams,
+    ) -> Result<(), Error> {
+        unsupported()
+    }
+
+    async fn put_me(
+        &self,
+        _body: endpoints::put_me::RequestBody,
+    ) -> Result<endpoints::put_me::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn put_workspaces(
+        &self,
+        _params: endpoints::put_workspaces::PathParams,
+        _body: endpoints::put_workspaces::RequestBody,
+    ) -> Result<endpoints::put_workspaces::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn get_preferences(&self) -> Result<endpoints::get_preferences::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn post_preferences(
+        &self,
+        _body: endpoints::post_preferences::RequestBody,
+    ) -> Result<(), Error> {
+        unsupported()
+    }
+
+    async fn get_workspace_preferences(
+        &self,
+        _params: endpoints::get_workspace_preferences::PathParams,
+    ) -> Result<endpoints::get_workspace_preferences::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn post_workspace_preferences(
+        &self,
+        _params: endpoints::post_workspace_preferences::PathParams,
+        _body: endpoints::post_workspace_preferences::RequestBody,
+    ) -> Result<(), Error> {
+        unsupported()
+    }
+
+    async fn get_dashboard_all_activity(
+        &self,
+        _params: endpoints::get_dashboard_all_activity::PathParams,
+    ) -> Result<endpoints::get_dashboard_all_activity::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn get_dashboard_most_active(
+        &self,
+        _params: endpoints::get_dashboard_most_active::PathParams,
+    ) -> Result<endpoints::get_dashboard_most_active::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn post_reports_search_time_entries(
+        &self,
+        _params: endpoints::post_reports_search_time_entries::PathParams,
+        _body: endpoints::post_reports_search_time_entries::RequestBody,
+    ) -> Result<endpoints::post_reports_search_time_entries::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn get_saved_reports(
+        &self,
+        _params: endpoints::get_saved_reports::PathParams,
+    ) -> Result<endpoints::get_saved_reports::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn post_saved_reports(
+        &self,
+        _params: endpoints::post_saved_reports::PathParams,
+        _body: endpoints::post_saved_reports::RequestBody,
+    ) -> Result<endpoints::post_saved_reports::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn put_saved_reports(
+        &self,
+        _params: endpoints::put_saved_reports::PathParams,
+        _body: endpoints::put_saved_reports::RequestBody,
+    ) -> Result<endpoints::put_saved_reports::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn get_shared_report(
+        &self,
+        _params: endpoints::get_shared_report::PathParams,
+    ) -> Result<endpoints::get_shared_report::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn post_shared_report(
+        &self,
+        _params: endpoints::post_shared_report::PathParams,
+        _body: endpoints::post_shared_report::RequestBody,
+    ) -> Result<endpoints::post_shared_report::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn export_report(
+        &self,
+        _workspace_id: i64,
+        _report: ReportKind,
+        _format: ExportFormat,
+        _filters: &ReportFilters,
+        _writer: &mut (dyn AsyncWrite + Unpin + Send),
+    ) -> Result<u64, Error> {
+        unsupported()
+    }
+
+    async fn get_track_reminders(
+        &self,
+    ) -> Result<endpoints::get_track_reminders::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn get_workspace_track_reminders(
+        &self,
+        _params: endpoints::get_workspace_track_reminders::PathParams,
+    ) -> Result<endpoints::get_workspace_track_reminders::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn post_track_reminders(
+        &self,
+        _params: endpoints::post_track_reminders::PathParams,
+        _body: endpoints::post_track_reminders::RequestBody,
+    ) -> Result<endpoints::post_track_reminders::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn put_track_reminders(
+        &self,
+        _params: endpoints::put_track_reminders::PathParams,
+        _body: endpoints::put_track_reminders::RequestBody,
+    ) -> Result<endpoints::put_track_reminders::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn delete_track_reminders(
+        &self,
+        _params: endpoints::delete_track_reminders::PathParams,
+    ) -> Result<(), Error> {
+        unsupported()
+    }
+
+    async fn get_webhook_subscriptions(
+        &self,
+        _params: endpoints::get_webhook_subscriptions::PathParams,
+    ) -> Result<endpoints::get_webhook_subscriptions::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn post_webhook_subscriptions(
+        &self,
+        _params: endpoints::post_webhook_subscriptions::PathParams,
+        _body: endpoints::post_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::post_webhook_subscriptions::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn put_webhook_subscriptions(
+        &self,
+        _params: endpoints::put_webhook_subscriptions::PathParams,
+        _body: endpoints::put_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::put_webhook_subscriptions::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn patch_webhook_subscriptions(
+        &self,
+        _params: endpoints::patch_webhook_subscriptions::PathParams,
+        _body: endpoints::patch_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::patch_webhook_subscriptions::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn delete_webhook_subscriptions(
+        &self,
+        _params: endpoints::delete_webhook_subscriptions::PathParams,
+    ) -> Result<endpoints::delete_webhook_subscriptions::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn get_webhook_event_filters(
+        &self,
+    ) -> Result<endpoints::get_webhook_event_filters::ResponseBody, Error> {
+        unsupported()
+    }
+
+    async fn post_webhook_ping(
+        &self,
+        _params: endpoints::post_webhook_ping::PathParams,
+    ) -> Result<(), Error> {
+        unsupported()
+    }
+}
@@ -0,0 +1,159 @@
+//! An in-process fake of the Toggl Track v9 API for testing code built on
+//! [`togglrust::client::Client`].
+//!
+//! Every [`MockServer`] listens on its own local port and keeps its data in memory, so tests can
+//! run in parallel:
+//!
+//! ```no_run
+//! # async fn example() {
+//! let server = togglrust_mock::MockServer::start().await;
+//! let workspace_id = server.store().add_workspace("Acme");
+//! server.store().add_project(workspace_id, "Website");
+//!
+//! let client = server.client();
+//! let projects = client.get_projects(Default::default()).await.unwrap();
+//! assert_eq!(projects.0.len(), 1);
+//! # }
+//! ```
+//!
+//! [`Fake`] serves the same store through [`togglrust::api::TogglApi`] without any HTTP.
+
+mod fake;
+mod routes;
+mod store;
+
+pub use fake::Fake;
+pub use hyper::{Method, StatusCode};
+pub use store::{ApiError, Store, User};
+
+use hyper::service::{make_service_fn, service_fn};
+use hyper::Server;
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use togglrust::client::{Auth, Client};
+use tokio::sync::oneshot;
+
+/// Something to go wrong with a request instead of, or before, handling it
+#[derive(Debug, Clone)]
+pub enum Fault {
+    /// Answer with this status without touching the store. 429s come with a `Retry-After` header.
+    Status(StatusCode),
+
+    /// Wait this long, then handle the request normally
+    Delay(Duration),
+}
+
+/// A request the server received, recorded before faults and authentication are applied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedRequest {
+    pub method: Method,
+    pub path: String,
+    pub query: Option<String>,
+}
+
+pub(crate) struct Shared {
+    pub(crate) store: Mutex<Store>,
+    pub(crate) faults: Mutex<VecDeque<Fault>>,
+    pub(crate) requests: Mutex<Vec<RecordedRequest>>,
+}
+
+/// A running mock server, shut down when dropped
+pub struct MockServer {
+    addr: SocketAddr,
+    shared: Arc<Shared>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl MockServer {
+    /// Starts a server with an empty store on a free local port. Must be called from within a
+    /// tokio runtime, e.g. a `#[tokio::test]`.
+    pub async fn start() -> Self {
+        Self::with_store(Store::default()).await
+    }
+
+    pub async fn with_store(store: Store) -> Self {
+        let shared = Arc::new(Shared {
+            store: Mutex::new(store),
+            faults: Mutex::new(VecDeque::new()),
+            requests: Mutex::new(Vec::new()),
+        });
+        let make_service = {
+            let shared = shared.clone();
+            make_service_fn(move |_| {
+                let shared = shared.clone();
+                async move {
+                    Ok::<_, Infallible>(service_fn(move |request| {
+                        routes::handle(shared.clone(), request)
+                    }))
+                }
+            })
+        };
+        let server = Server::try_bind(&SocketAddr::from(([127, 0, 0, 1], 0)))
+            .expect("Couldn't bind mock server")
+            .serve(make_service);
+        let addr = server.local_addr();
+
+        let (shutdown, shutdown_receiver) = oneshot::channel();
+        tokio::spawn(server.with_graceful_shutdown(async {
+            shutdown_receiver.await.ok();
+        }));
+
+        Self {
+            addr,
+            shared,
+            shutdown: Some(shutdown),
+        }
+    }
+
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Credentials the server accepts, the mock user's email and password
+    pub fn auth(&self) -> Auth {
+        let store = self.store();
+        Auth {
+            user: store.user.email.clone().into(),
+            password: store.user.password.clone().into(),
+        }
+    }
+
+    /// A client pointed at this server and logged in as the mock user
+    pub fn client(&self) -> Client {
+        Client::with_base_url(self.auth(), &self.base_url())
+    }
+
+    /// The server's data, for seeding it before requests and inspecting it afterwards. Don't
+    /// hold on to the guard while awaiting a request, the server needs it to answer.
+    pub fn store(&self) -> MutexGuard<'_, Store> {
+        self.shared.store.lock().unwrap()
+    }
+
+    /// Queues a fault for the next request that doesn't already have one. Each fault applies to
+    /// exactly one request.
+    pub fn inject(&self, fault: Fault) {
+        self.shared.faults.lock().unwrap().push_back(fault);
+    }
+
+    /// Queues the same fault for the next `times` requests
+    pub fn inject_times(&self, fault: Fault, times: usize) {
+        let mut faults = self.shared.faults.lock().unwrap();
+        faults.extend(std::iter::repeat_n(fault, times));
+    }
+
+    /// Every request received so far, in order
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.shared.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            shutdown.send(()).ok();
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use crate::store::{ApiError, Store};
+use crate::{Fault, RecordedRequest, Shared};
+use base64::Engine;
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeZone, Utc};
+use hyper::{Body, Method, Request, Response, StatusCode};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::Arc;
+use togglrust::endpoints::{
+    get_clients, get_projects, get_tags, get_tasks, get_time_entries, get_workspaces,
+};
+
+type Query = HashMap<String, String>;
+
+fn json_response(status: StatusCode, body: &impl Serialize) -> Response<Body> {
+    let mut response = Response::new(Body::from(
+        serde_json::to_vec(body).expect("Couldn't serialize response"),
+    ));
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        hyper::header::CONTENT_TYPE,
+        hyper::header::HeaderValue::from_static("application/json"),
+    );
+    response
+}
+
+fn error_response(error: ApiError) -> Response<Body> {
+    json_response(error.status, &error.message)
+}
+
+fn authorized(store: &Store, request: &Request<Body>) -> bool {
+    let credentials = request
+        .headers()
+        .get(hyper::header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Basic "))
+        .and_then(|encoded| {
+            base64::engine::general_purpose::STANDARD
+                .decode(encoded)
+                .ok()
+        })
+        .and_then(|decoded| String::from_utf8(decoded).ok());
+    match credentials
+        .as_deref()
+        .and_then(|value| value.split_once(':'))
+    {
+        Some((user, password)) => store.user.accepts(user, password),
+        None => false,
+    }
+}
+
+fn parse_since(query: &Query) -> Result<Option<DateTime<Utc>>, ApiError> {
+    query
+        .get("since")
+        .map(|since| {
+            since
+                .parse::<i64>()
+                .ok()
+                .and_then(|timestamp| Utc.timestamp_opt(timestamp, 0).single())
+                .ok_or_else(|| ApiError::bad_request("Invalid since"))
+        })
+        .transpose()
+}
+
+fn parse_bool(query: &Query, key: &str) -> Result<Option<bool>, ApiError> {
+    match query.get(key).map(String::as_str) {
+        None => Ok(None),
+        Some("false") => Ok(Some(false)),
+        Some("true") => Ok(Some(true)),
+        Some(_) => Err(ApiError::bad_request(format!("Invalid {}", key))),
+    }
+}
+
+/// Dates are accepted both as YYYY-MM-DD and as RFC 3339 timestamps
+fn parse_date(query: &Query, key: &str) -> Result<Option<DateTime<Utc>>, ApiError> {
+    query
+        .get(key)
+        .map(|value| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|date| date.with_timezone(&Utc))
+                .or_else(|_| {
+                    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                        .map(|date| date.and_time(NaiveTime::MIN).and_utc())
+                })
+                .map_err(|_| ApiError::bad_request(format!("Invalid {}", key)))
+        })
+        .transpose()
+}
+
+fn route(
+    store: &mut Store,
+    method: &Method,
+    segments: &[&str],
+    query: &Query,
+    body: &[u8],
+) -> Result<Response<Body>, ApiError> {
+    fn id(segment: &str) -> Result<i64, ApiError> {
+        segment
+            .parse()
+            .map_err(|_| ApiError::not_found("Not found"))
+    }
+    fn json<T: serde::de::DeserializeOwned>(body: &[u8]) -> Result<T, ApiError> {
+        serde_json::from_slice(body).map_err(|err| ApiError::bad_request(err.to_string()))
+    }
+
+    fn ok(body: &impl Serialize) -> Result<Response<Body>, ApiError> {
+        Ok(json_response(StatusCode::OK, body))
+    }
+
+    match (method, segments) {
+        (&Method::GET, ["me"]) => ok(&store.me()),
+        (&Method::GET, ["me", "logged"]) => Ok(Response::new(Body::empty())),
+        (&Method::GET, ["me", "workspaces"]) => {
+            let params = get_workspaces::RequestBody {
+                since: parse_since(query)?,
+            };
+            ok(&store.list_workspaces(&params))
+        }
+        (&Method::GET, ["me", "clients"]) => {
+            let params = get_clients::RequestBody {
+                since: parse_since(query)?,
+            };
+            ok(&store.list_clients(&params))
+        }
+        (&Method::GET, ["me", "projects"]) => {
+            let params = get_projects::RequestBody {
+                include_archived: parse_bool(query, "include_archived")?,
+                since: parse_since(query)?,
+            };
+            ok(&store.list_projects(&params))
+        }
+        (&Method::GET, ["me", "tasks"]) => {
+            let params = get_tasks::RequestBody {
+                since: parse_since(query)?,
+                include_not_active: parse_bool(query, "include_not_active")?,
+            };
+            ok(&store.list_tasks(&params))
+        }
+        (&Method::GET, ["me", "tags"]) => {
+            let params = get_tags::RequestBody {
+                since: parse_since(query)?,
+            };
+            ok(&store.list_tags(&params))
+        }
+        (&Method::GET, ["me", "time_entries"]) => {
+            let params = get_time_entries::RequestBody {
+                since: parse_since(query)?,
+                before: parse_date(query, "before")?,
+                start_date: parse_date(query, "start_date")?.map(|date| date.date_naive()),
+                end_date: parse_date(query, "end_date")?.map(|date| date.date_naive()),
+            };
+            ok(&store.list_time_entries(&params)?)
+        }
+        (&Method::GET, ["me", "time_entries", "current"]) => ok(&store.current_time_entry()),
+        (&Method::POST, ["workspaces", workspace_id, "clients"]) => {
+            ok(store.create_client(id(workspace_id)?, json(body)?)?)
+        }
+        (&Method::POST, ["workspaces", workspace_id, "projects"]) => {
+            ok(store.create_project(id(workspace_id)?, json(body)?)?)
+        }
+        (&Method::POST, ["workspaces", workspace_id, "projects", project_id, "tasks"]) => {
+            ok(store.create_task(id(workspace_id)?, id(project_id)?, json(body)?)?)
+        }
+        (&Method::POST, ["workspaces", workspace_id, "tags"]) => {
+            ok(store.create_tag(id(workspace_id)?, json(body)?)?)
+        }
+        (&Method::POST, ["workspaces", workspace_id, "time_entries"]) => {
+            ok(store.create_time_entry(id(workspace_id)?, json(body)?)?)
+        }
+        (&Method::PUT, ["workspaces", workspace_id, "time_entries", time_entry_id]) => {
+            ok(store.update_time_entry(id(workspace_id)?, id(time_entry_id)?, json(body)?)?)
+        }
+        (&Method::DELETE, ["workspaces", workspace_id, "time_entries", time_entry_id]) => {
+            store.remove_time_entry(id(workspace_id)?, id(time_entry_id)?)?;
+            Ok(Response::new(Body::empty()))
+        }
+        _ => Err(ApiError::not_found("Not found")),
+    }
+}
+
+pub(crate) async fn handle(
+    shared: Arc<Shared>,
+    request: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    shared.requests.lock().unwrap().push(RecordedRequest {
+        method: request.method().clone(),
+        path: request.uri().path().to_string(),
+        query: request.uri().query().map(str::to_string),
+    });
+
+    let fault = shared.faults.lock().unwrap().pop_front();
+    match fault {
+        Some(Fault::Status(status)) => {
+            let mut response = json_response(status, &status.canonical_reason());
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                response.headers_mut().insert(
+                    hyper::header::RETRY_AFTER,
+                    hyper::header::HeaderValue::from_static("1"),
+                );
+            }
+            return Ok(response);
+        }
+        Some(Fault::Delay(delay)) => tokio::time::sleep(delay).await,
+        None => {}
+    }
+
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    let query: Query = request
+        .uri()
+        .query()
+        .map(|query| {
+            form_urlencoded::parse(query.as_bytes())
+                .into_owned()
+                .collect()
+        })
+        .unwrap_or_default();
+    if !authorized(&shared.store.lock().unwrap(), &request) {
+        return Ok(error_response(ApiError::new(
+            StatusCode::FORBIDDEN,
+            "Incorrect username and/or password",
+        )));
+    }
+    let body = match hyper::body::to_bytes(request.into_body()).await {
+        Ok(body) => body,
+        Err(err) => return Ok(error_response(ApiError::bad_request(err.to_string()))),
+    };
+
+    let Some(path) = path.strip_prefix("/api/v9/") else {
+        return Ok(error_response(ApiError::new(
+            StatusCode::NOT_FOUND,
+            "Not found",
+        )));
+    };
+    let segments: Vec<_> = path.trim_end_matches('/').split('/').collect();
+    let mut store = shared.store.lock().unwrap();
+    Ok(route(&mut store, &method, &segments, &query, &body).unwrap_or_else(error_response))
+}
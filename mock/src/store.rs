@@ -0,0 +1,754 @@
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+use hyper::StatusCode;
+use togglrust::day_of_week::DayOfWeek;
+use togglrust::endpoints::{
+    get_clients, get_me, get_projects, get_tags, get_tasks, get_time_entries, get_workspaces,
+    post_clients, post_projects, post_tags, post_tasks, post_time_entries, put_time_entries,
+};
+use togglrust::models;
+
+/// An error response the way Toggl sends them: a status and a JSON string message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub message: String,
+}
+
+impl ApiError {
+    pub(crate) fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, message)
+    }
+
+    pub(crate) fn not_found(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::NOT_FOUND, message)
+    }
+
+    pub(crate) fn forbidden() -> Self {
+        Self::new(
+            StatusCode::FORBIDDEN,
+            "User does not have access to this resource.",
+        )
+    }
+}
+
+/// The account the mock server authenticates requests against
+#[derive(Debug, Clone)]
+pub struct User {
+    pub id: i64,
+    pub email: String,
+    pub password: String,
+    pub api_token: String,
+    pub fullname: String,
+    pub timezone: String,
+    pub default_workspace_id: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl User {
+    /// Whether basic auth credentials match either the email and password or the API token
+    pub fn accepts(&self, user: &str, password: &str) -> bool {
+        (user == self.email && password == self.password)
+            || (user == self.api_token && password == "api_token")
+    }
+}
+
+/// With `since`, everything modified since then including deletions. Without, what's not deleted.
+fn visible(
+    since: Option<DateTime<Utc>>,
+    at: DateTime<Utc>,
+    deleted: Option<DateTime<Utc>>,
+) -> bool {
+    match since {
+        Some(since) => at >= since,
+        None => deleted.is_none(),
+    }
+}
+
+/// Everything the mock server knows about, in the shape the API returns it.
+///
+/// Deleting something only sets its deletion timestamp, so requests with `since` still see it.
+#[derive(Debug)]
+pub struct Store {
+    pub user: User,
+    pub organization_id: i64,
+    pub workspaces: Vec<models::Workspace>,
+    pub clients: Vec<models::Client>,
+    pub projects: Vec<models::Project>,
+    pub tasks: Vec<models::Task>,
+    pub tags: Vec<models::Tag>,
+    pub time_entries: Vec<models::TimeEntry>,
+    next_id: i64,
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        Self {
+            user: User {
+                id: 1,
+                email: String::from("mock@example.com"),
+                password: String::from("password"),
+                api_token: String::from("0123456789abcdef0123456789abcdef"),
+                fullname: String::from("Mock User"),
+                timezone: String::from("UTC"),
+                default_workspace_id: None,
+                created_at: Utc::now(),
+            },
+            organization_id: 2,
+            workspaces: Vec::new(),
+            clients: Vec::new(),
+            projects: Vec::new(),
+            tasks: Vec::new(),
+            tags: Vec::new(),
+            time_entries: Vec::new(),
+            next_id: 100,
+        }
+    }
+}
+
+impl Store {
+    fn next_id(&mut self) -> i64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    pub(crate) fn check_workspace(&self, workspace_id: i64) -> Result<(), ApiError> {
+        match self
+            .workspaces
+            .iter()
+            .any(|workspace| workspace.id == workspace_id && workspace.server_deleted_at.is_none())
+        {
+            true => Ok(()),
+            false => Err(ApiError::forbidden()),
+        }
+    }
+
+    /// Adds a workspace with the API's defaults, making it the user's default if it's the first
+    pub fn add_workspace(&mut self, name: &str) -> i64 {
+        let id = self.next_id();
+        let now = Utc::now();
+        self.workspaces.push(models::Workspace {
+            admin: true,
+            api_token: Some(self.user.api_token.clone().into()),
+            at: now,
+            business_ws: false,
+            csv_upload: None,
+            default_currency: String::from("USD"),
+            default_hourly_rate: 0.0,
+            ical_enabled: false,
+            ical_url: String::new(),
+            id,
+            logo_url: String::new(),
+            name: name.to_string(),
+            only_admins_may_create_projects: false,
+            only_admins_may_create_tags: false,
+            only_admins_see_billable_rates: false,
+            only_admins_see_team_dashboard: false,
+            organization_id: self.organization_id,
+            premium: false,
+            profile: 0,
+            projects_billable_by_default: false,
+            rounding: 1,
+            rounding_minutes: 0,
+            server_deleted_at: None,
+            subscription: None,
+            suspended_at: None,
+            te_constraints: None,
+            extra: Default::default(),
+        });
+        self.user.default_workspace_id.get_or_insert(id);
+        id
+    }
+
+    /// Adds a client, panicking if the API would have rejected it
+    pub fn add_client(&mut self, workspace_id: i64, name: &str) -> i64 {
+        let body = post_clients::RequestBody {
+            name: name.to_string(),
+            notes: None,
+        };
+        self.create_client(workspace_id, body)
+            .expect("Couldn't add client")
+            .id
+    }
+
+    /// Adds an active project, panicking if the API would have rejected it
+    pub fn add_project(&mut self, workspace_id: i64, name: &str) -> i64 {
+        let body = post_projects::RequestBody {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        self.create_project(workspace_id, body)
+            .expect("Couldn't add project")
+            .id
+    }
+
+    /// Adds an active task, panicking if the API would have rejected it
+    pub fn add_task(&mut self, workspace_id: i64, project_id: i64, name: &str) -> i64 {
+        let body = post_tasks::RequestBody {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        self.create_task(workspace_id, project_id, body)
+            .expect("Couldn't add task")
+            .id
+    }
+
+    /// Adds a tag, panicking if the API would have rejected it
+    pub fn add_tag(&mut self, workspace_id: i64, name: &str) -> i64 {
+        let body = post_tags::RequestBody {
+            name: name.to_string(),
+        };
+        self.create_tag(workspace_id, body)
+            .expect("Couldn't add tag")
+            .id
+    }
+
+    /// Adds a time entry, panicking if the API would have rejected it
+    pub fn add_time_entry(&mut self, body: post_time_entries::RequestBody) -> i64 {
+        let workspace_id = body.workspace_id;
+        self.create_time_entry(workspace_id, body)
+            .expect("Couldn't add time entry")
+            .id
+    }
+
+    pub fn delete_client(&mut self, id: i64) -> bool {
+        let now = Utc::now();
+        self.clients
+            .iter_mut()
+            .find(|client| client.id == id && client.server_deleted_at.is_none())
+            .map(|client| {
+                client.at = now;
+                client.server_deleted_at = Some(now);
+            })
+            .is_some()
+    }
+
+    pub fn delete_project(&mut self, id: i64) -> bool {
+        let now = Utc::now();
+        self.projects
+            .iter_mut()
+            .find(|project| project.id == id && project.server_deleted_at.is_none())
+            .map(|project| {
+                project.at = now;
+                project.server_deleted_at = Some(now);
+            })
+            .is_some()
+    }
+
+    pub fn delete_tag(&mut self, id: i64) -> bool {
+        let now = Utc::now();
+        self.tags
+            .iter_mut()
+            .find(|tag| tag.id == id && tag.deleted_at.is_none())
+            .map(|tag| {
+                tag.at = now;
+                tag.deleted_at = Some(now);
+            })
+            .is_some()
+    }
+
+    pub fn delete_time_entry(&mut self, id: i64) -> bool {
+        let now = Utc::now();
+        self.time_entries
+            .iter_mut()
+            .find(|entry| entry.id == id && entry.server_deleted_at.is_none())
+            .map(|entry| {
+                entry.at = now;
+                entry.server_deleted_at = Some(now);
+            })
+            .is_some()
+    }
+
+    pub fn time_entry(&self, id: i64) -> Option<&models::TimeEntry> {
+        self.time_entries.iter().find(|entry| entry.id == id)
+    }
+
+    pub fn me(&self) -> get_me::ResponseBody {
+        let user = &self.user;
+        get_me::ResponseBody {
+            api_token: Some(user.api_token.clone().into()),
+            at: user.created_at,
+            beginning_of_week: DayOfWeek::Monday,
+            clients: None,
+            country_id: None,
+            created_at: user.created_at,
+            default_workspace_id: user.default_workspace_id,
+            email: user.email.clone(),
+            fullname: user.fullname.clone(),
+            has_password: true,
+            id: user.id,
+            image_url: String::new(),
+            intercom_hash: None,
+            oauth_providers: None,
+            openid_email: None,
+            openid_enabled: false,
+            projects: None,
+            tags: None,
+            tasks: None,
+            time_entries: None,
+            timezone: user.timezone.clone(),
+            updated_at: user.created_at,
+            workspaces: None,
+            extra: Default::default(),
+        }
+    }
+
+    pub fn list_workspaces(&self, params: &get_workspaces::RequestBody) -> Vec<&models::Workspace> {
+        self.workspaces
+            .iter()
+            .filter(|workspace| visible(params.since, workspace.at, workspace.server_deleted_at))
+            .collect()
+    }
+
+    pub fn list_clients(&self, params: &get_clients::RequestBody) -> Vec<&models::Client> {
+        self.clients
+            .iter()
+            .filter(|client| visible(params.since, client.at, client.server_deleted_at))
+            .collect()
+    }
+
+    pub fn list_projects(&self, params: &get_projects::RequestBody) -> Vec<&models::Project> {
+        let include_archived = params.include_archived.unwrap_or(false);
+        self.projects
+            .iter()
+            .filter(|project| visible(params.since, project.at, project.server_deleted_at))
+            .filter(|project| include_archived || project.active)
+            .collect()
+    }
+
+    pub fn list_tasks(&self, params: &get_tasks::RequestBody) -> Vec<&models::Task> {
+        let include_not_active = params.include_not_active.unwrap_or(false);
+        self.tasks
+            .iter()
+            .filter(|task| visible(params.since, task.at, task.server_deleted_at))
+            .filter(|task| include_not_active || task.active)
+            .collect()
+    }
+
+    pub fn list_tags(&self, params: &get_tags::RequestBody) -> Vec<&models::Tag> {
+        self.tags
+            .iter()
+            .filter(|tag| visible(params.since, tag.at, tag.deleted_at))
+            .collect()
+    }
+
+    /// The user's entries, latest first. `end_date` is exclusive, like the API's.
+    pub fn list_time_entries(
+        &self,
+        params: &get_time_entries::RequestBody,
+    ) -> Result<Vec<&models::TimeEntry>, ApiError> {
+        let start_date = params
+            .start_date
+            .map(|date| date.and_time(NaiveTime::MIN).and_utc());
+        let end_date = params
+            .end_date
+            .map(|date| date.and_time(NaiveTime::MIN).and_utc());
+        if start_date.is_some() != end_date.is_some() {
+            return Err(ApiError::bad_request(
+                "start_date and end_date must be used together",
+            ));
+        }
+        if start_date > end_date {
+            return Err(ApiError::bad_request(
+                "start_date must not be later than end_date",
+            ));
+        }
+
+        let mut entries: Vec<_> = self
+            .time_entries
+            .iter()
+            .filter(|entry| entry.user_id == self.user.id)
+            .filter(|entry| visible(params.since, entry.at, entry.server_deleted_at))
+            .filter(|entry| params.before.is_none_or(|before| entry.start < before))
+            .filter(|entry| start_date.is_none_or(|start_date| entry.start >= start_date))
+            .filter(|entry| end_date.is_none_or(|end_date| entry.start < end_date))
+            .collect();
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.start));
+        Ok(entries)
+    }
+
+    pub fn current_time_entry(&self) -> Option<&models::TimeEntry> {
+        self.time_entries.iter().find(|entry| {
+            entry.user_id == self.user.id
+                && entry.stop.is_none()
+                && entry.server_deleted_at.is_none()
+        })
+    }
+
+    pub(crate) fn create_client(
+        &mut self,
+        workspace_id: i64,
+        body: post_clients::RequestBody,
+    ) -> Result<&models::Client, ApiError> {
+        self.check_workspace(workspace_id)?;
+        if self.clients.iter().any(|client| {
+            client.wid == workspace_id
+                && client.server_deleted_at.is_none()
+                && client.name == body.name
+        }) {
+            return Err(ApiError::bad_request("client name already exists"));
+        }
+        let id = self.next_id();
+        self.clients.push(models::Client {
+            archived: false,
+            at: Utc::now(),
+            id,
+            name: body.name,
+            server_deleted_at: None,
+            wid: workspace_id,
+            extra: Default::default(),
+        });
+        Ok(self.clients.last().unwrap())
+    }
+
+    pub(crate) fn create_project(
+        &mut self,
+        workspace_id: i64,
+        body: post_projects::RequestBody,
+    ) -> Result<&models::Project, ApiError> {
+        self.check_workspace(workspace_id)?;
+        if body.name.trim().is_empty() {
+            return Err(ApiError::bad_request("Project name must not be empty"));
+        }
+        if self.projects.iter().any(|project| {
+            project.workspace_id == workspace_id
+                && project.server_deleted_at.is_none()
+                && project.name == body.name
+        }) {
+            return Err(ApiError::bad_request(
+                "Name has already been taken in this workspace",
+            ));
+        }
+        if let Some(client_id) = body.client_id {
+            if !self.clients.iter().any(|client| {
+                client.id == client_id
+                    && client.wid == workspace_id
+                    && client.server_deleted_at.is_none()
+            }) {
+                return Err(ApiError::bad_request("Invalid client_id"));
+            }
+        }
+        let id = self.next_id();
+        let now = Utc::now();
+        self.projects.push(models::Project {
+            active: body.active.unwrap_or(true),
+            actual_hours: None,
+            at: now,
+            auto_estimates: None,
+            billable: body.billable,
+            cid: body.client_id,
+            client_id: body.client_id,
+            color: body.color.unwrap_or_else(|| String::from("#06aaf5")),
+            created_at: now,
+            currency: body.currency,
+            current_period: None,
+            end_date: None,
+            estimated_hours: None,
+            first_time_entry: None,
+            fixed_fee: None,
+            id,
+            is_private: body.is_private.unwrap_or(true),
+            name: body.name,
+            rate: body.rate,
+            rate_last_updated: body.rate.map(|_| now),
+            recurring: false,
+            recurring_parameters: None,
+            server_deleted_at: None,
+            start_date: Some(now),
+            template: None,
+            wid: workspace_id,
+            workspace_id,
+            extra: Default::default(),
+        });
+        Ok(self.projects.last().unwrap())
+    }
+
+    pub(crate) fn create_task(
+        &mut self,
+        workspace_id: i64,
+        project_id: i64,
+        body: post_tasks::RequestBody,
+    ) -> Result<&models::Task, ApiError> {
+        self.check_workspace(workspace_id)?;
+        if !self.projects.iter().any(|project| {
+            project.id == project_id
+                && project.workspace_id == workspace_id
+                && project.server_deleted_at.is_none()
+        }) {
+            return Err(ApiError::not_found("Project not found"));
+        }
+        let id = self.next_id();
+        self.tasks.push(models::Task {
+            active: body.active.unwrap_or(true),
+            at: Utc::now(),
+            estimated_seconds: body.estimated_seconds,
+            id,
+            name: body.name,
+            project_id,
+            recurring: false,
+            server_deleted_at: None,
+            tracked_time: Duration::zero(),
+            user_id: body.user_id.or(Some(self.user.id)),
+            workspace_id,
+            extra: Default::default(),
+        });
+        Ok(self.tasks.last().unwrap())
+    }
+
+    pub(crate) fn create_tag(
+        &mut self,
+        workspace_id: i64,
+        body: post_tags::RequestBody,
+    ) -> Result<&models::Tag, ApiError> {
+        self.check_workspace(workspace_id)?;
+        if self.tags.iter().any(|tag| {
+            tag.workspace_id == workspace_id && tag.deleted_at.is_none() && tag.name == body.name
+        }) {
+            return Err(ApiError::bad_request("Tag already exists"));
+        }
+        let id = self.next_id();
+        self.tags.push(models::Tag {
+            at: Utc::now(),
+            deleted_at: None,
+            id,
+            name: body.name,
+            workspace_id,
+            extra: Default::default(),
+        });
+        Ok(self.tags.last().unwrap())
+    }
+
+    /// Checks that the project, task and tags an entry refers to exist in its workspace
+    fn check_references(
+        &self,
+        workspace_id: i64,
+        project_id: Option<i64>,
+        task_id: Option<i64>,
+        tag_ids: &[i64],
+    ) -> Result<(), ApiError> {
+        if let Some(project_id) = project_id {
+            if !self.projects.iter().any(|project| {
+                project.id == project_id
+                    && project.workspace_id == workspace_id
+                    && project.server_deleted_at.is_none()
+            }) {
+                return Err(ApiError::bad_request("Invalid project_id"));
+            }
+        }
+        if let Some(task_id) = task_id {
+            if !self.tasks.iter().any(|task| {
+                task.id == task_id
+                    && task.workspace_id == workspace_id
+                    && Some(task.project_id) == project_id
+            }) {
+                return Err(ApiError::bad_request("Invalid task_id"));
+            }
+        }
+        for tag_id in tag_ids {
+            if !self.tags.iter().any(|tag| {
+                tag.id == *tag_id && tag.workspace_id == workspace_id && tag.deleted_at.is_none()
+            }) {
+                return Err(ApiError::bad_request("Invalid tag_ids"));
+            }
+        }
+        Ok(())
+    }
+
+    /// IDs for tags given by name, creating the ones that don't exist yet
+    fn resolve_tags(&mut self, workspace_id: i64, names: &[String]) -> Result<Vec<i64>, ApiError> {
+        let mut tag_ids = Vec::new();
+        for name in names {
+            let existing = self.tags.iter().find(|tag| {
+                tag.workspace_id == workspace_id && tag.deleted_at.is_none() && &tag.name == name
+            });
+            let tag_id = match existing {
+                Some(tag) => tag.id,
+                None => {
+                    let body = post_tags::RequestBody { name: name.clone() };
+                    self.create_tag(workspace_id, body)?.id
+                }
+            };
+            tag_ids.push(tag_id);
+        }
+        Ok(tag_ids)
+    }
+
+    fn tag_names(&self, tag_ids: &[i64]) -> Vec<String> {
+        tag_ids
+            .iter()
+            .filter_map(|tag_id| self.tags.iter().find(|tag| tag.id == *tag_id))
+            .map(|tag| tag.name.clone())
+            .collect()
+    }
+
+    /// Stops the user's running entry, if any, at `stop`
+    fn stop_running(&mut self, user_id: i64, stop: DateTime<Utc>) {
+        if let Some(running) = self.time_entries.iter_mut().find(|entry| {
+            entry.user_id == user_id && entry.stop.is_none() && entry.server_deleted_at.is_none()
+        }) {
+            let stop = stop.max(running.start);
+            running.stop = Some(stop);
+            running.duration = stop - running.start;
+            running.at = Utc::now();
+        }
+    }
+
+    /// Creates a time entry like the API does: tags given by name are created when missing, and
+    /// starting a running entry stops the one that's currently running
+    pub(crate) fn create_time_entry(
+        &mut self,
+        workspace_id: i64,
+        body: post_time_entries::RequestBody,
+    ) -> Result<&models::TimeEntry, ApiError> {
+        self.check_workspace(workspace_id)?;
+        if body.workspace_id != workspace_id {
+            return Err(ApiError::bad_request(
+                "Workspace ID in the body doesn't match the one in the path",
+            ));
+        }
+        let mut tag_ids = body.tag_ids.unwrap_or_default();
+        self.check_references(workspace_id, body.project_id, body.task_id, &tag_ids)?;
+        let (stop, duration) = match (body.stop, body.duration) {
+            (Some(stop), _) if stop < body.start => {
+                return Err(ApiError::bad_request(
+                    "Stop time should be after start time",
+                ))
+            }
+            (Some(stop), _) => (Some(stop), stop - body.start),
+            (None, Some(duration)) if duration >= Duration::zero() => {
+                (Some(body.start + duration), duration)
+            }
+            (None, _) => (None, Duration::seconds(-body.start.timestamp())),
+        };
+
+        let user_id = body.user_id.unwrap_or(self.user.id);
+        if stop.is_none() {
+            self.stop_running(user_id, body.start);
+        }
+        for tag_id in self.resolve_tags(workspace_id, body.tags.as_deref().unwrap_or_default())? {
+            if !tag_ids.contains(&tag_id) {
+                tag_ids.push(tag_id);
+            }
+        }
+
+        let id = self.next_id();
+        self.time_entries.push(models::TimeEntry {
+            at: Utc::now(),
+            billable: body.billable.unwrap_or(false),
+            description: body.description,
+            duration,
+            duronly: true,
+            id,
+            pid: body.project_id,
+            project_id: body.project_id,
+            server_deleted_at: None,
+            start: body.start,
+            stop,
+            tags: self.tag_names(&tag_ids),
+            tag_ids,
+            task_id: body.task_id,
+            tid: body.task_id,
+            uid: Some(user_id),
+            user_id,
+            wid: Some(workspace_id),
+            workspace_id,
+            extra: Default::default(),
+        });
+        Ok(self.time_entries.last().unwrap())
+    }
+
+    fn time_entry_index(&self, workspace_id: i64, id: i64) -> Result<usize, ApiError> {
+        self.check_workspace(workspace_id)?;
+        self.time_entries
+            .iter()
+            .position(|entry| {
+                entry.id == id
+                    && entry.workspace_id == workspace_id
+                    && entry.server_deleted_at.is_none()
+            })
+            .ok_or_else(|| ApiError::not_found("Time entry not found"))
+    }
+
+    /// Updates the fields that are set in `body`. Setting a stop time on a running entry stops it.
+    pub(crate) fn update_time_entry(
+        &mut self,
+        workspace_id: i64,
+        id: i64,
+        body: put_time_entries::RequestBody,
+    ) -> Result<&models::TimeEntry, ApiError> {
+        let index = self.time_entry_index(workspace_id, id)?;
+        let entry = &self.time_entries[index];
+        let project_id = body.project_id.or(entry.project_id);
+        let task_id = body.task_id.or(entry.task_id);
+        let start = body.start.unwrap_or(entry.start);
+        let stop = match (body.stop, body.duration) {
+            (Some(stop), _) => Some(stop),
+            (None, Some(duration)) if duration >= Duration::zero() => Some(start + duration),
+            (None, Some(_)) => None,
+            (None, None) => entry.stop,
+        };
+        if stop.is_some_and(|stop| stop < start) {
+            return Err(ApiError::bad_request(
+                "Stop time should be after start time",
+            ));
+        }
+        let replaces_tags = body.tag_ids.is_some() || body.tags.is_some();
+        let mut given = body.tag_ids.unwrap_or_default();
+        self.check_references(workspace_id, project_id, task_id, &given)?;
+        for tag_id in self.resolve_tags(workspace_id, body.tags.as_deref().unwrap_or_default())? {
+            if !given.contains(&tag_id) {
+                given.push(tag_id);
+            }
+        }
+        let mut tag_ids = self.time_entries[index].tag_ids.clone();
+        match body.tag_action {
+            Some(put_time_entries::TagAction::Add) => {
+                for tag_id in given {
+                    if !tag_ids.contains(&tag_id) {
+                        tag_ids.push(tag_id);
+                    }
+                }
+            }
+            Some(put_time_entries::TagAction::Delete) => {
+                tag_ids.retain(|tag_id| !given.contains(tag_id))
+            }
+            None if replaces_tags => tag_ids = given,
+            None => {}
+        }
+        let tags = self.tag_names(&tag_ids);
+
+        let entry = &mut self.time_entries[index];
+        entry.at = Utc::now();
+        if let Some(billable) = body.billable {
+            entry.billable = billable;
+        }
+        if body.description.is_some() {
+            entry.description = body.description;
+        }
+        entry.project_id = project_id;
+        entry.pid = project_id;
+        entry.task_id = task_id;
+        entry.tid = task_id;
+        entry.start = start;
+        entry.stop = stop;
+        entry.duration = match stop {
+            Some(stop) => stop - start,
+            None => Duration::seconds(-start.timestamp()),
+        };
+        entry.tag_ids = tag_ids;
+        entry.tags = tags;
+        Ok(&self.time_entries[index])
+    }
+
+    pub(crate) fn remove_time_entry(&mut self, workspace_id: i64, id: i64) -> Result<(), ApiError> {
+        self.time_entry_index(workspace_id, id)?;
+        self.delete_time_entry(id);
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+use chrono::{Duration, TimeZone, Utc};
+use togglrust::api::{Cached, TogglApi};
+use togglrust::endpoints::{
+    delete_time_entries, get_dashboard_all_activity, get_time_entries, get_webhook_subscriptions,
+    post_projects, post_time_entries, put_time_entries,
+};
+use togglrust_mock::{Fake, MockServer, StatusCode};
+
+/// Code under test only sees the trait, so it runs against the server and the fake alike
+async fn tag_and_stop(api: &impl TogglApi, workspace_id: i64) -> i64 {
+    let start = Utc.with_ymd_and_hms(2024, 5, 6, 8, 0, 0).unwrap();
+    let entry = api
+        .post_time_entries(
+            post_time_entries::PathParams { workspace_id },
+            post_time_entries::RequestBody::new(workspace_id, start, "tests"),
+        )
+        .await
+        .unwrap()
+        .0
+        .unwrap();
+    let body = put_time_entries::RequestBody {
+        stop: Some(start + Duration::minutes(90)),
+        tags: Some(vec![String::from("deep work")]),
+        tag_action: Some(put_time_entries::TagAction::Add),
+        ..Default::default()
+    };
+    let params = put_time_entries::PathParams {
+        workspace_id,
+        time_entry_id: entry.id,
+    };
+    let updated = api.put_time_entries(params, body).await.unwrap().0;
+    assert_eq!(updated.duration, Duration::minutes(90));
+    assert_eq!(updated.tags, vec![String::from("deep work")]);
+    assert!(api.get_current_time_entry().await.unwrap().0.is_none());
+    entry.id
+}
+
+#[tokio::test]
+async fn server_and_fake_behave_alike() {
+    let server = MockServer::start().await;
+    let workspace_id = server.store().add_workspace("Acme");
+    let id = tag_and_stop(&server.client(), workspace_id).await;
+    assert_eq!(server.store().time_entry(id).unwrap().tags.len(), 1);
+
+    let fake = Fake::default();
+    let workspace_id = fake.store().add_workspace("Acme");
+    let id = tag_and_stop(&fake, workspace_id).await;
+    assert_eq!(fake.store().time_entry(id).unwrap().tags.len(), 1);
+}
+
+#[tokio::test]
+async fn deleting_twice_is_not_found() {
+    let fake = Fake::default();
+    let workspace_id = fake.store().add_workspace("Acme");
+    let mut body = post_time_entries::RequestBody::new(workspace_id, Utc::now(), "tests");
+    body.duration = Some(Duration::minutes(5));
+    let time_entry_id = fake.store().add_time_entry(body);
+
+    let params = || delete_time_entries::PathParams {
+        workspace_id,
+        time_entry_id,
+    };
+    fake.delete_time_entries(params()).await.unwrap();
+    let err = fake.delete_time_entries(params()).await.unwrap_err();
+    assert_eq!(err.status(), Some(StatusCode::NOT_FOUND));
+    let entries = fake.get_time_entries(get_time_entries::RequestBody::default());
+    assert!(entries.await.unwrap().0.is_empty());
+}
+
+#[tokio::test]
+async fn cached_reads_until_a_write() {
+    let fake = Fake::default();
+    let workspace_id = fake.store().add_workspace("Acme");
+    fake.store().add_project(workspace_id, "Website");
+    let cached = Cached::new(fake, std::time::Duration::from_secs(60));
+
+    assert_eq!(
+        cached
+            .get_projects(Default::default())
+            .await
+            .unwrap()
+            .0
+            .len(),
+        1
+    );
+    cached
+        .inner()
+        .store()
+        .add_project(workspace_id, "Added behind the cache");
+    assert_eq!(
+        cached
+            .get_projects(Default::default())
+            .await
+            .unwrap()
+            .0
+            .len(),
+        1
+    );
+
+    let body = post_projects::RequestBody {
+        name: String::from("Mobile app"),
+        ..Default::default()
+    };
+    cached
+        .post_projects(post_projects::PathParams { workspace_id }, body)
+        .await
+        .unwrap();
+    assert_eq!(
+        cached
+            .get_projects(Default::default())
+            .await
+            .unwrap()
+            .0
+            .len(),
+        3
+    );
+}
+
+#[tokio::test]
+async fn unmodelled_endpoints_are_not_found() {
+    let server = MockServer::start().await;
+    let err = TogglApi::get_track_reminders(&server.client())
+        .await
+        .unwrap_err();
+    assert_eq!(err.status(), Some(StatusCode::NOT_FOUND));
+
+    let fake = Fake::default();
+    let err = fake.get_track_reminders().await.unwrap_err();
+    assert_eq!(err.status(), Some(StatusCode::NOT_FOUND));
+    let err = fake
+        .get_dashboard_all_activity(get_dashboard_all_activity::PathParams { workspace_id: 1 })
+        .await
+        .unwrap_err();
+    assert_eq!(err.status(), Some(StatusCode::NOT_FOUND));
+}
+
+#[tokio::test]
+async fn session_and_webhook_endpoints_are_on_the_trait() {
+    let server = MockServer::start().await;
+    let fake = Fake::default();
+    let apis: [&dyn TogglApi; 2] = [&server.client(), &fake];
+    for api in apis {
+        api.get_logged().await.unwrap();
+        let err = api.get_webhook_event_filters().await.unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::NOT_FOUND));
+        let err = api
+            .get_webhook_subscriptions(get_webhook_subscriptions::PathParams { workspace_id: 1 })
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::NOT_FOUND));
+    }
+}
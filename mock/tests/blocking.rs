@@ -0,0 +1,34 @@
+use chrono::Utc;
+use togglrust::blocking::Client;
+use togglrust::endpoints::{delete_time_entries, post_time_entries};
+use togglrust_mock::{MockServer, StatusCode};
+
+#[test]
+fn works_without_an_async_caller() {
+    // The mock server itself still needs a runtime to run on
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let server = runtime.block_on(MockServer::start());
+    let workspace_id = server.store().add_workspace("Acme");
+    server.store().add_project(workspace_id, "Website");
+
+    let client = Client::with_base_url(server.auth(), &server.base_url());
+    let me = client.get_me().unwrap();
+    assert_eq!(me.default_workspace_id, Some(workspace_id));
+    assert_eq!(client.get_projects(Default::default()).unwrap().0.len(), 1);
+
+    let entry = client
+        .post_time_entries(
+            post_time_entries::PathParams { workspace_id },
+            post_time_entries::RequestBody::new(workspace_id, Utc::now(), "tests"),
+        )
+        .unwrap()
+        .0
+        .unwrap();
+    let params = || delete_time_entries::PathParams {
+        workspace_id,
+        time_entry_id: entry.id,
+    };
+    client.delete_time_entries(params()).unwrap();
+    let err = client.delete_time_entries(params()).unwrap_err();
+    assert_eq!(err.status(), Some(StatusCode::NOT_FOUND));
+}
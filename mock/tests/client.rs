@@ -0,0 +1,168 @@
+use chrono::{Duration, TimeZone, Utc};
+use togglrust::client::{Auth, Client};
+use togglrust::endpoints::{get_projects, get_time_entries, post_time_entries};
+use togglrust::secret::Secret;
+use togglrust_mock::{Fault, MockServer, StatusCode};
+
+#[tokio::test]
+async fn lists_seeded_data() {
+    let server = MockServer::start().await;
+    let workspace_id = server.store().add_workspace("Acme");
+    let website = server.store().add_project(workspace_id, "Website");
+    let archived = server.store().add_project(workspace_id, "Old website");
+    server
+        .store()
+        .projects
+        .iter_mut()
+        .find(|project| project.id == archived)
+        .unwrap()
+        .active = false;
+
+    let client = server.client();
+    let me = client.get_me().await.unwrap();
+    assert_eq!(me.default_workspace_id, Some(workspace_id));
+
+    let projects = client.get_projects(Default::default()).await.unwrap();
+    let ids: Vec<_> = projects.0.iter().map(|project| project.id).collect();
+    assert_eq!(ids, vec![website]);
+
+    let params = get_projects::RequestBody {
+        include_archived: Some(true),
+        ..Default::default()
+    };
+    assert_eq!(client.get_projects(params).await.unwrap().0.len(), 2);
+}
+
+#[tokio::test]
+async fn starting_an_entry_stops_the_running_one() {
+    let server = MockServer::start().await;
+    let workspace_id = server.store().add_workspace("Acme");
+    let client = server.client();
+
+    let first_start = Utc.with_ymd_and_hms(2024, 3, 4, 9, 0, 0).unwrap();
+    let first = client
+        .post_time_entries(
+            post_time_entries::PathParams { workspace_id },
+            post_time_entries::RequestBody::new(workspace_id, first_start, "tests"),
+        )
+        .await
+        .unwrap()
+        .0
+        .unwrap();
+    assert_eq!(first.stop, None);
+
+    let second_start = first_start + Duration::hours(2);
+    let mut body = post_time_entries::RequestBody::new(workspace_id, second_start, "tests");
+    body.tags = Some(vec![String::from("meetings")]);
+    let second = client
+        .post_time_entries(post_time_entries::PathParams { workspace_id }, body)
+        .await
+        .unwrap()
+        .0
+        .unwrap();
+    assert_eq!(second.tags, vec![String::from("meetings")]);
+
+    let first = server.store().time_entry(first.id).unwrap().stop;
+    assert_eq!(first, Some(second_start));
+    let current = client.get_current_time_entry().await.unwrap().0.unwrap();
+    assert_eq!(current.id, second.id);
+    assert_eq!(server.store().tags.len(), 1);
+}
+
+#[tokio::test]
+async fn since_includes_deleted_entries() {
+    let server = MockServer::start().await;
+    let workspace_id = server.store().add_workspace("Acme");
+    let start = Utc::now() - Duration::days(1);
+    let mut body = post_time_entries::RequestBody::new(workspace_id, start, "tests");
+    body.stop = Some(start + Duration::hours(1));
+    let kept = server.store().add_time_entry(body.clone());
+    let deleted = server.store().add_time_entry(body);
+    let since = Utc::now() - Duration::minutes(1);
+    server.store().delete_time_entry(deleted);
+
+    let client = server.client();
+    let entries = client.get_time_entries(Default::default()).await.unwrap();
+    let ids: Vec<_> = entries.0.iter().map(|entry| entry.id).collect();
+    assert_eq!(ids, vec![kept]);
+
+    let params = get_time_entries::RequestBody {
+        since: Some(since),
+        ..Default::default()
+    };
+    let entries = client.get_time_entries(params).await.unwrap();
+    assert_eq!(entries.0.len(), 2);
+    assert!(entries
+        .0
+        .iter()
+        .any(|entry| entry.id == deleted && entry.server_deleted_at.is_some()));
+}
+
+#[tokio::test]
+async fn rejects_invalid_entries_and_credentials() {
+    let server = MockServer::start().await;
+    let workspace_id = server.store().add_workspace("Acme");
+    let client = server.client();
+
+    let mut body = post_time_entries::RequestBody::new(workspace_id, Utc::now(), "tests");
+    body.project_id = Some(12345);
+    let err = client
+        .post_time_entries(post_time_entries::PathParams { workspace_id }, body)
+        .await
+        .unwrap_err();
+    assert_eq!(err.status(), Some(StatusCode::BAD_REQUEST));
+
+    let body = post_time_entries::RequestBody::new(workspace_id + 1, Utc::now(), "tests");
+    let err = client
+        .post_time_entries(
+            post_time_entries::PathParams {
+                workspace_id: workspace_id + 1,
+            },
+            body,
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.status(), Some(StatusCode::FORBIDDEN));
+
+    let stranger = Client::with_base_url(
+        Auth {
+            user: Secret::from("someone@example.com"),
+            password: Secret::from("guess"),
+        },
+        &server.base_url(),
+    );
+    assert!(stranger.get_me().await.is_err());
+    assert!(stranger.get_logged().await.is_err());
+    client.get_logged().await.unwrap();
+}
+
+#[tokio::test]
+async fn injects_faults() {
+    let server = MockServer::start().await;
+    let workspace_id = server.store().add_workspace("Acme");
+    let client = server.client();
+    let body = post_time_entries::RequestBody::new(workspace_id, Utc::now(), "tests");
+
+    server.inject(Fault::Status(StatusCode::TOO_MANY_REQUESTS));
+    server.inject(Fault::Status(StatusCode::INTERNAL_SERVER_ERROR));
+    for status in [
+        StatusCode::TOO_MANY_REQUESTS,
+        StatusCode::INTERNAL_SERVER_ERROR,
+    ] {
+        let err = client
+            .post_time_entries(post_time_entries::PathParams { workspace_id }, body.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), Some(status));
+    }
+    assert!(server.store().time_entries.is_empty());
+
+    server.inject(Fault::Delay(std::time::Duration::from_millis(200)));
+    let started = std::time::Instant::now();
+    client
+        .post_time_entries(post_time_entries::PathParams { workspace_id }, body)
+        .await
+        .unwrap();
+    assert!(started.elapsed() >= std::time::Duration::from_millis(200));
+    assert_eq!(server.requests().len(), 3);
+}
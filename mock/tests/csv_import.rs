@@ -0,0 +1,85 @@
+use chrono_tz::Tz;
+use std::time::Duration;
+use togglrust::api::TogglApi;
+use togglrust::csv_import::{
+    ColumnMapping, DuplicateOf, Error, ImportContext, ImportPlan, RowOutcome,
+};
+use togglrust::endpoints::get_workspaces;
+use togglrust_mock::{Fake, Fault, MockServer, StatusCode};
+
+const CSV: &str = "\
+Start,Duration,Description
+2024-03-04 09:00,1:00,Planning
+2024-03-04 10:00,0:30,Review
+2024-03-04 11:00,2:00,Deploy
+";
+
+#[tokio::test]
+async fn failed_imports_return_the_created_entries() {
+    let server = MockServer::start().await;
+    let workspace_id = server.store().add_workspace("Acme");
+    let client = server.client();
+    let workspaces = client
+        .get_workspaces(get_workspaces::RequestBody::default())
+        .await
+        .unwrap();
+    let workspace = workspaces
+        .0
+        .iter()
+        .find(|workspace| workspace.id == workspace_id)
+        .unwrap();
+    let context = |existing| ImportContext {
+        workspace,
+        timezone: Tz::UTC,
+        projects: &[],
+        clients: &[],
+        tags: &[],
+        existing,
+    };
+    let mapping = ColumnMapping::default();
+
+    let plan = ImportPlan::new(CSV.as_bytes(), &mapping, &context(&[])).unwrap();
+    server.inject(Fault::Delay(Duration::ZERO));
+    server.inject(Fault::Status(StatusCode::INTERNAL_SERVER_ERROR));
+    let Err(Error::Client { line, created, .. }) = plan.execute(&client).await else {
+        panic!("expected the second request to fail");
+    };
+    assert_eq!(line, 3);
+    assert_eq!(created.len(), 1);
+    assert_eq!(created[0].description.as_deref(), Some("Planning"));
+    assert_eq!(server.store().time_entries.len(), 1);
+
+    let resumed = ImportPlan::new(CSV.as_bytes(), &mapping, &context(&created)).unwrap();
+    assert!(matches!(
+        resumed.rows[0].outcome,
+        RowOutcome::Duplicate(DuplicateOf::TimeEntry(id)) if id == created[0].id
+    ));
+    let rest = resumed.execute(&client).await.unwrap();
+    assert_eq!(rest.len(), 2);
+    assert_eq!(server.store().time_entries.len(), 3);
+}
+
+#[tokio::test]
+async fn imports_into_the_fake() {
+    let fake = Fake::default();
+    let workspace_id = fake.store().add_workspace("Acme");
+    let workspaces = fake
+        .get_workspaces(get_workspaces::RequestBody::default())
+        .await
+        .unwrap();
+    let context = ImportContext {
+        workspace: &workspaces.0[0],
+        timezone: Tz::UTC,
+        projects: &[],
+        clients: &[],
+        tags: &[],
+        existing: &[],
+    };
+    let plan = ImportPlan::new(CSV.as_bytes(), &ColumnMapping::default(), &context).unwrap();
+    let created = plan.execute(&fake).await.unwrap();
+    assert_eq!(created.len(), 3);
+    assert!(created
+        .iter()
+        .all(|entry| entry.workspace_id == workspace_id));
+    assert_eq!(fake.store().time_entries.len(), 3);
+}
@@ -0,0 +1,104 @@
+use chrono::{Duration, TimeZone, Utc};
+use std::path::PathBuf;
+use togglrust::migrate::{Existing, Migration, SourceEntry};
+use togglrust_mock::{Fake, Fault, Method, MockServer, StatusCode};
+
+fn journal_path(name: &str) -> PathBuf {
+    let dir =
+        std::env::temp_dir().join(format!("togglrust-migrate-{}-{}", name, std::process::id()));
+    std::fs::remove_dir_all(&dir).ok();
+    std::fs::create_dir_all(&dir).unwrap();
+    dir.join("journal.jsonl")
+}
+
+fn entry(line: u64, tags: &[&str]) -> SourceEntry {
+    SourceEntry {
+        line,
+        client: None,
+        project: None,
+        task: None,
+        description: Some(format!("line {}", line)),
+        tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        billable: false,
+        start: Utc.with_ymd_and_hms(2024, 3, 4, 9, 0, 0).unwrap() + Duration::hours(line as i64),
+        duration: Duration::minutes(30),
+    }
+}
+
+#[tokio::test]
+async fn resumes_after_a_torn_journal_line() {
+    let server = MockServer::start().await;
+    let workspace_id = server.store().add_workspace("Acme");
+    let tag_id = server.store().add_tag(workspace_id, "Deep work");
+    let client = server.client();
+    let path = journal_path("torn");
+    std::fs::write(
+        &path,
+        format!(
+            "{{\"kind\":\"tag\",\"name\":\"Deep work\",\"id\":{}}}\n{{\"kind\":\"time_en",
+            tag_id
+        ),
+    )
+    .unwrap();
+
+    let entries = [entry(2, &["deep work"]), entry(3, &[])];
+    let mut migration = Migration::open(&client, workspace_id, &path, Existing::default()).unwrap();
+    migration.request_interval = std::time::Duration::ZERO;
+    let report = migration.run(&entries).await.unwrap();
+    assert_eq!((report.tags, report.time_entries), (0, 2));
+
+    let journal = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(journal.lines().count(), 3);
+    for line in journal.lines() {
+        serde_json::from_str::<serde_json::Value>(line).unwrap();
+    }
+
+    let mut migration = Migration::open(&client, workspace_id, &path, Existing::default()).unwrap();
+    migration.request_interval = std::time::Duration::ZERO;
+    let report = migration.run(&entries).await.unwrap();
+    assert_eq!((report.time_entries, report.skipped), (0, 2));
+    assert_eq!(server.store().time_entries.len(), 2);
+}
+
+#[tokio::test]
+async fn server_errors_are_not_retried() {
+    let server = MockServer::start().await;
+    let workspace_id = server.store().add_workspace("Acme");
+    let client = server.client();
+    let path = journal_path("server-error");
+    server.inject(Fault::Status(StatusCode::BAD_GATEWAY));
+
+    let mut migration = Migration::open(&client, workspace_id, &path, Existing::default()).unwrap();
+    migration.request_interval = std::time::Duration::ZERO;
+    assert!(migration.run(&[entry(2, &[])]).await.is_err());
+    let posts = server
+        .requests()
+        .iter()
+        .filter(|request| request.method == Method::POST)
+        .count();
+    assert_eq!(posts, 1);
+}
+
+#[tokio::test]
+async fn migrates_into_the_fake() {
+    let fake = Fake::default();
+    let workspace_id = fake.store().add_workspace("Acme");
+    let path = journal_path("fake");
+    let mut acme = entry(2, &["deep work"]);
+    acme.client = Some(String::from("Globex"));
+    acme.project = Some(String::from("Website"));
+
+    let mut migration = Migration::open(&fake, workspace_id, &path, Existing::default()).unwrap();
+    migration.request_interval = std::time::Duration::ZERO;
+    let report = migration.run(&[acme, entry(3, &[])]).await.unwrap();
+    assert_eq!(
+        (
+            report.clients,
+            report.projects,
+            report.tags,
+            report.time_entries
+        ),
+        (1, 1, 1, 2)
+    );
+    assert_eq!(fake.store().time_entries.len(), 2);
+}
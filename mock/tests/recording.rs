@@ -0,0 +1,173 @@
+use hyper::body::Bytes;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Response, Server};
+use std::convert::Infallible;
+use togglrust::client::{Auth, Client};
+use togglrust::endpoints::get_shared_report;
+use togglrust::fixtures::{Fixture, Recorder, Replayer, REDACTED};
+use togglrust::models::{ExportFormat, ReportFilters, ReportKind};
+use togglrust::secret::Secret;
+use togglrust_mock::MockServer;
+
+#[tokio::test]
+async fn recorded_fixtures_are_redacted_and_replay() {
+    let dir = std::env::temp_dir().join(format!("togglrust-recording-{}", std::process::id()));
+    let server = MockServer::start().await;
+    let workspace_id = server.store().add_workspace("Acme");
+    server.store().add_project(workspace_id, "Website");
+
+    let client = server.client().recording(Recorder::new(&dir).unwrap());
+    let me = client.get_me().await.unwrap();
+    let projects = client.get_projects(Default::default()).await.unwrap();
+    drop(server);
+
+    let mut paths: Vec<_> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    paths.sort();
+    let names: Vec<_> = paths
+        .iter()
+        .map(|path| path.file_name().unwrap().to_str().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            "0000-get-api_v9_me.json",
+            "0001-get-api_v9_me_projects.json"
+        ]
+    );
+    let fixture = Fixture::load(&paths[0]).unwrap();
+    assert_eq!(fixture.response.body["api_token"], REDACTED);
+    assert_eq!(fixture.response.body["email"], REDACTED);
+
+    let replayed = Client::replaying(Replayer::load(&dir).unwrap());
+    assert_eq!(replayed.get_me().await.unwrap().id, me.id);
+    let replayed_projects = replayed.get_projects(Default::default()).await.unwrap();
+    assert_eq!(replayed_projects.0[0].id, projects.0[0].id);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn report_tokens_stay_out_of_fixtures() {
+    let dir =
+        std::env::temp_dir().join(format!("togglrust-recording-shared-{}", std::process::id()));
+    std::fs::remove_dir_all(&dir).ok();
+    let server = MockServer::start().await;
+    let token = String::from("s3cr3t tok/en");
+    let client = server.client().recording(Recorder::new(&dir).unwrap());
+    let params = get_shared_report::PathParams {
+        report_token: token.clone(),
+    };
+    assert!(client.get_shared_report(params).await.is_err());
+    assert_eq!(
+        server.requests()[0].path,
+        "/reports/api/v3/shared/s3cr3t%20tok%2Fen"
+    );
+
+    let path = std::fs::read_dir(&dir)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path();
+    assert_eq!(
+        path.file_name().unwrap(),
+        "0000-get-reports_api_v3_shared__REDACTED_.json"
+    );
+    let fixture = Fixture::load(&path).unwrap();
+    assert_eq!(fixture.request.path, "/reports/api/v3/shared/[REDACTED]");
+
+    let replayed = Client::replaying(Replayer::load(&dir).unwrap());
+    let params = get_shared_report::PathParams {
+        report_token: String::from("another"),
+    };
+    let err = replayed.get_shared_report(params).await.unwrap_err();
+    assert_eq!(
+        err.status().map(|status| status.as_u16()),
+        Some(fixture.response.status)
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+const PDF: &[u8] = b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\ntrailer\n<< >>\n%%EOF\n";
+
+/// Answers every request with [`PDF`], in two chunks
+async fn pdf_server() -> String {
+    let make_service = make_service_fn(|_| async {
+        Ok::<_, Infallible>(service_fn(|_| async {
+            let (mut sender, body) = Body::channel();
+            tokio::spawn(async move {
+                for chunk in [&PDF[..10], &PDF[10..]] {
+                    sender.send_data(Bytes::from_static(chunk)).await.unwrap();
+                }
+            });
+            Ok::<_, Infallible>(Response::new(body))
+        }))
+    });
+    let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_service);
+    let base_url = format!("http://{}", server.local_addr());
+    tokio::spawn(server);
+    base_url
+}
+
+#[tokio::test]
+async fn binary_exports_stream_and_replay() {
+    let dir = std::env::temp_dir().join(format!("togglrust-recording-pdf-{}", std::process::id()));
+    std::fs::remove_dir_all(&dir).ok();
+    let auth = || Auth {
+        user: Secret::from("token"),
+        password: Secret::from("api_token"),
+    };
+    let base_url = pdf_server().await;
+    let filters = ReportFilters::default();
+
+    let mut file = Vec::new();
+    let client = Client::with_base_url(auth(), &base_url);
+    let size = client
+        .export_report(
+            1,
+            ReportKind::Summary,
+            ExportFormat::Pdf,
+            &filters,
+            &mut file,
+        )
+        .await
+        .unwrap();
+    assert_eq!((size, file.as_slice()), (PDF.len() as u64, PDF));
+
+    let client = Client::with_base_url(auth(), &base_url).recording(Recorder::new(&dir).unwrap());
+    client
+        .export_report(
+            1,
+            ReportKind::Summary,
+            ExportFormat::Pdf,
+            &filters,
+            &mut Vec::new(),
+        )
+        .await
+        .unwrap();
+    let path = std::fs::read_dir(&dir)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path();
+    let fixture = Fixture::load(&path).unwrap();
+    assert!(fixture.response.body.is_null());
+    assert!(fixture.response.body_base64.is_some());
+
+    let mut file = Vec::new();
+    Client::replaying(Replayer::load(&dir).unwrap())
+        .export_report(
+            1,
+            ReportKind::Summary,
+            ExportFormat::Pdf,
+            &filters,
+            &mut file,
+        )
+        .await
+        .unwrap();
+    assert_eq!(file, PDF);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
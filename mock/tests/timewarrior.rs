@@ -0,0 +1,189 @@
+use chrono::{TimeZone, Utc};
+use std::path::{Path, PathBuf};
+use togglrust::timewarrior::{Interval, Sync, TagMapping};
+use togglrust_mock::{Fake, MockServer};
+
+const DATA: &str = "\
+inc 20240304T090000Z - 20240304T103000Z # web acme deep-work mtg # \"standup and planning\"
+inc 20240305T080000Z - 20240305T090000Z # email
+";
+
+fn data_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "togglrust-timewarrior-{}-{}",
+        name,
+        std::process::id()
+    ));
+    std::fs::remove_dir_all(&dir).ok();
+    std::fs::create_dir_all(dir.join("data")).unwrap();
+    std::fs::write(dir.join("data/2024-03.data"), DATA).unwrap();
+    dir
+}
+
+fn mapping(workspace_id: i64, project_id: i64) -> TagMapping {
+    TagMapping {
+        workspace_id,
+        projects: [
+            (String::from("web"), project_id),
+            (String::from("acme"), project_id),
+        ]
+        .into(),
+        tags: [(String::from("mtg"), String::from("meetings"))].into(),
+        keep_unmapped_tags: false,
+    }
+}
+
+fn intervals(dir: &Path) -> Vec<Interval> {
+    std::fs::read_to_string(dir.join("data/2024-03.data"))
+        .unwrap()
+        .lines()
+        .map(|line| Interval::parse(line).unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn pushed_intervals_come_back_unchanged() {
+    let server = MockServer::start().await;
+    let workspace_id = server.store().add_workspace("Acme");
+    let project_id = server.store().add_project(workspace_id, "Website");
+    let client = server.client();
+    let mapping = mapping(workspace_id, project_id);
+    let dir = data_dir("round-trip");
+    let state = dir.join("state.json");
+    let before = intervals(&dir);
+
+    let report = Sync::open(&client, &mapping, dir.join("data"), &state)
+        .unwrap()
+        .run()
+        .await
+        .unwrap();
+    assert_eq!(report.pushed, 2);
+    let report = Sync::open(&client, &mapping, dir.join("data"), &state)
+        .unwrap()
+        .run()
+        .await
+        .unwrap();
+    assert_eq!((report.pulled, report.updated, report.pushed), (0, 0, 0));
+    assert_eq!(intervals(&dir), before);
+    assert_eq!(server.store().time_entries.len(), 2);
+}
+
+#[tokio::test]
+async fn remote_updates_keep_local_tags() {
+    let server = MockServer::start().await;
+    let workspace_id = server.store().add_workspace("Acme");
+    let project_id = server.store().add_project(workspace_id, "Website");
+    let client = server.client();
+    let mapping = mapping(workspace_id, project_id);
+    let dir = data_dir("update");
+    let state = dir.join("state.json");
+    Sync::open(&client, &mapping, dir.join("data"), &state)
+        .unwrap()
+        .run()
+        .await
+        .unwrap();
+
+    for entry in server.store().time_entries.iter_mut() {
+        if entry.project_id == Some(project_id) {
+            entry.description = Some(String::from("planning"));
+            entry.at = Utc::now();
+        }
+    }
+    let report = Sync::open(&client, &mapping, dir.join("data"), &state)
+        .unwrap()
+        .run()
+        .await
+        .unwrap();
+    assert_eq!(report.updated, 1);
+    let updated = &intervals(&dir)[0];
+    assert_eq!(updated.tags, ["web", "acme", "deep-work", "mtg"]);
+    assert_eq!(updated.annotation.as_deref(), Some("planning"));
+}
+
+#[tokio::test]
+async fn syncs_against_the_fake() {
+    let fake = Fake::default();
+    let workspace_id = fake.store().add_workspace("Acme");
+    let project_id = fake.store().add_project(workspace_id, "Website");
+    let mapping = mapping(workspace_id, project_id);
+    let dir = data_dir("fake");
+    let state = dir.join("state.json");
+
+    let report = Sync::open(&fake, &mapping, dir.join("data"), &state)
+        .unwrap()
+        .run()
+        .await
+        .unwrap();
+    assert_eq!(report.pushed, 2);
+    let store = fake.store();
+    let entry = store
+        .time_entries
+        .iter()
+        .find(|entry| entry.project_id == Some(project_id))
+        .unwrap();
+    assert_eq!(entry.tags, ["meetings"]);
+}
+
+#[tokio::test]
+async fn local_changes_update_and_delete_time_entries() {
+    let server = MockServer::start().await;
+    let workspace_id = server.store().add_workspace("Acme");
+    let project_id = server.store().add_project(workspace_id, "Website");
+    let client = server.client();
+    let mapping = mapping(workspace_id, project_id);
+    let dir = data_dir("local-changes");
+    let state = dir.join("state.json");
+    let sync = || async {
+        Sync::open(&client, &mapping, dir.join("data"), &state)
+            .unwrap()
+            .run()
+            .await
+            .unwrap()
+    };
+    sync().await;
+
+    // Move the first interval half an hour later, and drop the second one
+    std::fs::write(
+        dir.join("data/2024-03.data"),
+        "inc 20240304T093000Z - 20240304T110000Z # web mtg # \"planning\"\n",
+    )
+    .unwrap();
+    let report = sync().await;
+    assert_eq!(
+        (
+            report.pushed,
+            report.pushed_updates,
+            report.pushed_deletions
+        ),
+        (0, 1, 1)
+    );
+    let live: Vec<_> = server
+        .store()
+        .time_entries
+        .iter()
+        .filter(|entry| entry.server_deleted_at.is_none())
+        .cloned()
+        .collect();
+    let [entry] = &live[..] else {
+        panic!("expected the moved entry only, got {:?}", live);
+    };
+    assert_eq!(
+        entry.start,
+        Utc.with_ymd_and_hms(2024, 3, 4, 9, 30, 0).unwrap()
+    );
+    assert_eq!(entry.description.as_deref(), Some("planning"));
+    assert_eq!(entry.tags, ["meetings"]);
+
+    let report = sync().await;
+    assert_eq!(
+        (
+            report.pushed,
+            report.pushed_updates,
+            report.pushed_deletions
+        ),
+        (0, 0, 0)
+    );
+    assert_eq!((report.pulled, report.updated), (0, 0));
+    assert_eq!(intervals(&dir).len(), 1);
+    assert!(!dir.join("data/2024-03.data.tmp").exists());
+}
@@ -0,0 +1,129 @@
+use chrono::Utc;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use togglrust::endpoints::{get_shared_report, post_time_entries};
+use togglrust_mock::{Fault, MockServer, StatusCode};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Keeps every span field and event field as `name=value`
+#[derive(Clone, Default)]
+struct Capture {
+    lines: Arc<Mutex<Vec<String>>>,
+    next_id: Arc<AtomicU64>,
+}
+
+impl Capture {
+    fn text(&self) -> String {
+        self.lines.lock().unwrap().join("\n")
+    }
+}
+
+impl Visit for Capture {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        let line = format!("{}={:?}", field.name(), value);
+        self.lines.lock().unwrap().push(line);
+    }
+}
+
+impl Subscriber for Capture {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        span.record(&mut self.clone());
+        Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) + 1)
+    }
+
+    fn record(&self, _: &Id, values: &Record<'_>) {
+        values.record(&mut self.clone());
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        event.record(&mut self.clone());
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[tokio::test]
+async fn logs_requests_without_secrets() {
+    let server = MockServer::start().await;
+    server.store().add_workspace("Acme");
+    let password = String::from("correct horse battery staple");
+    server.store().user.password = password.clone();
+    let api_token = server.store().user.api_token.clone();
+    let client = server.client().log_bodies();
+
+    let capture = Capture::default();
+    let _guard = tracing::subscriber::set_default(capture.clone());
+    let me = client.get_me().await.unwrap();
+    assert_eq!(me.api_token.unwrap().expose(), api_token);
+    client.get_workspaces(Default::default()).await.unwrap();
+
+    let text = capture.text();
+    assert!(text.contains("endpoint=\"get_me\""), "{}", text);
+    assert!(text.contains("endpoint=\"get_workspaces\""), "{}", text);
+    assert!(text.contains("method=GET"), "{}", text);
+    assert!(text.contains("status=200"), "{}", text);
+    assert!(text.contains("duration_ms="), "{}", text);
+    assert!(text.contains("authorization: [REDACTED]"), "{}", text);
+    assert!(text.contains("\"api_token\":\"[REDACTED]\""), "{}", text);
+    assert!(!text.contains(&api_token), "{}", text);
+    assert!(!text.contains(&password), "{}", text);
+}
+
+#[tokio::test]
+async fn report_tokens_stay_out_of_spans() {
+    let server = MockServer::start().await;
+    let client = server.client().log_bodies();
+
+    let capture = Capture::default();
+    let _guard = tracing::subscriber::set_default(capture.clone());
+    let params = get_shared_report::PathParams {
+        report_token: String::from("s3cr3t"),
+    };
+    client.get_shared_report(params).await.unwrap_err();
+
+    let text = capture.text();
+    assert!(
+        text.contains("path=\"/reports/api/v3/shared/[REDACTED]\""),
+        "{}",
+        text
+    );
+    assert!(!text.contains("s3cr3t"), "{}", text);
+}
+
+#[tokio::test]
+async fn spans_count_retries() {
+    let server = MockServer::start().await;
+    let workspace_id = server.store().add_workspace("Acme");
+    let client = server.client().retrying(2);
+
+    let capture = Capture::default();
+    let _guard = tracing::subscriber::set_default(capture.clone());
+    server.inject(Fault::Status(StatusCode::TOO_MANY_REQUESTS));
+    client.get_me().await.unwrap();
+    assert_eq!(server.requests().len(), 2);
+    let text = capture.text();
+    assert!(text.contains("retries=1"), "{}", text);
+
+    // Creating an entry again after a server error could create it twice
+    server.inject(Fault::Status(StatusCode::INTERNAL_SERVER_ERROR));
+    let body = post_time_entries::RequestBody::new(workspace_id, Utc::now(), "tests");
+    let err = client
+        .post_time_entries(post_time_entries::PathParams { workspace_id }, body)
+        .await
+        .unwrap_err();
+    assert_eq!(err.status(), Some(StatusCode::INTERNAL_SERVER_ERROR));
+    assert_eq!(server.requests().len(), 3);
+    let text = capture.text();
+    assert!(text.contains("retries=0"), "{}", text);
+}
@@ -0,0 +1,1362 @@
+use crate::client::{Client, Error};
+use crate::endpoints;
+use crate::models::{ExportFormat, ReportFilters, ReportKind};
+use async_trait::async_trait;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::io::AsyncWrite;
+
+/// The Toggl operations services build on, so they can be handed a [`Client`], a fake or a
+/// [`Cached`] client interchangeably
+#[async_trait]
+pub trait TogglApi: Send + Sync {
+    async fn get_me(&self) -> Result<endpoints::get_me::ResponseBody, Error>;
+
+    async fn get_logged(&self) -> Result<(), Error>;
+
+    async fn get_orgs(&self) -> Result<endpoints::get_orgs::ResponseBody, Error>;
+
+    async fn get_current_time_entry(
+        &self,
+    ) -> Result<endpoints::get_current_time_entry::ResponseBody, Error>;
+
+    async fn get_workspaces(
+        &self,
+        params: endpoints::get_workspaces::RequestBody,
+    ) -> Result<endpoints::get_workspaces::ResponseBody, Error>;
+
+    async fn get_clients(
+        &self,
+        params: endpoints::get_clients::RequestBody,
+    ) -> Result<endpoints::get_clients::ResponseBody, Error>;
+
+    async fn get_projects(
+        &self,
+        params: endpoints::get_projects::RequestBody,
+    ) -> Result<endpoints::get_projects::ResponseBody, Error>;
+
+    async fn get_tasks(
+        &self,
+        params: endpoints::get_tasks::RequestBody,
+    ) -> Result<endpoints::get_tasks::ResponseBody, Error>;
+
+    async fn get_tags(
+        &self,
+        params: endpoints::get_tags::RequestBody,
+    ) -> Result<endpoints::get_tags::ResponseBody, Error>;
+
+    async fn get_time_entries(
+        &self,
+        params: endpoints::get_time_entries::RequestBody,
+    ) -> Result<endpoints::get_time_entries::ResponseBody, Error>;
+
+    async fn post_time_entries(
+        &self,
+        params: endpoints::post_time_entries::PathParams,
+        body: endpoints::post_time_entries::RequestBody,
+    ) -> Result<endpoints::post_time_entries::ResponseBody, Error>;
+
+    async fn put_time_entries(
+        &self,
+        params: endpoints::put_time_entries::PathParams,
+        body: endpoints::put_time_entries::RequestBody,
+    ) -> Result<endpoints::put_time_entries::ResponseBody, Error>;
+
+    async fn delete_time_entries(
+        &self,
+        params: endpoints::delete_time_entries::PathParams,
+    ) -> Result<(), Error>;
+
+    async fn post_clients(
+        &self,
+        params: endpoints::post_clients::PathParams,
+        body: endpoints::post_clients::RequestBody,
+    ) -> Result<endpoints::post_clients::ResponseBody, Error>;
+
+    async fn post_projects(
+        &self,
+        params: endpoints::post_projects::PathParams,
+        body: endpoints::post_projects::RequestBody,
+    ) -> Result<endpoints::post_projects::ResponseBody, Error>;
+
+    async fn post_tasks(
+        &self,
+        params: endpoints::post_tasks::PathParams,
+        body: endpoints::post_tasks::RequestBody,
+    ) -> Result<endpoints::post_tasks::ResponseBody, Error>;
+
+    async fn post_tags(
+        &self,
+        params: endpoints::post_tags::PathParams,
+        body: endpoints::post_tags::RequestBody,
+    ) -> Result<endpoints::post_tags::ResponseBody, Error>;
+
+    async fn get_organization_users(
+        &self,
+        params: endpoints::get_organization_users::PathParams,
+    ) -> Result<endpoints::get_organization_users::ResponseBody, Error>;
+
+    async fn post_organization_invitation(
+        &self,
+        params: endpoints::post_organization_invitation::PathParams,
+        body: endpoints::post_organization_invitation::RequestBody,
+    ) -> Result<endpoints::post_organization_invitation::ResponseBody, Error>;
+
+    async fn put_organization_users(
+        &self,
+        params: endpoints::put_organization_users::PathParams,
+        body: endpoints::put_organization_users::RequestBody,
+    ) -> Result<(), Error>;
+
+    async fn patch_organization_users(
+        &self,
+        params: endpoints::patch_organization_users::PathParams,
+        body: endpoints::patch_organization_users::RequestBody,
+    ) -> Result<(), Error>;
+
+    async fn get_workspace_users(
+        &self,
+        params: endpoints::get_workspace_users::PathParams,
+    ) -> Result<endpoints::get_workspace_users::ResponseBody, Error>;
+
+    async fn put_workspace_users(
+        &self,
+        params: endpoints::put_workspace_users::PathParams,
+        body: endpoints::put_workspace_users::RequestBody,
+    ) -> Result<endpoints::put_workspace_users::ResponseBody, Error>;
+
+    async fn delete_workspace_users(
+        &self,
+        params: endpoints::delete_workspace_users::PathParams,
+    ) -> Result<(), Error>;
+
+    async fn get_organization_groups(
+        &self,
+        params: endpoints::get_organization_groups::PathParams,
+    ) -> Result<endpoints::get_organization_groups::ResponseBody, Error>;
+
+    async fn post_organization_groups(
+        &self,
+        params: endpoints::post_organization_groups::PathParams,
+        body: endpoints::post_organization_groups::RequestBody,
+    ) -> Result<endpoints::post_organization_groups::ResponseBody, Error>;
+
+    async fn put_organization_groups(
+        &self,
+        params: endpoints::put_organization_groups::PathParams,
+        body: endpoints::put_organization_groups::RequestBody,
+    ) -> Result<endpoints::put_organization_groups::ResponseBody, Error>;
+
+    async fn patch_organization_groups(
+        &self,
+        params: endpoints::patch_organization_groups::PathParams,
+        body: endpoints::patch_organization_groups::RequestBody,
+    ) -> Result<endpoints::patch_organization_groups::ResponseBody, Error>;
+
+    async fn delete_organization_groups(
+        &self,
+        params: endpoints::delete_organization_groups::PathParams,
+    ) -> Result<(), Error>;
+
+    async fn get_workspace_groups(
+        &self,
+        params: endpoints::get_workspace_groups::PathParams,
+    ) -> Result<endpoints::get_workspace_groups::ResponseBody, Error>;
+
+    async fn get_project_users(
+        &self,
+        params: endpoints::get_project_users::PathParams,
+        query: endpoints::get_project_users::RequestBody,
+    ) -> Result<endpoints::get_project_users::ResponseBody, Error>;
+
+    async fn post_project_users(
+        &self,
+        params: endpoints::post_project_users::PathParams,
+        body: endpoints::post_project_users::RequestBody,
+    ) -> Result<endpoints::post_project_users::ResponseBody, Error>;
+
+    async fn put_project_users(
+        &self,
+        params: endpoints::put_project_users::PathParams,
+        body: endpoints::put_project_users::RequestBody,
+    ) -> Result<endpoints::put_project_users::ResponseBody, Error>;
+
+    async fn patch_project_users(
+        &self,
+        params: endpoints::patch_project_users::PathParams,
+        body: endpoints::patch_project_users::RequestBody,
+    ) -> Result<endpoints::patch_project_users::ResponseBody, Error>;
+
+    async fn delete_project_users(
+        &self,
+        params: endpoints::delete_project_users::PathParams,
+    ) -> Result<(), Error>;
+
+    async fn put_me(
+        &self,
+        body: endpoints::put_me::RequestBody,
+    ) -> Result<endpoints::put_me::ResponseBody, Error>;
+
+    async fn put_workspaces(
+        &self,
+        params: endpoints::put_workspaces::PathParams,
+        body: endpoints::put_workspaces::RequestBody,
+    ) -> Result<endpoints::put_workspaces::ResponseBody, Error>;
+
+    async fn get_preferences(&self) -> Result<endpoints::get_preferences::ResponseBody, Error>;
+
+    async fn post_preferences(
+        &self,
+        body: endpoints::post_preferences::RequestBody,
+    ) -> Result<(), Error>;
+
+    async fn get_workspace_preferences(
+        &self,
+        params: endpoints::get_workspace_preferences::PathParams,
+    ) -> Result<endpoints::get_workspace_preferences::ResponseBody, Error>;
+
+    async fn post_workspace_preferences(
+        &self,
+        params: endpoints::post_workspace_preferences::PathParams,
+        body: endpoints::post_workspace_preferences::RequestBody,
+    ) -> Result<(), Error>;
+
+    async fn get_dashboard_all_activity(
+        &self,
+        params: endpoints::get_dashboard_all_activity::PathParams,
+    ) -> Result<endpoints::get_dashboard_all_activity::ResponseBody, Error>;
+
+    async fn get_dashboard_most_active(
+        &self,
+        params: endpoints::get_dashboard_most_active::PathParams,
+    ) -> Result<endpoints::get_dashboard_most_active::ResponseBody, Error>;
+
+    async fn post_reports_search_time_entries(
+        &self,
+        params: endpoints::post_reports_search_time_entries::PathParams,
+        body: endpoints::post_reports_search_time_entries::RequestBody,
+    ) -> Result<endpoints::post_reports_search_time_entries::ResponseBody, Error>;
+
+    async fn get_saved_reports(
+        &self,
+        params: endpoints::get_saved_reports::PathParams,
+    ) -> Result<endpoints::get_saved_reports::ResponseBody, Error>;
+
+    async fn post_saved_reports(
+        &self,
+        params: endpoints::post_saved_reports::PathParams,
+        body: endpoints::post_saved_reports::RequestBody,
+    ) -> Result<endpoints::post_saved_reports::ResponseBody, Error>;
+
+    async fn put_saved_reports(
+        &self,
+        params: endpoints::put_saved_reports::PathParams,
+        body: endpoints::put_saved_reports::RequestBody,
+    ) -> Result<endpoints::put_saved_reports::ResponseBody, Error>;
+
+    async fn get_shared_report(
+        &self,
+        params: endpoints::get_shared_report::PathParams,
+    ) -> Result<endpoints::get_shared_report::ResponseBody, Error>;
+
+    async fn post_shared_report(
+        &self,
+        params: endpoints::post_shared_report::PathParams,
+        body: endpoints::post_shared_report::RequestBody,
+    ) -> Result<endpoints::post_shared_report::ResponseBody, Error>;
+
+    async fn export_report(
+        &self,
+        workspace_id: i64,
+        report: ReportKind,
+        format: ExportFormat,
+        filters: &ReportFilters,
+        writer: &mut (dyn AsyncWrite + Unpin + Send),
+    ) -> Result<u64, Error>;
+
+    async fn get_track_reminders(
+        &self,
+    ) -> Result<endpoints::get_track_reminders::ResponseBody, Error>;
+
+    async fn get_workspace_track_reminders(
+        &self,
+        params: endpoints::get_workspace_track_reminders::PathParams,
+    ) -> Result<endpoints::get_workspace_track_reminders::ResponseBody, Error>;
+
+    async fn post_track_reminders(
+        &self,
+        params: endpoints::post_track_reminders::PathParams,
+        body: endpoints::post_track_reminders::RequestBody,
+    ) -> Result<endpoints::post_track_reminders::ResponseBody, Error>;
+
+    async fn put_track_reminders(
+        &self,
+        params: endpoints::put_track_reminders::PathParams,
+        body: endpoints::put_track_reminders::RequestBody,
+    ) -> Result<endpoints::put_track_reminders::ResponseBody, Error>;
+
+    async fn delete_track_reminders(
+        &self,
+        params: endpoints::delete_track_reminders::PathParams,
+    ) -> Result<(), Error>;
+
+    async fn get_webhook_subscriptions(
+        &self,
+        params: endpoints::get_webhook_subscriptions::PathParams,
+    ) -> Result<endpoints::get_webhook_subscriptions::ResponseBody, Error>;
+
+    async fn post_webhook_subscriptions(
+        &self,
+        params: endpoints::post_webhook_subscriptions::PathParams,
+        body: endpoints::post_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::post_webhook_subscriptions::ResponseBody, Error>;
+
+    async fn put_webhook_subscriptions(
+        &self,
+        params: endpoints::put_webhook_subscriptions::PathParams,
+        body: endpoints::put_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::put_webhook_subscriptions::ResponseBody, Error>;
+
+    async fn patch_webhook_subscriptions(
+        &self,
+        params: endpoints::patch_webhook_subscriptions::PathParams,
+        body: endpoints::patch_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::patch_webhook_subscriptions::ResponseBody, Error>;
+
+    async fn delete_webhook_subscriptions(
+        &self,
+        params: endpoints::delete_webhook_subscriptions::PathParams,
+    ) -> Result<endpoints::delete_webhook_subscriptions::ResponseBody, Error>;
+
+    async fn get_webhook_event_filters(
+        &self,
+    ) -> Result<endpoints::get_webhook_event_filters::ResponseBody, Error>;
+
+    async fn post_webhook_ping(
+        &self,
+        params: endpoints::post_webhook_ping::PathParams,
+    ) -> Result<(), Error>;
+}
+
+#[async_trait]
+impl TogglApi for Client {
+    async fn get_me(&self) -> Result<endpoints::get_me::ResponseBody, Error> {
+        Client::get_me(self).await
+    }
+
+    async fn get_logged(&self) -> Result<(), Error> {
+        Client::get_logged(self).await
+    }
+
+    async fn get_orgs(&self) -> Result<endpoints::get_orgs::ResponseBody, Error> {
+        Client::get_orgs(self).await
+    }
+
+    async fn get_current_time_entry(
+        &self,
+    ) -> Result<endpoints::get_current_time_entry::ResponseBody, Error> {
+        Client::get_current_time_entry(self).await
+    }
+
+    async fn get_workspaces(
+        &self,
+        params: endpoints::get_workspaces::RequestBody,
+    ) -> Result<endpoints::get_workspaces::ResponseBody, Error> {
+        Client::get_workspaces(self, params).await
+    }
+
+    async fn get_clients(
+        &self,
+        params: endpoints::get_clients::RequestBody,
+    ) -> Result<endpoints::get_clients::ResponseBody, Error> {
+        Client::get_clients(self, params).await
+    }
+
+    async fn get_projects(
+        &self,
+        params: endpoints::get_projects::RequestBody,
+    ) -> Result<endpoints::get_projects::ResponseBody, Error> {
+        Client::get_projects(self, params).await
+    }
+
+    async fn get_tasks(
+        &self,
+        params: endpoints::get_tasks::RequestBody,
+    ) -> Result<endpoints::get_tasks::ResponseBody, Error> {
+        Client::get_tasks(self, params).await
+    }
+
+    async fn get_tags(
+        &self,
+        params: endpoints::get_tags::RequestBody,
+    ) -> Result<endpoints::get_tags::ResponseBody, Error> {
+        Client::get_tags(self, params).await
+    }
+
+    async fn get_time_entries(
+        &self,
+        params: endpoints::get_time_entries::RequestBody,
+    ) -> Result<endpoints::get_time_entries::ResponseBody, Error> {
+        Client::get_time_entries(self, params).await
+    }
+
+    async fn post_time_entries(
+        &self,
+        params: endpoints::post_time_entries::PathParams,
+        body: endpoints::post_time_entries::RequestBody,
+    ) -> Result<endpoints::post_time_entries::ResponseBody, Error> {
+        Client::post_time_entries(self, params, body).await
+    }
+
+    async fn put_time_entries(
+        &self,
+        params: endpoints::put_time_entries::PathParams,
+        body: endpoints::put_time_entries::RequestBody,
+    ) -> Result<endpoints::put_time_entries::ResponseBody, Error> {
+        Client::put_time_entries(self, params, body).await
+    }
+
+    async fn delete_time_entries(
+        &self,
+        params: endpoints::delete_time_entries::PathParams,
+    ) -> Result<(), Error> {
+        Client::delete_time_entries(self, params).await
+    }
+
+    async fn post_clients(
+        &self,
+        params: endpoints::post_clients::PathParams,
+        body: endpoints::post_clients::RequestBody,
+    ) -> Result<endpoints::post_clients::ResponseBody, Error> {
+        Client::post_clients(self, params, body).await
+    }
+
+    async fn post_projects(
+        &self,
+        params: endpoints::post_projects::PathParams,
+        body: endpoints::post_projects::RequestBody,
+    ) -> Result<endpoints::post_projects::ResponseBody, Error> {
+        Client::post_projects(self, params, body).await
+    }
+
+    async fn post_tasks(
+        &self,
+        params: endpoints::post_tasks::PathParams,
+        body: endpoints::post_tasks::RequestBody,
+    ) -> Result<endpoints::post_tasks::ResponseBody, Error> {
+        Client::post_tasks(self, params, body).await
+    }
+
+    async fn post_tags(
+        &self,
+        params: endpoints::post_tags::PathParams,
+        body: endpoints::post_tags::RequestBody,
+    ) -> Result<endpoints::post_tags::ResponseBody, Error> {
+        Client::post_tags(self, params, body).await
+    }
+
+    async fn get_organization_users(
+        &self,
+        params: endpoints::get_organization_users::PathParams,
+    ) -> Result<endpoints::get_organization_users::ResponseBody, Error> {
+        Client::get_organization_users(self, params).await
+    }
+
+    async fn post_organization_invitation(
+        &self,
+        params: endpoints::post_organization_invitation::PathParams,
+        body: endpoints::post_organization_invitation::RequestBody,
+    ) -> Result<endpoints::post_organization_invitation::ResponseBody, Error> {
+        Client::post_organization_invitation(self, params, body).await
+    }
+
+    async fn put_organization_users(
+        &self,
+        params: endpoints::put_organization_users::PathParams,
+        body: endpoints::put_organization_users::RequestBody,
+    ) -> Result<(), Error> {
+        Client::put_organization_users(self, params, body).await
+    }
+
+    async fn patch_organization_users(
+        &self,
+        params: endpoints::patch_organization_users::PathParams,
+        body: endpoints::patch_organization_users::RequestBody,
+    ) -> Result<(), Error> {
+        Client::patch_organization_users(self, params, body).await
+    }
+
+    async fn get_workspace_users(
+        &self,
+        params: endpoints::get_workspace_users::PathParams,
+    ) -> Result<endpoints::get_workspace_users::ResponseBody, Error> {
+        Client::get_workspace_users(self, params).await
+    }
+
+    async fn put_workspace_users(
+        &self,
+        params: endpoints::put_workspace_users::PathParams,
+        body: endpoints::put_workspace_users::RequestBody,
+    ) -> Result<endpoints::put_workspace_users::ResponseBody, Error> {
+        Client::put_workspace_users(self, params, body).await
+    }
+
+    async fn delete_workspace_users(
+        &self,
+        params: endpoints::delete_workspace_users::PathParams,
+    ) -> Result<(), Error> {
+        Client::delete_workspace_users(self, params).await
+    }
+
+    async fn get_organization_groups(
+        &self,
+        params: endpoints::get_organization_groups::PathParams,
+    ) -> Result<endpoints::get_organization_groups::ResponseBody, Error> {
+        Client::get_organization_groups(self, params).await
+    }
+
+    async fn post_organization_groups(
+        &self,
+        params: endpoints::post_organization_groups::PathParams,
+        body: endpoints::post_organization_groups::RequestBody,
+    ) -> Result<endpoints::post_organization_groups::ResponseBody, Error> {
+        Client::post_organization_groups(self, params, body).await
+    }
+
+    async fn put_organization_groups(
+        &self,
+        params: endpoints::put_organization_groups::PathParams,
+        body: endpoints::put_organization_groups::RequestBody,
+    ) -> Result<endpoints::put_organization_groups::ResponseBody, Error> {
+        Client::put_organization_groups(self, params, body).await
+    }
+
+    async fn patch_organization_groups(
+        &self,
+        params: endpoints::patch_organization_groups::PathParams,
+        body: endpoints::patch_organization_groups::RequestBody,
+    ) -> Result<endpoints::patch_organization_groups::ResponseBody, Error> {
+        Client::patch_organization_groups(self, params, body).await
+    }
+
+    async fn delete_organization_groups(
+        &self,
+        params: endpoints::delete_organization_groups::PathParams,
+    ) -> Result<(), Error> {
+        Client::delete_organization_groups(self, params).await
+    }
+
+    async fn get_workspace_groups(
+        &self,
+        params: endpoints::get_workspace_groups::PathParams,
+    ) -> Result<endpoints::get_workspace_groups::ResponseBody, Error> {
+        Client::get_workspace_groups(self, params).await
+    }
+
+    async fn get_project_users(
+        &self,
+        params: endpoints::get_project_users::PathParams,
+        query: endpoints::get_project_users::RequestBody,
+    ) -> Result<endpoints::get_project_users::ResponseBody, Error> {
+        Client::get_project_users(self, params, query).await
+    }
+
+    async fn post_project_users(
+        &self,
+        params: endpoints::post_project_users::PathParams,
+        body: endpoints::post_project_users::RequestBody,
+    ) -> Result<endpoints::post_project_users::ResponseBody, Error> {
+        Client::post_project_users(self, params, body).await
+    }
+
+    async fn put_project_users(
+        &self,
+        params: endpoints::put_project_users::PathParams,
+        body: endpoints::put_project_users::RequestBody,
+    ) -> Result<endpoints::put_project_users::ResponseBody, Error> {
+        Client::put_project_users(self, params, body).await
+    }
+
+    async fn patch_project_users(
+        &self,
+        params: endpoints::patch_project_users::PathParams,
+        body: endpoints::patch_project_users::RequestBody,
+    ) -> Result<endpoints::patch_project_users::ResponseBody, Error> {
+        Client::patch_project_users(self, params, body).await
+    }
+
+    async fn delete_project_users(
+        &self,
+        params: endpoints::delete_project_users::PathParams,
+    ) -> Result<(), Error> {
+        Client::delete_project_users(self, params).await
+    }
+
+    async fn put_me(
+        &self,
+        body: endpoints::put_me::RequestBody,
+    ) -> Result<endpoints::put_me::ResponseBody, Error> {
+        Client::put_me(self, body).await
+    }
+
+    async fn put_workspaces(
+        &self,
+        params: endpoints::put_workspaces::PathParams,
+        body: endpoints::put_workspaces::RequestBody,
+    ) -> Result<endpoints::put_workspaces::ResponseBody, Error> {
+        Client::put_workspaces(self, params, body).await
+    }
+
+    async fn get_preferences(&self) -> Result<endpoints::get_preferences::ResponseBody, Error> {
+        Client::get_preferences(self).await
+    }
+
+    async fn post_preferences(
+        &self,
+        body: endpoints::post_preferences::RequestBody,
+    ) -> Result<(), Error> {
+        Client::post_preferences(self, body).await
+    }
+
+    async fn get_workspace_preferences(
+        &self,
+        params: endpoints::get_workspace_preferences::PathParams,
+    ) -> Result<endpoints::get_workspace_preferences::ResponseBody, Error> {
+        Client::get_workspace_preferences(self, params).await
+    }
+
+    async fn post_workspace_preferences(
+        &self,
+        params: endpoints::post_workspace_preferences::PathParams,
+        body: endpoints::post_workspace_preferences::RequestBody,
+    ) -> Result<(), Error> {
+        Client::post_workspace_preferences(self, params, body).await
+    }
+
+    async fn get_dashboard_all_activity(
+        &self,
+        params: endpoints::get_dashboard_all_activity::PathParams,
+    ) -> Result<endpoints::get_dashboard_all_activity::ResponseBody, Error> {
+        Client::get_dashboard_all_activity(self, params).await
+    }
+
+    async fn get_dashboard_most_active(
+        &self,
+        params: endpoints::get_dashboard_most_active::PathParams,
+    ) -> Result<endpoints::get_dashboard_most_active::ResponseBody, Error> {
+        Client::get_dashboard_most_active(self, params).await
+    }
+
+    async fn post_reports_search_time_entries(
+        &self,
+        params: endpoints::post_reports_search_time_entries::PathParams,
+        body: endpoints::post_reports_search_time_entries::RequestBody,
+    ) -> Result<endpoints::post_reports_search_time_entries::ResponseBody, Error> {
+        Client::post_reports_search_time_entries(self, params, body).await
+    }
+
+    async fn get_saved_reports(
+        &self,
+        params: endpoints::get_saved_reports::PathParams,
+    ) -> Result<endpoints::get_saved_reports::ResponseBody, Error> {
+        Client::get_saved_reports(self, params).await
+    }
+
+    async fn post_saved_reports(
+        &self,
+        params: endpoints::post_saved_reports::PathParams,
+        body: endpoints::post_saved_reports::RequestBody,
+    ) -> Result<endpoints::post_saved_reports::ResponseBody, Error> {
+        Client::post_saved_reports(self, params, body).await
+    }
+
+    async fn put_saved_reports(
+        &self,
+        params: endpoints::put_saved_reports::PathParams,
+        body: endpoints::put_saved_reports::RequestBody,
+    ) -> Result<endpoints::put_saved_reports::ResponseBody, Error> {
+        Client::put_saved_reports(self, params, body).await
+    }
+
+    async fn get_shared_report(
+        &self,
+        params: endpoints::get_shared_report::PathParams,
+    ) -> Result<endpoints::get_shared_report::ResponseBody, Error> {
+        Client::get_shared_report(self, params).await
+    }
+
+    async fn post_shared_report(
+        &self,
+        params: endpoints::post_shared_report::PathParams,
+        body: endpoints::post_shared_report::RequestBody,
+    ) -> Result<endpoints::post_shared_report::ResponseBody, Error> {
+        Client::post_shared_report(self, params, body).await
+    }
+
+    async fn export_report(
+        &self,
+        workspace_id: i64,
+        report: ReportKind,
+        format: ExportFormat,
+        filters: &ReportFilters,
+        mut writer: &mut (dyn AsyncWrite + Unpin + Send),
+    ) -> Result<u64, Error> {
+        Client::export_report(self, workspace_id, report, format, filters, &mut writer).await
+    }
+
+    async fn get_track_reminders(
+        &self,
+    ) -> Result<endpoints::get_track_reminders::ResponseBody, Error> {
+        Client::get_track_reminders(self).await
+    }
+
+    async fn get_workspace_track_reminders(
+        &self,
+        params: endpoints::get_workspace_track_reminders::PathParams,
+    ) -> Result<endpoints::get_workspace_track_reminders::ResponseBody, Error> {
+        Client::get_workspace_track_reminders(self, params).await
+    }
+
+    async fn post_track_reminders(
+        &self,
+        params: endpoints::post_track_reminders::PathParams,
+        body: endpoints::post_track_reminders::RequestBody,
+    ) -> Result<endpoints::post_track_reminders::ResponseBody, Error> {
+        Client::post_track_reminders(self, params, body).await
+    }
+
+    async fn put_track_reminders(
+        &self,
+        params: endpoints::put_track_reminders::PathParams,
+        body: endpoints::put_track_reminders::RequestBody,
+    ) -> Result<endpoints::put_track_reminders::ResponseBody, Error> {
+        Client::put_track_reminders(self, params, body).await
+    }
+
+    async fn delete_track_reminders(
+        &self,
+        params: endpoints::delete_track_reminders::PathParams,
+    ) -> Result<(), Error> {
+        Client::delete_track_reminders(self, params).await
+    }
+
+    async fn get_webhook_subscriptions(
+        &self,
+        params: endpoints::get_webhook_subscriptions::PathParams,
+    ) -> Result<endpoints::get_webhook_subscriptions::ResponseBody, Error> {
+        Client::get_webhook_subscriptions(self, params).await
+    }
+
+    async fn post_webhook_subscriptions(
+        &self,
+        params: endpoints::post_webhook_subscriptions::PathParams,
+        body: endpoints::post_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::post_webhook_subscriptions::ResponseBody, Error> {
+        Client::post_webhook_subscriptions(self, params, body).await
+    }
+
+    async fn put_webhook_subscriptions(
+        &self,
+        params: endpoints::put_webhook_subscriptions::PathParams,
+        body: endpoints::put_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::put_webhook_subscriptions::ResponseBody, Error> {
+        Client::put_webhook_subscriptions(self, params, body).await
+    }
+
+    async fn patch_webhook_subscriptions(
+        &self,
+        params: endpoints::patch_webhook_subscriptions::PathParams,
+        body: endpoints::patch_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::patch_webhook_subscriptions::ResponseBody, Error> {
+        Client::patch_webhook_subscriptions(self, params, body).await
+    }
+
+    async fn delete_webhook_subscriptions(
+        &self,
+        params: endpoints::delete_webhook_subscriptions::PathParams,
+    ) -> Result<endpoints::delete_webhook_subscriptions::ResponseBody, Error> {
+        Client::delete_webhook_subscriptions(self, params).await
+    }
+
+    async fn get_webhook_event_filters(
+        &self,
+    ) -> Result<endpoints::get_webhook_event_filters::ResponseBody, Error> {
+        Client::get_webhook_event_filters(self).await
+    }
+
+    async fn post_webhook_ping(
+        &self,
+        params: endpoints::post_webhook_ping::PathParams,
+    ) -> Result<(), Error> {
+        Client::post_webhook_ping(self, params).await
+    }
+}
+
+struct CacheEntry<T> {
+    fetched_at: Instant,
+    value: T,
+}
+
+/// Responses by their serialized request parameters
+type Responses<T> = HashMap<String, CacheEntry<T>>;
+
+fn cache_key(params: &impl Serialize) -> String {
+    serde_json::to_string(params).expect("Request parameters always serialize")
+}
+
+#[derive(Default)]
+struct Cache {
+    me: Responses<endpoints::get_me::ResponseBody>,
+    workspaces: Responses<endpoints::get_workspaces::ResponseBody>,
+    clients: Responses<endpoints::get_clients::ResponseBody>,
+    projects: Responses<endpoints::get_projects::ResponseBody>,
+    tasks: Responses<endpoints::get_tasks::ResponseBody>,
+    tags: Responses<endpoints::get_tags::ResponseBody>,
+}
+
+/// Keeps responses for the user and the workspace entities around for `ttl`.
+///
+/// Time entries change too often to be worth caching and always go to the inner API, as do
+/// memberships, preferences, reports and reminders. Any successful write clears the whole cache,
+/// since e.g. creating a time entry can create tags.
+pub struct Cached<A> {
+    inner: A,
+    ttl: Duration,
+    cache: Mutex<Cache>,
+}
+
+impl<A: TogglApi> Cached<A> {
+    pub fn new(inner: A, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            cache: Mutex::new(Cache::default()),
+        }
+    }
+
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    pub fn invalidate(&self) {
+        *self.cache.lock().unwrap() = Cache::default();
+    }
+
+    async fn cached<T, F>(
+        &self,
+        responses: fn(&mut Cache) -> &mut Responses<T>,
+        key: String,
+        fetch: F,
+    ) -> Result<T, Error>
+    where
+        T: Clone,
+        F: Future<Output = Result<T, Error>>,
+    {
+        if let Some(entry) = responses(&mut self.cache.lock().unwrap()).get(&key) {
+            if entry.fetched_at.elapsed() < self.ttl {
+                return Ok(entry.value.clone());
+            }
+        }
+
+        let value = fetch.await?;
+        responses(&mut self.cache.lock().unwrap()).insert(
+            key,
+            CacheEntry {
+                fetched_at: Instant::now(),
+                value: value.clone(),
+            },
+        );
+        Ok(value)
+    }
+
+    fn invalidate_after<T>(&self, result: Result<T, Error>) -> Result<T, Error> {
+        if result.is_ok() {
+            self.invalidate();
+        }
+        result
+    }
+}
+
+#[async_trait]
+impl<A: TogglApi> TogglApi for Cached<A> {
+    async fn get_me(&self) -> Result<endpoints::get_me::ResponseBody, Error> {
+        self.cached(|cache| &mut cache.me, String::new(), self.inner.get_me())
+            .await
+    }
+
+    async fn get_logged(&self) -> Result<(), Error> {
+        self.inner.get_logged().await
+    }
+
+    async fn get_orgs(&self) -> Result<endpoints::get_orgs::ResponseBody, Error> {
+        self.inner.get_orgs().await
+    }
+
+    async fn get_current_time_entry(
+        &self,
+    ) -> Result<endpoints::get_current_time_entry::ResponseBody, Error> {
+        self.inner.get_current_time_entry().await
+    }
+
+    async fn get_workspaces(
+        &self,
+        params: endpoints::get_workspaces::RequestBody,
+    ) -> Result<endpoints::get_workspaces::ResponseBody, Error> {
+        let key = cache_key(&params);
+        self.cached(
+            |cache| &mut cache.workspaces,
+            key,
+            self.inner.get_workspaces(params),
+        )
+        .await
+    }
+
+    async fn get_clients(
+        &self,
+        params: endpoints::get_clients::RequestBody,
+    ) -> Result<endpoints::get_clients::ResponseBody, Error> {
+        let key = cache_key(&params);
+        self.cached(
+            |cache| &mut cache.clients,
+            key,
+            self.inner.get_clients(params),
+        )
+        .await
+    }
+
+    async fn get_projects(
+        &self,
+        params: endpoints::get_projects::RequestBody,
+    ) -> Result<endpoints::get_projects::ResponseBody, Error> {
+        let key = cache_key(&params);
+        self.cached(
+            |cache| &mut cache.projects,
+            key,
+            self.inner.get_projects(params),
+        )
+        .await
+    }
+
+    async fn get_tasks(
+        &self,
+        params: endpoints::get_tasks::RequestBody,
+    ) -> Result<endpoints::get_tasks::ResponseBody, Error> {
+        let key = cache_key(&params);
+        self.cached(|cache| &mut cache.tasks, key, self.inner.get_tasks(params))
+            .await
+    }
+
+    async fn get_tags(
+        &self,
+        params: endpoints::get_tags::RequestBody,
+    ) -> Result<endpoints::get_tags::ResponseBody, Error> {
+        let key = cache_key(&params);
+        self.cached(|cache| &mut cache.tags, key, self.inner.get_tags(params))
+            .await
+    }
+
+    async fn get_time_entries(
+        &self,
+        params: endpoints::get_time_entries::RequestBody,
+    ) -> Result<endpoints::get_time_entries::ResponseBody, Error> {
+        self.inner.get_time_entries(params).await
+    }
+
+    async fn post_time_entries(
+        &self,
+        params: endpoints::post_time_entries::PathParams,
+        body: endpoints::post_time_entries::RequestBody,
+    ) -> Result<endpoints::post_time_entries::ResponseBody, Error> {
+        self.invalidate_after(self.inner.post_time_entries(params, body).await)
+    }
+
+    async fn put_time_entries(
+        &self,
+        params: endpoints::put_time_entries::PathParams,
+        body: endpoints::put_time_entries::RequestBody,
+    ) -> Result<endpoints::put_time_entries::ResponseBody, Error> {
+        self.invalidate_after(self.inner.put_time_entries(params, body).await)
+    }
+
+    async fn delete_time_entries(
+        &self,
+        params: endpoints::delete_time_entries::PathParams,
+    ) -> Result<(), Error> {
+        self.invalidate_after(self.inner.delete_time_entries(params).await)
+    }
+
+    async fn post_clients(
+        &self,
+        params: endpoints::post_clients::PathParams,
+        body: endpoints::post_clients::RequestBody,
+    ) -> Result<endpoints::post_clients::ResponseBody, Error> {
+        self.invalidate_after(self.inner.post_clients(params, body).await)
+    }
+
+    async fn post_projects(
+        &self,
+        params: endpoints::post_projects::PathParams,
+        body: endpoints::post_projects::RequestBody,
+    ) -> Result<endpoints::post_projects::ResponseBody, Error> {
+        self.invalidate_after(self.inner.post_projects(params, body).await)
+    }
+
+    async fn post_tasks(
+        &self,
+        params: endpoints::post_tasks::PathParams,
+        body: endpoints::post_tasks::RequestBody,
+    ) -> Result<endpoints::post_tasks::ResponseBody, Error> {
+        self.invalidate_after(self.inner.post_tasks(params, body).await)
+    }
+
+    async fn post_tags(
+        &self,
+        params: endpoints::post_tags::PathParams,
+        body: endpoints::post_tags::RequestBody,
+    ) -> Result<endpoints::post_tags::ResponseBody, Error> {
+        self.invalidate_after(self.inner.post_tags(params, body).await)
+    }
+
+    async fn get_organization_users(
+        &self,
+        params: endpoints::get_organization_users::PathParams,
+    ) -> Result<endpoints::get_organization_users::ResponseBody, Error> {
+        self.inner.get_organization_users(params).await
+    }
+
+    async fn post_organization_invitation(
+        &self,
+        params: endpoints::post_organization_invitation::PathParams,
+        body: endpoints::post_organization_invitation::RequestBody,
+    ) -> Result<endpoints::post_organization_invitation::ResponseBody, Error> {
+        self.invalidate_after(self.inner.post_organization_invitation(params, body).await)
+    }
+
+    async fn put_organization_users(
+        &self,
+        params: endpoints::put_organization_users::PathParams,
+        body: endpoints::put_organization_users::RequestBody,
+    ) -> Result<(), Error> {
+        self.invalidate_after(self.inner.put_organization_users(params, body).await)
+    }
+
+    async fn patch_organization_users(
+        &self,
+        params: endpoints::patch_organization_users::PathParams,
+        body: endpoints::patch_organization_users::RequestBody,
+    ) -> Result<(), Error> {
+        self.invalidate_after(self.inner.patch_organization_users(params, body).await)
+    }
+
+    async fn get_workspace_users(
+        &self,
+        params: endpoints::get_workspace_users::PathParams,
+    ) -> Result<endpoints::get_workspace_users::ResponseBody, Error> {
+        self.inner.get_workspace_users(params).await
+    }
+
+    async fn put_workspace_users(
+        &self,
+        params: endpoints::put_workspace_users::PathParams,
+        body: endpoints::put_workspace_users::RequestBody,
+    ) -> Result<endpoints::put_workspace_users::ResponseBody, Error> {
+        self.invalidate_after(self.inner.put_workspace_users(params, body).await)
+    }
+
+    async fn delete_workspace_users(
+        &self,
+        params: endpoints::delete_workspace_users::PathParams,
+    ) -> Result<(), Error> {
+        self.invalidate_after(self.inner.delete_workspace_users(params).await)
+    }
+
+    async fn get_organization_groups(
+        &self,
+        params: endpoints::get_organization_groups::PathParams,
+    ) -> Result<endpoints::get_organization_groups::ResponseBody, Error> {
+        self.inner.get_organization_groups(params).await
+    }
+
+    async fn post_organization_groups(
+        &self,
+        params: endpoints::post_organization_groups::PathParams,
+        body: endpoints::post_organization_groups::RequestBody,
+    ) -> Result<endpoints::post_organization_groups::ResponseBody, Error> {
+        self.invalidate_after(self.inner.post_organization_groups(params, body).await)
+    }
+
+    async fn put_organization_groups(
+        &self,
+        params: endpoints::put_organization_groups::PathParams,
+        body: endpoints::put_organization_groups::RequestBody,
+    ) -> Result<endpoints::put_organization_groups::ResponseBody, Error> {
+        self.invalidate_after(self.inner.put_organization_groups(params, body).await)
+    }
+
+    async fn patch_organization_groups(
+        &self,
+        params: endpoints::patch_organization_groups::PathParams,
+        body: endpoints::patch_organization_groups::RequestBody,
+    ) -> Result<endpoints::patch_organization_groups::ResponseBody, Error> {
+        self.invalidate_after(self.inner.patch_organization_groups(params, body).await)
+    }
+
+    async fn delete_organization_groups(
+        &self,
+        params: endpoints::delete_organization_groups::PathParams,
+    ) -> Result<(), Error> {
+        self.invalidate_after(self.inner.delete_organization_groups(params).await)
+    }
+
+    async fn get_workspace_groups(
+        &self,
+        params: endpoints::get_workspace_groups::PathParams,
+    ) -> Result<endpoints::get_workspace_groups::ResponseBody, Error> {
+        self.inner.get_workspace_groups(params).await
+    }
+
+    async fn get_project_users(
+        &self,
+        params: endpoints::get_project_users::PathParams,
+        query: endpoints::get_project_users::RequestBody,
+    ) -> Result<endpoints::get_project_users::ResponseBody, Error> {
+        self.inner.get_project_users(params, query).await
+    }
+
+    async fn post_project_users(
+        &self,
+        params: endpoints::post_project_users::PathParams,
+        body: endpoints::post_project_users::RequestBody,
+    ) -> Result<endpoints::post_project_users::ResponseBody, Error> {
+        self.invalidate_after(self.inner.post_project_users(params, body).await)
+    }
+
+    async fn put_project_users(
+        &self,
+        params: endpoints::put_project_users::PathParams,
+        body: endpoints::put_project_users::RequestBody,
+    ) -> Result<endpoints::put_project_users::ResponseBody, Error> {
+        self.invalidate_after(self.inner.put_project_users(params, body).await)
+    }
+
+    async fn patch_project_users(
+        &self,
+        params: endpoints::patch_project_users::PathParams,
+        body: endpoints::patch_project_users::RequestBody,
+    ) -> Result<endpoints::patch_project_users::ResponseBody, Error> {
+        self.invalidate_after(self.inner.patch_project_users(params, body).await)
+    }
+
+    async fn delete_project_users(
+        &self,
+        params: endpoints::delete_project_users::PathParams,
+    ) -> Result<(), Error> {
+        self.invalidate_after(self.inner.delete_project_users(params).await)
+    }
+
+    async fn put_me(
+        &self,
+        body: endpoints::put_me::RequestBody,
+    ) -> Result<endpoints::put_me::ResponseBody, Error> {
+        self.invalidate_after(self.inner.put_me(body).await)
+    }
+
+    async fn put_workspaces(
+        &self,
+        params: endpoints::put_workspaces::PathParams,
+        body: endpoints::put_workspaces::RequestBody,
+    ) -> Result<endpoints::put_workspaces::ResponseBody, Error> {
+        self.invalidate_after(self.inner.put_workspaces(params, body).await)
+    }
+
+    async fn get_preferences(&self) -> Result<endpoints::get_preferences::ResponseBody, Error> {
+        self.inner.get_preferences().await
+    }
+
+    async fn post_preferences(
+        &self,
+        body: endpoints::post_preferences::RequestBody,
+    ) -> Result<(), Error> {
+        self.invalidate_after(self.inner.post_preferences(body).await)
+    }
+
+    async fn get_workspace_preferences(
+        &self,
+        params: endpoints::get_workspace_preferences::PathParams,
+    ) -> Result<endpoints::get_workspace_preferences::ResponseBody, Error> {
+        self.inner.get_workspace_preferences(params).await
+    }
+
+    async fn post_workspace_preferences(
+        &self,
+        params: endpoints::post_workspace_preferences::PathParams,
+        body: endpoints::post_workspace_preferences::RequestBody,
+    ) -> Result<(), Error> {
+        self.invalidate_after(self.inner.post_workspace_preferences(params, body).await)
+    }
+
+    async fn get_dashboard_all_activity(
+        &self,
+        params: endpoints::get_dashboard_all_activity::PathParams,
+    ) -> Result<endpoints::get_dashboard_all_activity::ResponseBody, Error> {
+        self.inner.get_dashboard_all_activity(params).await
+    }
+
+    async fn get_dashboard_most_active(
+        &self,
+        params: endpoints::get_dashboard_most_active::PathParams,
+    ) -> Result<endpoints::get_dashboard_most_active::ResponseBody, Error> {
+        self.inner.get_dashboard_most_active(params).await
+    }
+
+    async fn post_reports_search_time_entries(
+        &self,
+        params: endpoints::post_reports_search_time_entries::PathParams,
+        body: endpoints::post_reports_search_time_entries::RequestBody,
+    ) -> Result<endpoints::post_reports_search_time_entries::ResponseBody, Error> {
+        self.inner
+            .post_reports_search_time_entries(params, body)
+            .await
+    }
+
+    async fn get_saved_reports(
+        &self,
+        params: endpoints::get_saved_reports::PathParams,
+    ) -> Result<endpoints::get_saved_reports::ResponseBody, Error> {
+        self.inner.get_saved_reports(params).await
+    }
+
+    async fn post_saved_reports(
+        &self,
+        params: endpoints::post_saved_reports::PathParams,
+        body: endpoints::post_saved_reports::RequestBody,
+    ) -> Result<endpoints::post_saved_reports::ResponseBody, Error> {
+        self.invalidate_after(self.inner.post_saved_reports(params, body).await)
+    }
+
+    async fn put_saved_reports(
+        &self,
+        params: endpoints::put_saved_reports::PathParams,
+        body: endpoints::put_saved_reports::RequestBody,
+    ) -> Result<endpoints::put_saved_reports::ResponseBody, Error> {
+        self.invalidate_after(self.inner.put_saved_reports(params, body).await)
+    }
+
+    async fn get_shared_report(
+        &self,
+        params: endpoints::get_shared_report::PathParams,
+    ) -> Result<endpoints::get_shared_report::ResponseBody, Error> {
+        self.inner.get_shared_report(params).await
+    }
+
+    async fn post_shared_report(
+        &self,
+        params: endpoints::post_shared_report::PathParams,
+        body: endpoints::post_shared_report::RequestBody,
+    ) -> Result<endpoints::post_shared_report::ResponseBody, Error> {
+        self.inner.post_shared_report(params, body).await
+    }
+
+    async fn export_report(
+        &self,
+        workspace_id: i64,
+        report: ReportKind,
+        format: ExportFormat,
+        filters: &ReportFilters,
+        writer: &mut (dyn AsyncWrite + Unpin + Send),
+    ) -> Result<u64, Error> {
+        self.inner
+            .export_report(workspace_id, report, format, filters, writer)
+            .await
+    }
+
+    async fn get_track_reminders(
+        &self,
+    ) -> Result<endpoints::get_track_reminders::ResponseBody, Error> {
+        self.inner.get_track_reminders().await
+    }
+
+    async fn get_workspace_track_reminders(
+        &self,
+        params: endpoints::get_workspace_track_reminders::PathParams,
+    ) -> Result<endpoints::get_workspace_track_reminders::ResponseBody, Error> {
+        self.inner.get_workspace_track_reminders(params).await
+    }
+
+    async fn post_track_reminders(
+        &self,
+        params: endpoints::post_track_reminders::PathParams,
+        body: endpoints::post_track_reminders::RequestBody,
+    ) -> Result<endpoints::post_track_reminders::ResponseBody, Error> {
+        self.invalidate_after(self.inner.post_track_reminders(params, body).await)
+    }
+
+    async fn put_track_reminders(
+        &self,
+        params: endpoints::put_track_reminders::PathParams,
+        body: endpoints::put_track_reminders::RequestBody,
+    ) -> Result<endpoints::put_track_reminders::ResponseBody, Error> {
+        self.invalidate_after(self.inner.put_track_reminders(params, body).await)
+    }
+
+    async fn delete_track_reminders(
+        &self,
+        params: endpoints::delete_track_reminders::PathParams,
+    ) -> Result<(), Error> {
+        self.invalidate_after(self.inner.delete_track_reminders(params).await)
+    }
+
+    async fn get_webhook_subscriptions(
+        &self,
+        params: endpoints::get_webhook_subscriptions::PathParams,
+    ) -> Result<endpoints::get_webhook_subscriptions::ResponseBody, Error> {
+        self.inner.get_webhook_subscriptions(params).await
+    }
+
+    async fn post_webhook_subscriptions(
+        &self,
+        params: endpoints::post_webhook_subscriptions::PathParams,
+        body: endpoints::post_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::post_webhook_subscriptions::ResponseBody, Error> {
+        self.invalidate_after(self.inner.post_webhook_subscriptions(params, body).await)
+    }
+
+    async fn put_webhook_subscriptions(
+        &self,
+        params: endpoints::put_webhook_subscriptions::PathParams,
+        body: endpoints::put_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::put_webhook_subscriptions::ResponseBody, Error> {
+        self.invalidate_after(self.inner.put_webhook_subscriptions(params, body).await)
+    }
+
+    async fn patch_webhook_subscriptions(
+        &self,
+        params: endpoints::patch_webhook_subscriptions::PathParams,
+        body: endpoints::patch_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::patch_webhook_subscriptions::ResponseBody, Error> {
+        self.invalidate_after(self.inner.patch_webhook_subscriptions(params, body).await)
+    }
+
+    async fn delete_webhook_subscriptions(
+        &self,
+        params: endpoints::delete_webhook_subscriptions::PathParams,
+    ) -> Result<endpoints::delete_webhook_subscriptions::ResponseBody, Error> {
+        self.invalidate_after(self.inner.delete_webhook_subscriptions(params).await)
+    }
+
+    async fn get_webhook_event_filters(
+        &self,
+    ) -> Result<endpoints::get_webhook_event_filters::ResponseBody, Error> {
+        self.inner.get_webhook_event_filters().await
+    }
+
+    async fn post_webhook_ping(
+        &self,
+        params: endpoints::post_webhook_ping::PathParams,
+    ) -> Result<(), Error> {
+        self.inner.post_webhook_ping(params).await
+    }
+}
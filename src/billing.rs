@@ -0,0 +1,260 @@
+use crate::models::{Project, ProjectUser, TimeEntry, Workspace, WorkspaceUser};
+use chrono::Duration;
+use std::collections::{BTreeMap, HashMap};
+
+/// How durations are rounded before being billed, as configured on the workspace
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Nearest,
+    Up,
+}
+
+impl Rounding {
+    /// Toggl stores the rounding mode as -1 (down), 0 (nearest) or 1 (up)
+    pub fn from_toggl(value: i64) -> Option<Self> {
+        match value {
+            -1 => Some(Rounding::Down),
+            0 => Some(Rounding::Nearest),
+            1 => Some(Rounding::Up),
+            _ => None,
+        }
+    }
+
+    pub fn to_toggl(self) -> i64 {
+        match self {
+            Rounding::Down => -1,
+            Rounding::Nearest => 0,
+            Rounding::Up => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundingRule {
+    pub mode: Rounding,
+    pub minutes: i64,
+}
+
+impl RoundingRule {
+    /// The rounding configured on a workspace, or `None` if durations are billed as tracked
+    pub fn from_workspace(workspace: &Workspace) -> Option<Self> {
+        if workspace.rounding_minutes <= 0 {
+            return None;
+        }
+        Some(RoundingRule {
+            mode: Rounding::from_toggl(workspace.rounding)?,
+            minutes: workspace.rounding_minutes,
+        })
+    }
+
+    pub fn apply(&self, duration: Duration) -> Duration {
+        let step = self.minutes * 60;
+        let seconds = duration.num_seconds();
+        let remainder = seconds % step;
+        if remainder == 0 {
+            return duration;
+        }
+        let rounded = match self.mode {
+            Rounding::Down => seconds - remainder,
+            Rounding::Up => seconds - remainder + step,
+            Rounding::Nearest if remainder * 2 >= step => seconds - remainder + step,
+            Rounding::Nearest => seconds - remainder,
+        };
+        Duration::seconds(rounded)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EntryAmount {
+    /// Time Entry ID
+    pub time_entry_id: i64,
+
+    /// Project ID, if the entry has one
+    pub project_id: Option<i64>,
+
+    /// Tracked duration after the workspace rounding was applied
+    pub duration: Duration,
+
+    /// Hourly rate the entry is billed at
+    pub rate: f64,
+
+    pub currency: String,
+
+    /// Billed amount, zero for entries on fixed fee projects
+    pub amount: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProjectAmount {
+    /// Project ID, `None` for billable entries without a project
+    pub project_id: Option<i64>,
+
+    pub currency: String,
+
+    /// Sum of the rounded durations of the project's billable entries
+    pub duration: Duration,
+
+    /// Either the fixed fee of the project or the sum of its entry amounts
+    pub amount: f64,
+
+    pub fixed_fee: bool,
+}
+
+/// Computes billable amounts for the time entries of a single workspace.
+///
+/// Rates come from the user's project membership when it sets one, then from the entry's project,
+/// then from the user's workspace membership, and fall back to the workspace default. Currencies
+/// come from the project or the workspace. Running entries and entries that aren't billable are
+/// ignored.
+pub struct Calculator<'a> {
+    workspace: &'a Workspace,
+    projects: HashMap<i64, &'a Project>,
+
+    /// Rates of project users, by project ID and user ID
+    user_rates: HashMap<(i64, i64), f32>,
+
+    /// Rates of workspace users, by user ID
+    workspace_user_rates: HashMap<i64, f32>,
+    rounding: Option<RoundingRule>,
+}
+
+impl<'a> Calculator<'a> {
+    pub fn new(workspace: &'a Workspace, projects: &'a [Project]) -> Self {
+        Self {
+            workspace,
+            projects: projects
+                .iter()
+                .filter(|project| project.workspace_id == workspace.id)
+                .map(|project| (project.id, project))
+                .collect(),
+            user_rates: HashMap::new(),
+            workspace_user_rates: HashMap::new(),
+            rounding: RoundingRule::from_workspace(workspace),
+        }
+    }
+
+    /// Override the rounding configured on the workspace
+    pub fn with_rounding(mut self, rounding: Option<RoundingRule>) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Bill users at the rates set on their project memberships
+    pub fn with_project_users(mut self, project_users: &[ProjectUser]) -> Self {
+        self.user_rates = project_users
+            .iter()
+            .filter(|project_user| project_user.workspace_id == self.workspace.id)
+            .filter_map(|project_user| {
+                let rate = project_user.rate?;
+                Some(((project_user.project_id, project_user.user_id), rate))
+            })
+            .collect();
+        self
+    }
+
+    /// Bill users of projects without a rate at the rates set on their workspace memberships
+    pub fn with_workspace_users(mut self, workspace_users: &[WorkspaceUser]) -> Self {
+        self.workspace_user_rates = workspace_users
+            .iter()
+            .filter(|workspace_user| workspace_user.wid == self.workspace.id)
+            .filter_map(|workspace_user| Some((workspace_user.uid, workspace_user.rate?)))
+            .collect();
+        self
+    }
+
+    fn project(&self, entry: &TimeEntry) -> Option<&'a Project> {
+        entry
+            .project_id
+            .and_then(|project_id| self.projects.get(&project_id).copied())
+    }
+
+    fn rate(&self, project: Option<&Project>, user_id: i64) -> f64 {
+        project
+            .and_then(|project| {
+                self.user_rates
+                    .get(&(project.id, user_id))
+                    .copied()
+                    .or(project.rate)
+            })
+            .or_else(|| self.workspace_user_rates.get(&user_id).copied())
+            .map(f64::from)
+            .unwrap_or(self.workspace.default_hourly_rate)
+    }
+
+    fn currency(&self, project: Option<&Project>) -> String {
+        project
+            .and_then(|project| project.currency.clone())
+            .unwrap_or_else(|| self.workspace.default_currency.clone())
+    }
+
+    pub fn entry_amount(&self, entry: &TimeEntry) -> Option<EntryAmount> {
+        if !entry.billable
+            || entry.workspace_id != self.workspace.id
+            || entry.server_deleted_at.is_some()
+            || entry.duration < Duration::zero()
+        {
+            return None;
+        }
+
+        let project = self.project(entry);
+        let duration = match self.rounding {
+            Some(rule) => rule.apply(entry.duration),
+            None => entry.duration,
+        };
+        let rate = self.rate(project, entry.user_id);
+        let amount = if project.and_then(|project| project.fixed_fee).is_some() {
+            0.0
+        } else {
+            rate * duration.num_seconds() as f64 / 3600.0
+        };
+
+        Some(EntryAmount {
+            time_entry_id: entry.id,
+            project_id: entry.project_id,
+            duration,
+            rate,
+            currency: self.currency(project),
+            amount,
+        })
+    }
+
+    pub fn project_amounts(&self, entries: &[TimeEntry]) -> Vec<ProjectAmount> {
+        let mut totals: BTreeMap<Option<i64>, ProjectAmount> = BTreeMap::new();
+        for entry_amount in entries.iter().filter_map(|entry| self.entry_amount(entry)) {
+            let total = totals
+                .entry(entry_amount.project_id)
+                .or_insert_with(|| ProjectAmount {
+                    project_id: entry_amount.project_id,
+                    currency: entry_amount.currency.clone(),
+                    duration: Duration::zero(),
+                    amount: 0.0,
+                    fixed_fee: false,
+                });
+            total.duration += entry_amount.duration;
+            total.amount += entry_amount.amount;
+        }
+
+        for total in totals.values_mut() {
+            let fixed_fee = total
+                .project_id
+                .and_then(|project_id| self.projects.get(&project_id))
+                .and_then(|project| project.fixed_fee);
+            if let Some(fixed_fee) = fixed_fee {
+                total.amount = f64::from(fixed_fee);
+                total.fixed_fee = true;
+            }
+        }
+
+        totals.into_values().collect()
+    }
+
+    /// Total billable amount per currency
+    pub fn totals_by_currency(&self, entries: &[TimeEntry]) -> BTreeMap<String, f64> {
+        let mut totals = BTreeMap::new();
+        for project_amount in self.project_amounts(entries) {
+            *totals.entry(project_amount.currency).or_insert(0.0) += project_amount.amount;
+        }
+        totals
+    }
+}
@@ -0,0 +1,588 @@
+use crate::client::{self, Auth, Drift, Error};
+use crate::endpoints;
+use crate::fixtures::{Recorder, Replayer};
+use crate::models::{ExportFormat, Project, ReportFilters, ReportKind, Workspace};
+use std::future::Future;
+use std::io::Write;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::AsyncWrite;
+use tokio::runtime::Runtime;
+
+/// A synchronous [`client::Client`] for code that doesn't run inside an async runtime.
+///
+/// Requests run to completion on a private single-threaded tokio runtime, so calling these
+/// methods from within an async context panics. Use the async client there instead.
+pub struct Client {
+    inner: client::Client,
+    runtime: Runtime,
+}
+
+impl Client {
+    pub fn new(auth: Auth) -> Self {
+        Self::from_async(client::Client::new(auth))
+    }
+
+    /// Sends every request to `base_url` instead of Toggl, e.g. a proxy or a local mock server
+    pub fn with_base_url(auth: Auth, base_url: &str) -> Self {
+        Self::from_async(client::Client::with_base_url(auth, base_url))
+    }
+
+    /// Answers every request from recorded fixtures without touching the network
+    pub fn replaying(replayer: Replayer) -> Self {
+        Self::from_async(client::Client::replaying(replayer))
+    }
+
+    /// Wraps an already configured async client
+    pub fn from_async(inner: client::Client) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("Couldn't start the blocking client's runtime");
+        Self { inner, runtime }
+    }
+
+    /// See [`client::Client::strict`]
+    pub fn strict(self) -> Self {
+        Self {
+            inner: self.inner.strict(),
+            ..self
+        }
+    }
+
+    /// The drift warnings collected since the last call, always empty unless [`Client::strict`]
+    pub fn take_drift(&self) -> Vec<Drift> {
+        self.inner.take_drift()
+    }
+
+    /// See [`client::Client::recording`]
+    pub fn recording(self, recorder: Recorder) -> Self {
+        Self {
+            inner: self.inner.recording(recorder),
+            ..self
+        }
+    }
+
+    /// See [`client::Client::log_bodies`]
+    pub fn log_bodies(self) -> Self {
+        Self {
+            inner: self.inner.log_bodies(),
+            ..self
+        }
+    }
+
+    /// See [`client::Client::retrying`]
+    pub fn retrying(self, max_retries: u32) -> Self {
+        Self {
+            inner: self.inner.retrying(max_retries),
+            ..self
+        }
+    }
+
+    /// See [`client::Client::into_auth`]
+    pub fn into_auth(self) -> Auth {
+        self.inner.into_auth()
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Succeeds if the credentials are valid
+    pub fn get_logged(&self) -> Result<(), Error> {
+        self.block_on(self.inner.get_logged())
+    }
+
+    pub fn get_me(&self) -> Result<endpoints::get_me::ResponseBody, Error> {
+        self.block_on(self.inner.get_me())
+    }
+
+    /// Organizations the user is part of
+    pub fn get_orgs(&self) -> Result<endpoints::get_orgs::ResponseBody, Error> {
+        self.block_on(self.inner.get_orgs())
+    }
+
+    pub fn put_me(
+        &self,
+        body: endpoints::put_me::RequestBody,
+    ) -> Result<endpoints::put_me::ResponseBody, Error> {
+        self.block_on(self.inner.put_me(body))
+    }
+
+    pub fn get_current_time_entry(
+        &self,
+    ) -> Result<endpoints::get_current_time_entry::ResponseBody, Error> {
+        self.block_on(self.inner.get_current_time_entry())
+    }
+
+    pub fn get_projects(
+        &self,
+        params: endpoints::get_projects::RequestBody,
+    ) -> Result<endpoints::get_projects::ResponseBody, Error> {
+        self.block_on(self.inner.get_projects(params))
+    }
+
+    pub fn get_clients(
+        &self,
+        params: endpoints::get_clients::RequestBody,
+    ) -> Result<endpoints::get_clients::ResponseBody, Error> {
+        self.block_on(self.inner.get_clients(params))
+    }
+
+    pub fn get_tags(
+        &self,
+        params: endpoints::get_tags::RequestBody,
+    ) -> Result<endpoints::get_tags::ResponseBody, Error> {
+        self.block_on(self.inner.get_tags(params))
+    }
+
+    pub fn get_workspaces(
+        &self,
+        params: endpoints::get_workspaces::RequestBody,
+    ) -> Result<endpoints::get_workspaces::ResponseBody, Error> {
+        self.block_on(self.inner.get_workspaces(params))
+    }
+
+    pub fn get_track_reminders(
+        &self,
+    ) -> Result<endpoints::get_track_reminders::ResponseBody, Error> {
+        self.block_on(self.inner.get_track_reminders())
+    }
+
+    pub fn get_tasks(
+        &self,
+        params: endpoints::get_tasks::RequestBody,
+    ) -> Result<endpoints::get_tasks::ResponseBody, Error> {
+        self.block_on(self.inner.get_tasks(params))
+    }
+
+    pub fn get_time_entries(
+        &self,
+        params: endpoints::get_time_entries::RequestBody,
+    ) -> Result<endpoints::get_time_entries::ResponseBody, Error> {
+        self.block_on(self.inner.get_time_entries(params))
+    }
+
+    pub fn post_time_entries(
+        &self,
+        params: endpoints::post_time_entries::PathParams,
+        body: endpoints::post_time_entries::RequestBody,
+    ) -> Result<endpoints::post_time_entries::ResponseBody, Error> {
+        self.block_on(self.inner.post_time_entries(params, body))
+    }
+
+    /// See [`client::Client::post_time_entries_validated`]
+    pub fn post_time_entries_validated(
+        &self,
+        params: endpoints::post_time_entries::PathParams,
+        body: endpoints::post_time_entries::RequestBody,
+        workspace: &Workspace,
+        projects: &[Project],
+    ) -> Result<endpoints::post_time_entries::ResponseBody, Error> {
+        self.block_on(
+            self.inner
+                .post_time_entries_validated(params, body, workspace, projects),
+        )
+    }
+
+    pub fn put_time_entries(
+        &self,
+        params: endpoints::put_time_entries::PathParams,
+        body: endpoints::put_time_entries::RequestBody,
+    ) -> Result<endpoints::put_time_entries::ResponseBody, Error> {
+        self.block_on(self.inner.put_time_entries(params, body))
+    }
+
+    pub fn delete_time_entries(
+        &self,
+        params: endpoints::delete_time_entries::PathParams,
+    ) -> Result<(), Error> {
+        self.block_on(self.inner.delete_time_entries(params))
+    }
+
+    pub fn post_clients(
+        &self,
+        params: endpoints::post_clients::PathParams,
+        body: endpoints::post_clients::RequestBody,
+    ) -> Result<endpoints::post_clients::ResponseBody, Error> {
+        self.block_on(self.inner.post_clients(params, body))
+    }
+
+    pub fn post_projects(
+        &self,
+        params: endpoints::post_projects::PathParams,
+        body: endpoints::post_projects::RequestBody,
+    ) -> Result<endpoints::post_projects::ResponseBody, Error> {
+        self.block_on(self.inner.post_projects(params, body))
+    }
+
+    pub fn post_tasks(
+        &self,
+        params: endpoints::post_tasks::PathParams,
+        body: endpoints::post_tasks::RequestBody,
+    ) -> Result<endpoints::post_tasks::ResponseBody, Error> {
+        self.block_on(self.inner.post_tasks(params, body))
+    }
+
+    pub fn post_tags(
+        &self,
+        params: endpoints::post_tags::PathParams,
+        body: endpoints::post_tags::RequestBody,
+    ) -> Result<endpoints::post_tags::ResponseBody, Error> {
+        self.block_on(self.inner.post_tags(params, body))
+    }
+
+    pub fn get_webhook_subscriptions(
+        &self,
+        params: endpoints::get_webhook_subscriptions::PathParams,
+    ) -> Result<endpoints::get_webhook_subscriptions::ResponseBody, Error> {
+        self.block_on(self.inner.get_webhook_subscriptions(params))
+    }
+
+    pub fn post_webhook_subscriptions(
+        &self,
+        params: endpoints::post_webhook_subscriptions::PathParams,
+        body: endpoints::post_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::post_webhook_subscriptions::ResponseBody, Error> {
+        self.block_on(self.inner.post_webhook_subscriptions(params, body))
+    }
+
+    pub fn put_webhook_subscriptions(
+        &self,
+        params: endpoints::put_webhook_subscriptions::PathParams,
+        body: endpoints::put_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::put_webhook_subscriptions::ResponseBody, Error> {
+        self.block_on(self.inner.put_webhook_subscriptions(params, body))
+    }
+
+    pub fn patch_webhook_subscriptions(
+        &self,
+        params: endpoints::patch_webhook_subscriptions::PathParams,
+        body: endpoints::patch_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::patch_webhook_subscriptions::ResponseBody, Error> {
+        self.block_on(self.inner.patch_webhook_subscriptions(params, body))
+    }
+
+    pub fn delete_webhook_subscriptions(
+        &self,
+        params: endpoints::delete_webhook_subscriptions::PathParams,
+    ) -> Result<endpoints::delete_webhook_subscriptions::ResponseBody, Error> {
+        self.block_on(self.inner.delete_webhook_subscriptions(params))
+    }
+
+    pub fn get_webhook_event_filters(
+        &self,
+    ) -> Result<endpoints::get_webhook_event_filters::ResponseBody, Error> {
+        self.block_on(self.inner.get_webhook_event_filters())
+    }
+
+    pub fn post_webhook_ping(
+        &self,
+        params: endpoints::post_webhook_ping::PathParams,
+    ) -> Result<(), Error> {
+        self.block_on(self.inner.post_webhook_ping(params))
+    }
+
+    pub fn get_organization_users(
+        &self,
+        params: endpoints::get_organization_users::PathParams,
+    ) -> Result<endpoints::get_organization_users::ResponseBody, Error> {
+        self.block_on(self.inner.get_organization_users(params))
+    }
+
+    pub fn post_organization_invitation(
+        &self,
+        params: endpoints::post_organization_invitation::PathParams,
+        body: endpoints::post_organization_invitation::RequestBody,
+    ) -> Result<endpoints::post_organization_invitation::ResponseBody, Error> {
+        self.block_on(self.inner.post_organization_invitation(params, body))
+    }
+
+    pub fn put_organization_users(
+        &self,
+        params: endpoints::put_organization_users::PathParams,
+        body: endpoints::put_organization_users::RequestBody,
+    ) -> Result<(), Error> {
+        self.block_on(self.inner.put_organization_users(params, body))
+    }
+
+    pub fn patch_organization_users(
+        &self,
+        params: endpoints::patch_organization_users::PathParams,
+        body: endpoints::patch_organization_users::RequestBody,
+    ) -> Result<(), Error> {
+        self.block_on(self.inner.patch_organization_users(params, body))
+    }
+
+    pub fn get_workspace_users(
+        &self,
+        params: endpoints::get_workspace_users::PathParams,
+    ) -> Result<endpoints::get_workspace_users::ResponseBody, Error> {
+        self.block_on(self.inner.get_workspace_users(params))
+    }
+
+    pub fn put_workspace_users(
+        &self,
+        params: endpoints::put_workspace_users::PathParams,
+        body: endpoints::put_workspace_users::RequestBody,
+    ) -> Result<endpoints::put_workspace_users::ResponseBody, Error> {
+        self.block_on(self.inner.put_workspace_users(params, body))
+    }
+
+    pub fn delete_workspace_users(
+        &self,
+        params: endpoints::delete_workspace_users::PathParams,
+    ) -> Result<(), Error> {
+        self.block_on(self.inner.delete_workspace_users(params))
+    }
+
+    pub fn get_organization_groups(
+        &self,
+        params: endpoints::get_organization_groups::PathParams,
+    ) -> Result<endpoints::get_organization_groups::ResponseBody, Error> {
+        self.block_on(self.inner.get_organization_groups(params))
+    }
+
+    pub fn post_organization_groups(
+        &self,
+        params: endpoints::post_organization_groups::PathParams,
+        body: endpoints::post_organization_groups::RequestBody,
+    ) -> Result<endpoints::post_organization_groups::ResponseBody, Error> {
+        self.block_on(self.inner.post_organization_groups(params, body))
+    }
+
+    pub fn put_organization_groups(
+        &self,
+        params: endpoints::put_organization_groups::PathParams,
+        body: endpoints::put_organization_groups::RequestBody,
+    ) -> Result<endpoints::put_organization_groups::ResponseBody, Error> {
+        self.block_on(self.inner.put_organization_groups(params, body))
+    }
+
+    pub fn patch_organization_groups(
+        &self,
+        params: endpoints::patch_organization_groups::PathParams,
+        body: endpoints::patch_organization_groups::RequestBody,
+    ) -> Result<endpoints::patch_organization_groups::ResponseBody, Error> {
+        self.block_on(self.inner.patch_organization_groups(params, body))
+    }
+
+    pub fn delete_organization_groups(
+        &self,
+        params: endpoints::delete_organization_groups::PathParams,
+    ) -> Result<(), Error> {
+        self.block_on(self.inner.delete_organization_groups(params))
+    }
+
+    pub fn get_workspace_groups(
+        &self,
+        params: endpoints::get_workspace_groups::PathParams,
+    ) -> Result<endpoints::get_workspace_groups::ResponseBody, Error> {
+        self.block_on(self.inner.get_workspace_groups(params))
+    }
+
+    pub fn get_project_users(
+        &self,
+        params: endpoints::get_project_users::PathParams,
+        query: endpoints::get_project_users::RequestBody,
+    ) -> Result<endpoints::get_project_users::ResponseBody, Error> {
+        self.block_on(self.inner.get_project_users(params, query))
+    }
+
+    pub fn post_project_users(
+        &self,
+        params: endpoints::post_project_users::PathParams,
+        body: endpoints::post_project_users::RequestBody,
+    ) -> Result<endpoints::post_project_users::ResponseBody, Error> {
+        self.block_on(self.inner.post_project_users(params, body))
+    }
+
+    pub fn put_project_users(
+        &self,
+        params: endpoints::put_project_users::PathParams,
+        body: endpoints::put_project_users::RequestBody,
+    ) -> Result<endpoints::put_project_users::ResponseBody, Error> {
+        self.block_on(self.inner.put_project_users(params, body))
+    }
+
+    pub fn patch_project_users(
+        &self,
+        params: endpoints::patch_project_users::PathParams,
+        body: endpoints::patch_project_users::RequestBody,
+    ) -> Result<endpoints::patch_project_users::ResponseBody, Error> {
+        self.block_on(self.inner.patch_project_users(params, body))
+    }
+
+    pub fn delete_project_users(
+        &self,
+        params: endpoints::delete_project_users::PathParams,
+    ) -> Result<(), Error> {
+        self.block_on(self.inner.delete_project_users(params))
+    }
+
+    pub fn put_workspaces(
+        &self,
+        params: endpoints::put_workspaces::PathParams,
+        body: endpoints::put_workspaces::RequestBody,
+    ) -> Result<endpoints::put_workspaces::ResponseBody, Error> {
+        self.block_on(self.inner.put_workspaces(params, body))
+    }
+
+    pub fn get_preferences(
+        &self,
+    ) -> Result<endpoints::get_preferences::ResponseBody, Error> {
+        self.block_on(self.inner.get_preferences())
+    }
+
+    pub fn post_preferences(
+        &self,
+        body: endpoints::post_preferences::RequestBody,
+    ) -> Result<(), Error> {
+        self.block_on(self.inner.post_preferences(body))
+    }
+
+    pub fn get_workspace_preferences(
+        &self,
+        params: endpoints::get_workspace_preferences::PathParams,
+    ) -> Result<endpoints::get_workspace_preferences::ResponseBody, Error> {
+        self.block_on(self.inner.get_workspace_preferences(params))
+    }
+
+    pub fn post_workspace_preferences(
+        &self,
+        params: endpoints::post_workspace_preferences::PathParams,
+        body: endpoints::post_workspace_preferences::RequestBody,
+    ) -> Result<(), Error> {
+        self.block_on(self.inner.post_workspace_preferences(params, body))
+    }
+
+    pub fn get_dashboard_all_activity(
+        &self,
+        params: endpoints::get_dashboard_all_activity::PathParams,
+    ) -> Result<endpoints::get_dashboard_all_activity::ResponseBody, Error> {
+        self.block_on(self.inner.get_dashboard_all_activity(params))
+    }
+
+    pub fn get_dashboard_most_active(
+        &self,
+        params: endpoints::get_dashboard_most_active::PathParams,
+    ) -> Result<endpoints::get_dashboard_most_active::ResponseBody, Error> {
+        self.block_on(self.inner.get_dashboard_most_active(params))
+    }
+
+    pub fn post_reports_search_time_entries(
+        &self,
+        params: endpoints::post_reports_search_time_entries::PathParams,
+        body: endpoints::post_reports_search_time_entries::RequestBody,
+    ) -> Result<endpoints::post_reports_search_time_entries::ResponseBody, Error> {
+        self.block_on(self.inner.post_reports_search_time_entries(params, body))
+    }
+
+    pub fn get_saved_reports(
+        &self,
+        params: endpoints::get_saved_reports::PathParams,
+    ) -> Result<endpoints::get_saved_reports::ResponseBody, Error> {
+        self.block_on(self.inner.get_saved_reports(params))
+    }
+
+    pub fn post_saved_reports(
+        &self,
+        params: endpoints::post_saved_reports::PathParams,
+        body: endpoints::post_saved_reports::RequestBody,
+    ) -> Result<endpoints::post_saved_reports::ResponseBody, Error> {
+        self.block_on(self.inner.post_saved_reports(params, body))
+    }
+
+    pub fn put_saved_reports(
+        &self,
+        params: endpoints::put_saved_reports::PathParams,
+        body: endpoints::put_saved_reports::RequestBody,
+    ) -> Result<endpoints::put_saved_reports::ResponseBody, Error> {
+        self.block_on(self.inner.put_saved_reports(params, body))
+    }
+
+    pub fn get_shared_report(
+        &self,
+        params: endpoints::get_shared_report::PathParams,
+    ) -> Result<endpoints::get_shared_report::ResponseBody, Error> {
+        self.block_on(self.inner.get_shared_report(params))
+    }
+
+    pub fn post_shared_report(
+        &self,
+        params: endpoints::post_shared_report::PathParams,
+        body: endpoints::post_shared_report::RequestBody,
+    ) -> Result<endpoints::post_shared_report::ResponseBody, Error> {
+        self.block_on(self.inner.post_shared_report(params, body))
+    }
+
+    /// Downloads the CSV or PDF export of a report into `writer` and returns its size, see
+    /// [`client::Client::export_report`]
+    pub fn export_report<W: Write>(
+        &self,
+        workspace_id: i64,
+        report: ReportKind,
+        format: ExportFormat,
+        filters: &ReportFilters,
+        writer: &mut W,
+    ) -> Result<u64, Error> {
+        self.block_on(self.inner.export_report(
+            workspace_id,
+            report,
+            format,
+            filters,
+            &mut SyncWriter(writer),
+        ))
+    }
+
+    pub fn get_workspace_track_reminders(
+        &self,
+        params: endpoints::get_workspace_track_reminders::PathParams,
+    ) -> Result<endpoints::get_workspace_track_reminders::ResponseBody, Error> {
+        self.block_on(self.inner.get_workspace_track_reminders(params))
+    }
+
+    pub fn post_track_reminders(
+        &self,
+        params: endpoints::post_track_reminders::PathParams,
+        body: endpoints::post_track_reminders::RequestBody,
+    ) -> Result<endpoints::post_track_reminders::ResponseBody, Error> {
+        self.block_on(self.inner.post_track_reminders(params, body))
+    }
+
+    pub fn put_track_reminders(
+        &self,
+        params: endpoints::put_track_reminders::PathParams,
+        body: endpoints::put_track_reminders::RequestBody,
+    ) -> Result<endpoints::put_track_reminders::ResponseBody, Error> {
+        self.block_on(self.inner.put_track_reminders(params, body))
+    }
+
+    pub fn delete_track_reminders(
+        &self,
+        params: endpoints::delete_track_reminders::PathParams,
+    ) -> Result<(), Error> {
+        self.block_on(self.inner.delete_track_reminders(params))
+    }
+}
+
+/// Lets the async client write into a [`Write`], blocking is what this client does anyway
+struct SyncWriter<'a, W>(&'a mut W);
+
+impl<W: Write> AsyncWrite for SyncWriter<'_, W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Poll::Ready(self.get_mut().0.write(buf))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(self.get_mut().0.flush())
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
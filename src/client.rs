@@ -1,77 +1,1460 @@
 use crate::endpoints;
+use crate::fixtures::{self, Recorder, Replayer};
+use crate::models::{ExportFormat, Project, ReportFilters, ReportKind, UnknownFields, Workspace};
+use crate::secret::Secret;
+use crate::validation::{self, ReminderViolation, Violation};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use thiserror::Error;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tracing::Instrument;
+
+/// Headers that carry credentials, never logged
+const SECRET_HEADERS: &[&str] = &["authorization", "cookie", "proxy-authorization"];
 
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("HTTP error: {0}")]
     Http(#[from] reqwest::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("no fixture recorded for {method} {url}")]
+    MissingFixture { method: String, url: String },
+
+    /// Rejected before sending, the API would have refused it too
+    #[error("invalid time entry: {}", display_violations(.0))]
+    InvalidTimeEntry(Vec<Violation>),
+
+    /// Rejected before sending, the API would have refused it too
+    #[error("invalid track reminder: {}", display_violations(.0))]
+    InvalidTrackReminder(Vec<ReminderViolation>),
+
+    /// An error response from the API, or from a fake or fixture standing in for it
+    #[error("API error {status}: {message}")]
+    Api {
+        status: reqwest::StatusCode,
+        message: String,
+    },
+}
+
+impl Error {
+    /// The HTTP status the API responded with, if the request got that far
+    pub fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Error::Http(err) => err.status(),
+            Error::Api { status, .. } => Some(*status),
+            Error::Json(_)
+            | Error::Io(_)
+            | Error::MissingFixture { .. }
+            | Error::InvalidTimeEntry(_)
+            | Error::InvalidTrackReminder(_) => None,
+        }
+    }
+}
+
+fn display_violations<V: ToString>(violations: &[V]) -> String {
+    let violations: Vec<_> = violations.iter().map(ToString::to_string).collect();
+    violations.join(", ")
 }
 
+/// Basic auth credentials, either an email and password or an API token and `"api_token"`
+#[derive(Debug, Clone)]
 pub struct Auth {
-    pub user: String,
-    pub password: String,
+    pub user: Secret,
+    pub password: Secret,
+}
+
+/// Fields a response had that the models don't know about, found in strict mode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Drift {
+    pub method: String,
+    pub path: String,
+    pub fields: Vec<String>,
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} returned unknown fields: {}",
+            self.method,
+            self.path,
+            self.fields.join(", ")
+        )
+    }
+}
+
+fn log_request(request: &reqwest::Request) {
+    let headers: Vec<String> = request
+        .headers()
+        .iter()
+        .map(|(name, value)| match SECRET_HEADERS.contains(&name.as_str()) {
+            true => format!("{}: {}", name, fixtures::REDACTED),
+            false => format!("{}: {}", name, String::from_utf8_lossy(value.as_bytes())),
+        })
+        .collect();
+    let body = request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .map(fixtures::redacted_body);
+    match body {
+        Some(body) => tracing::debug!(headers = ?headers, %body, "request"),
+        None => tracing::debug!(headers = ?headers, "request"),
+    }
+}
+
+/// The `toggl_request` span, see [`Client::execute`]
+fn request_span(endpoint: &'static str, request: &reqwest::Request) -> tracing::Span {
+    tracing::info_span!(
+        "toggl_request",
+        endpoint,
+        method = %request.method(),
+        path = fixtures::redact_path(request.url().path()),
+        status = tracing::field::Empty,
+        duration_ms = tracing::field::Empty,
+        retries = tracing::field::Empty,
+    )
+}
+
+/// Rate limiting is always worth another try. Server errors and lost connections only are for
+/// methods that can't create something twice.
+fn retryable(method: &reqwest::Method, err: &Error) -> bool {
+    let idempotent = matches!(
+        *method,
+        reqwest::Method::GET
+            | reqwest::Method::HEAD
+            | reqwest::Method::PUT
+            | reqwest::Method::DELETE
+    );
+    match err {
+        Error::Api { status, .. } if *status == reqwest::StatusCode::TOO_MANY_REQUESTS => true,
+        Error::Api { status, .. } => idempotent && status.is_server_error(),
+        Error::Http(err) => idempotent && (err.is_connect() || err.is_timeout()),
+        _ => false,
+    }
+}
+
+/// Turns an error response into [`Error::Api`]
+fn check_status(status: reqwest::StatusCode, body: &[u8]) -> Result<(), Error> {
+    if status.is_client_error() || status.is_server_error() {
+        return Err(api_error(status, body));
+    }
+    Ok(())
+}
+
+fn api_error(status: reqwest::StatusCode, body: &[u8]) -> Error {
+    tracing::warn!(status = status.as_u16(), "API error");
+    Error::Api {
+        status,
+        message: String::from_utf8_lossy(body).into_owned(),
+    }
+}
+
+/// Percent-encodes a string parameter that goes into a URL path
+fn path_segment(value: &str) -> String {
+    utf8_percent_encode(value, PATH_SEGMENT).to_string()
+}
+
+/// Everything but unreserved characters, RFC 3986 section 2.3
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Scheme and host every endpoint URL in [`endpoints`] starts with
+pub const DEFAULT_BASE_URL: &str = "https://api.track.toggl.com";
+
+enum Transport {
+    Http,
+    Record(Recorder),
+    Replay(Replayer),
 }
 
 pub struct Client {
     auth: Auth,
+    base_url: String,
     reqwest_client: reqwest::Client,
+    transport: Transport,
+    drift: Option<Mutex<Vec<Drift>>>,
+    log_bodies: bool,
+    max_retries: u32,
 }
 
 impl Client {
     pub fn new(auth: Auth) -> Self {
+        Self::with_base_url(auth, DEFAULT_BASE_URL)
+    }
+
+    /// Sends every request to `base_url` instead of Toggl, e.g. a proxy or a local mock server
+    pub fn with_base_url(auth: Auth, base_url: &str) -> Self {
         Self {
             auth,
+            base_url: base_url.trim_end_matches('/').to_string(),
             reqwest_client: reqwest::Client::new(),
+            transport: Transport::Http,
+            drift: None,
+            log_bodies: false,
+            max_retries: 0,
+        }
+    }
+
+    /// Keeps a [`Drift`] warning for every response with fields the models don't know about.
+    /// Responses are accepted either way, the unknown fields end up in the models' `extra` maps.
+    pub fn strict(self) -> Self {
+        Self {
+            drift: Some(Mutex::new(Vec::new())),
+            ..self
+        }
+    }
+
+    /// The drift warnings collected since the last call, always empty unless [`Client::strict`]
+    pub fn take_drift(&self) -> Vec<Drift> {
+        match &self.drift {
+            Some(drift) => std::mem::take(&mut *drift.lock().unwrap()),
+            None => Vec::new(),
         }
     }
 
+    /// Keeps talking to the API as usual, additionally writing every exchange to a redacted
+    /// fixture file
+    pub fn recording(self, recorder: Recorder) -> Self {
+        Self {
+            transport: Transport::Record(recorder),
+            ..self
+        }
+    }
+
+    /// Answers every request from recorded fixtures without touching the network
+    pub fn replaying(replayer: Replayer) -> Self {
+        let auth = Auth {
+            user: Secret::default(),
+            password: Secret::default(),
+        };
+        Self {
+            transport: Transport::Replay(replayer),
+            ..Self::new(auth)
+        }
+    }
+
+    /// Logs request headers and bodies, and response bodies, as `debug` events in each
+    /// request's span. Credentials, tokens and personal data are redacted.
+    pub fn log_bodies(self) -> Self {
+        Self {
+            log_bodies: true,
+            ..self
+        }
+    }
+
+    /// Retries a request up to `max_retries` times, waiting a second before the first retry and
+    /// twice as long before each one after. Rate limited requests are retried, server errors and
+    /// connection failures only for GET, HEAD, PUT and DELETE.
+    pub fn retrying(self, max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..self
+        }
+    }
+
+    /// Gives the credentials back, so they don't need to be cloned to check them with a
+    /// throwaway client
+    pub fn into_auth(self) -> Auth {
+        self.auth
+    }
+
+    /// Performs the request and returns the body of a successful response
+    async fn fetch(
+        &self,
+        endpoint: &'static str,
+        request: reqwest::RequestBuilder,
+    ) -> Result<Vec<u8>, Error> {
+        self.execute(endpoint, request.build()?).await
+    }
+
+    /// Runs the request in a `toggl_request` span carrying the endpoint module's name, method,
+    /// path, status, duration and the number of retries it took, see [`Client::retrying`]
+    async fn execute(
+        &self,
+        endpoint: &'static str,
+        request: reqwest::Request,
+    ) -> Result<Vec<u8>, Error> {
+        let span = request_span(endpoint, &request);
+        async {
+            if self.log_bodies {
+                log_request(&request);
+            }
+            let started = Instant::now();
+            let result = self
+                .with_retries(&span, request, |request| async {
+                    let (status, body) = self.exchange(request).await?;
+                    span.record("status", status.as_u16());
+                    if self.log_bodies {
+                        tracing::debug!(body = %fixtures::redacted_body(&body), "response");
+                    }
+                    check_status(status, &body)?;
+                    Ok(body)
+                })
+                .await;
+            span.record("duration_ms", started.elapsed().as_millis() as u64);
+            let body = result.inspect_err(|err| {
+                tracing::warn!(error = %err, "request failed");
+            })?;
+            tracing::debug!("request finished");
+            Ok(body)
+        }
+        .instrument(span.clone())
+        .await
+    }
+
+    /// Like [`Client::execute`], but writes the body of a successful response to `writer` as it
+    /// arrives and returns its size. Recording and replaying need the whole body, so those
+    /// write it out in one go.
+    async fn execute_into<W: AsyncWrite + Unpin>(
+        &self,
+        endpoint: &'static str,
+        request: reqwest::Request,
+        writer: &mut W,
+    ) -> Result<u64, Error> {
+        if !matches!(self.transport, Transport::Http) {
+            let body = self.execute(endpoint, request).await?;
+            writer.write_all(&body).await?;
+            return Ok(body.len() as u64);
+        }
+
+        let span = request_span(endpoint, &request);
+        async {
+            if self.log_bodies {
+                log_request(&request);
+            }
+            let started = Instant::now();
+            let result = async {
+                // Only retried until the body starts arriving
+                let mut response = self
+                    .with_retries(&span, request, |request| async {
+                        let response = self.reqwest_client.execute(request).await?;
+                        let status = response.status();
+                        span.record("status", status.as_u16());
+                        if status.is_client_error() || status.is_server_error() {
+                            return Err(api_error(status, &response.bytes().await?));
+                        }
+                        Ok(response)
+                    })
+                    .await?;
+                let mut size = 0;
+                while let Some(chunk) = response.chunk().await? {
+                    writer.write_all(&chunk).await?;
+                    size += chunk.len() as u64;
+                }
+                Ok(size)
+            }
+            .await;
+            span.record("duration_ms", started.elapsed().as_millis() as u64);
+            let size = result.inspect_err(|err: &Error| {
+                tracing::warn!(error = %err, "request failed");
+            })?;
+            if self.log_bodies {
+                tracing::debug!(size, "response streamed");
+            }
+            tracing::debug!("request finished");
+            Ok(size)
+        }
+        .instrument(span.clone())
+        .await
+    }
+
+    /// Runs `attempt` until it succeeds, fails in a way that isn't [`retryable`] or runs out of
+    /// retries. Records the retries made in `span`.
+    async fn with_retries<T, F, Fut>(
+        &self,
+        span: &tracing::Span,
+        request: reqwest::Request,
+        mut attempt: F,
+    ) -> Result<T, Error>
+    where
+        F: FnMut(reqwest::Request) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let method = request.method().clone();
+        let mut request = Some(request);
+        let mut backoff = Duration::from_secs(1);
+        let mut retries = 0;
+        loop {
+            let current = request.take().expect("a request is left for every attempt");
+            // Requests with streaming bodies can't be cloned, and aren't retried
+            request = match retries < self.max_retries {
+                true => current.try_clone(),
+                false => None,
+            };
+            let result = attempt(current).await;
+            span.record("retries", retries);
+            match result {
+                Err(err) if request.is_some() && retryable(&method, &err) => {
+                    tracing::warn!(error = %err, backoff = ?backoff, "retrying");
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(Duration::from_secs(60));
+                    retries += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn exchange(
+        &self,
+        request: reqwest::Request,
+    ) -> Result<(reqwest::StatusCode, Vec<u8>), Error> {
+        Ok(match &self.transport {
+            Transport::Replay(replayer) => {
+                let fixture = replayer.respond(&request).ok_or_else(|| {
+                    let mut url = request.url().clone();
+                    url.set_path(&fixtures::redact_path(url.path()));
+                    Error::MissingFixture {
+                        method: request.method().to_string(),
+                        url: url.to_string(),
+                    }
+                })?;
+                let status = reqwest::StatusCode::from_u16(fixture.response.status)
+                    .unwrap_or(reqwest::StatusCode::INTERNAL_SERVER_ERROR);
+                (status, fixture.body_bytes()?)
+            }
+            Transport::Http | Transport::Record(_) => {
+                let recorded = match self.transport {
+                    Transport::Record(_) => request.try_clone(),
+                    _ => None,
+                };
+                let response = self.reqwest_client.execute(request).await?;
+                let status = response.status();
+                let body = response.bytes().await?.to_vec();
+                if let (Transport::Record(recorder), Some(request)) = (&self.transport, recorded) {
+                    recorder.record(&request, status, &body)?;
+                }
+                (status, body)
+            }
+        })
+    }
+
+    async fn send<T: DeserializeOwned + UnknownFields>(
+        &self,
+        endpoint: &'static str,
+        request: reqwest::RequestBuilder,
+    ) -> Result<T, Error> {
+        let request = request.build()?;
+        let method = request.method().to_string();
+        let path = fixtures::redact_path(request.url().path());
+        let body = self.execute(endpoint, request).await?;
+        let response: T = serde_json::from_slice(&body)?;
+
+        if let Some(drift) = &self.drift {
+            let mut fields = Vec::new();
+            response.unknown_fields("", &mut fields);
+            if !fields.is_empty() {
+                drift.lock().unwrap().push(Drift {
+                    method,
+                    path,
+                    fields,
+                });
+            }
+        }
+        Ok(response)
+    }
+
+    fn url(&self, endpoint_url: &str) -> String {
+        match endpoint_url.strip_prefix(DEFAULT_BASE_URL) {
+            Some(path) => format!("{}{}", self.base_url, path),
+            None => endpoint_url.to_string(),
+        }
+    }
+
+    /// Succeeds if the credentials are valid
+    pub async fn get_logged(&self) -> Result<(), Error> {
+        let url = self.url(endpoints::get_logged::BASE_URL);
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.fetch("get_logged", request).await?;
+        Ok(())
+    }
+
     pub async fn get_me(&self) -> Result<endpoints::get_me::ResponseBody, Error> {
-        let url = endpoints::get_me::BASE_URL;
-        let response = self
+        let url = self.url(endpoints::get_me::BASE_URL);
+        let request = self
             .reqwest_client
             .get(url)
-            .basic_auth(&self.auth.user, Some(&self.auth.password))
-            .send()
-            .await?
-            .json()
-            .await?;
-        Ok(response)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_me", request).await
+    }
+
+    /// Organizations the user is part of
+    pub async fn get_orgs(&self) -> Result<endpoints::get_orgs::ResponseBody, Error> {
+        let url = self.url(endpoints::get_orgs::BASE_URL);
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_orgs", request).await
+    }
+
+    pub async fn put_me(
+        &self,
+        body: endpoints::put_me::RequestBody,
+    ) -> Result<endpoints::put_me::ResponseBody, Error> {
+        let url = self.url(endpoints::put_me::BASE_URL);
+        let request = self
+            .reqwest_client
+            .put(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("put_me", request).await
     }
 
     pub async fn get_current_time_entry(
         &self,
     ) -> Result<endpoints::get_current_time_entry::ResponseBody, Error> {
-        let url = endpoints::get_current_time_entry::BASE_URL;
-        let response = self
+        let url = self.url(endpoints::get_current_time_entry::BASE_URL);
+        let request = self
             .reqwest_client
             .get(url)
-            .basic_auth(&self.auth.user, Some(&self.auth.password))
-            .send()
-            .await?
-            .json()
-            .await?;
-        Ok(response)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_current_time_entry", request).await
     }
 
     pub async fn get_projects(
         &self,
         params: endpoints::get_projects::RequestBody,
     ) -> Result<endpoints::get_projects::ResponseBody, Error> {
-        let mut url = reqwest::Url::parse(endpoints::get_projects::BASE_URL).unwrap();
+        let mut url = reqwest::Url::parse(&self.url(endpoints::get_projects::BASE_URL)).unwrap();
         if let Some(include_archived) = params.include_archived {
             url.query_pairs_mut().append_pair("include_archived", &include_archived.to_string());
         }
         if let Some(since) = params.since {
-            url.query_pairs_mut().append_pair("since", &since.to_string());
+            url.query_pairs_mut().append_pair("since", &since.timestamp().to_string());
         }
-        let response = self
+        let request = self
             .reqwest_client
             .get(url)
-            .basic_auth(&self.auth.user, Some(&self.auth.password))
-            .send()
-            .await?
-            .json()
-            .await?;
-        Ok(response)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_projects", request).await
+    }
+
+    pub async fn get_clients(
+        &self,
+        params: endpoints::get_clients::RequestBody,
+    ) -> Result<endpoints::get_clients::ResponseBody, Error> {
+        let mut url = reqwest::Url::parse(&self.url(endpoints::get_clients::BASE_URL)).unwrap();
+        if let Some(since) = params.since {
+            url.query_pairs_mut().append_pair("since", &since.timestamp().to_string());
+        }
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_clients", request).await
+    }
+
+    pub async fn get_tags(
+        &self,
+        params: endpoints::get_tags::RequestBody,
+    ) -> Result<endpoints::get_tags::ResponseBody, Error> {
+        let mut url = reqwest::Url::parse(&self.url(endpoints::get_tags::BASE_URL)).unwrap();
+        if let Some(since) = params.since {
+            url.query_pairs_mut().append_pair("since", &since.timestamp().to_string());
+        }
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_tags", request).await
+    }
+
+    pub async fn get_workspaces(
+        &self,
+        params: endpoints::get_workspaces::RequestBody,
+    ) -> Result<endpoints::get_workspaces::ResponseBody, Error> {
+        let mut url = reqwest::Url::parse(&self.url(endpoints::get_workspaces::BASE_URL)).unwrap();
+        if let Some(since) = params.since {
+            url.query_pairs_mut().append_pair("since", &since.timestamp().to_string());
+        }
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_workspaces", request).await
+    }
+
+    pub async fn get_track_reminders(
+        &self,
+    ) -> Result<endpoints::get_track_reminders::ResponseBody, Error> {
+        let url = self.url(endpoints::get_track_reminders::BASE_URL);
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_track_reminders", request).await
+    }
+
+    pub async fn get_tasks(
+        &self,
+        params: endpoints::get_tasks::RequestBody,
+    ) -> Result<endpoints::get_tasks::ResponseBody, Error> {
+        let mut url = reqwest::Url::parse(&self.url(endpoints::get_tasks::BASE_URL)).unwrap();
+        if let Some(since) = params.since {
+            url.query_pairs_mut().append_pair("since", &since.timestamp().to_string());
+        }
+        if let Some(include_not_active) = params.include_not_active {
+            url.query_pairs_mut().append_pair("include_not_active", &include_not_active.to_string());
+        }
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_tasks", request).await
+    }
+
+    pub async fn get_time_entries(
+        &self,
+        params: endpoints::get_time_entries::RequestBody,
+    ) -> Result<endpoints::get_time_entries::ResponseBody, Error> {
+        let mut url = reqwest::Url::parse(&self.url(endpoints::get_time_entries::BASE_URL)).unwrap();
+        if let Some(since) = params.since {
+            url.query_pairs_mut().append_pair("since", &since.timestamp().to_string());
+        }
+        if let Some(before) = params.before {
+            url.query_pairs_mut().append_pair("before", &before.to_rfc3339());
+        }
+        if let Some(start_date) = params.start_date {
+            url.query_pairs_mut().append_pair("start_date", &start_date.to_string());
+        }
+        if let Some(end_date) = params.end_date {
+            url.query_pairs_mut().append_pair("end_date", &end_date.to_string());
+        }
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_time_entries", request).await
+    }
+
+    /// Fails with [`Error::InvalidTimeEntry`] without sending anything if the body is for another
+    /// workspace than the URL. See [`Client::post_time_entries_validated`] to check the
+    /// workspace's constraints too.
+    pub async fn post_time_entries(
+        &self,
+        params: endpoints::post_time_entries::PathParams,
+        body: endpoints::post_time_entries::RequestBody,
+    ) -> Result<endpoints::post_time_entries::ResponseBody, Error> {
+        if body.workspace_id != params.workspace_id {
+            return Err(Error::InvalidTimeEntry(vec![Violation::WrongWorkspace {
+                expected: params.workspace_id,
+                actual: body.workspace_id,
+            }]));
+        }
+        let url = self.url(endpoints::post_time_entries::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .post(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("post_time_entries", request).await
+    }
+
+    /// Checks the entry with [`validation::validate_time_entry`] against `workspace` and its
+    /// `projects` first, and fails with [`Error::InvalidTimeEntry`] listing every violation
+    /// without sending anything if there are any
+    pub async fn post_time_entries_validated(
+        &self,
+        params: endpoints::post_time_entries::PathParams,
+        body: endpoints::post_time_entries::RequestBody,
+        workspace: &Workspace,
+        projects: &[Project],
+    ) -> Result<endpoints::post_time_entries::ResponseBody, Error> {
+        let violations = validation::validate_time_entry(&body, workspace, projects);
+        if !violations.is_empty() {
+            return Err(Error::InvalidTimeEntry(violations));
+        }
+        self.post_time_entries(params, body).await
+    }
+
+    pub async fn put_time_entries(
+        &self,
+        params: endpoints::put_time_entries::PathParams,
+        body: endpoints::put_time_entries::RequestBody,
+    ) -> Result<endpoints::put_time_entries::ResponseBody, Error> {
+        let url = self.url(endpoints::put_time_entries::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string())
+            .replace("{time_entry_id}", &params.time_entry_id.to_string());
+        let request = self
+            .reqwest_client
+            .put(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("put_time_entries", request).await
+    }
+
+    pub async fn delete_time_entries(
+        &self,
+        params: endpoints::delete_time_entries::PathParams,
+    ) -> Result<(), Error> {
+        let url = self.url(endpoints::delete_time_entries::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string())
+            .replace("{time_entry_id}", &params.time_entry_id.to_string());
+        let request = self
+            .reqwest_client
+            .delete(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.fetch("delete_time_entries", request).await?;
+        Ok(())
+    }
+
+    pub async fn post_clients(
+        &self,
+        params: endpoints::post_clients::PathParams,
+        body: endpoints::post_clients::RequestBody,
+    ) -> Result<endpoints::post_clients::ResponseBody, Error> {
+        let url = self.url(endpoints::post_clients::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .post(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("post_clients", request).await
+    }
+
+    pub async fn post_projects(
+        &self,
+        params: endpoints::post_projects::PathParams,
+        body: endpoints::post_projects::RequestBody,
+    ) -> Result<endpoints::post_projects::ResponseBody, Error> {
+        let url = self.url(endpoints::post_projects::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .post(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("post_projects", request).await
+    }
+
+    pub async fn post_tasks(
+        &self,
+        params: endpoints::post_tasks::PathParams,
+        body: endpoints::post_tasks::RequestBody,
+    ) -> Result<endpoints::post_tasks::ResponseBody, Error> {
+        let url = self.url(endpoints::post_tasks::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string())
+            .replace("{project_id}", &params.project_id.to_string());
+        let request = self
+            .reqwest_client
+            .post(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("post_tasks", request).await
+    }
+
+    pub async fn post_tags(
+        &self,
+        params: endpoints::post_tags::PathParams,
+        body: endpoints::post_tags::RequestBody,
+    ) -> Result<endpoints::post_tags::ResponseBody, Error> {
+        let url = self.url(endpoints::post_tags::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .post(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("post_tags", request).await
+    }
+
+    pub async fn get_webhook_subscriptions(
+        &self,
+        params: endpoints::get_webhook_subscriptions::PathParams,
+    ) -> Result<endpoints::get_webhook_subscriptions::ResponseBody, Error> {
+        let url = self.url(endpoints::get_webhook_subscriptions::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_webhook_subscriptions", request).await
+    }
+
+    pub async fn post_webhook_subscriptions(
+        &self,
+        params: endpoints::post_webhook_subscriptions::PathParams,
+        body: endpoints::post_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::post_webhook_subscriptions::ResponseBody, Error> {
+        let url = self.url(endpoints::post_webhook_subscriptions::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .post(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("post_webhook_subscriptions", request).await
+    }
+
+    pub async fn put_webhook_subscriptions(
+        &self,
+        params: endpoints::put_webhook_subscriptions::PathParams,
+        body: endpoints::put_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::put_webhook_subscriptions::ResponseBody, Error> {
+        let url = self.url(endpoints::put_webhook_subscriptions::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string())
+            .replace("{subscription_id}", &params.subscription_id.to_string());
+        let request = self
+            .reqwest_client
+            .put(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("put_webhook_subscriptions", request).await
+    }
+
+    pub async fn patch_webhook_subscriptions(
+        &self,
+        params: endpoints::patch_webhook_subscriptions::PathParams,
+        body: endpoints::patch_webhook_subscriptions::RequestBody,
+    ) -> Result<endpoints::patch_webhook_subscriptions::ResponseBody, Error> {
+        let url = self.url(endpoints::patch_webhook_subscriptions::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string())
+            .replace("{subscription_id}", &params.subscription_id.to_string());
+        let request = self
+            .reqwest_client
+            .patch(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("patch_webhook_subscriptions", request).await
+    }
+
+    pub async fn delete_webhook_subscriptions(
+        &self,
+        params: endpoints::delete_webhook_subscriptions::PathParams,
+    ) -> Result<endpoints::delete_webhook_subscriptions::ResponseBody, Error> {
+        let url = self.url(endpoints::delete_webhook_subscriptions::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string())
+            .replace("{subscription_id}", &params.subscription_id.to_string());
+        let request = self
+            .reqwest_client
+            .delete(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("delete_webhook_subscriptions", request).await
+    }
+
+    pub async fn get_webhook_event_filters(
+        &self,
+    ) -> Result<endpoints::get_webhook_event_filters::ResponseBody, Error> {
+        let url = self.url(endpoints::get_webhook_event_filters::BASE_URL);
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_webhook_event_filters", request).await
+    }
+
+    pub async fn post_webhook_ping(
+        &self,
+        params: endpoints::post_webhook_ping::PathParams,
+    ) -> Result<(), Error> {
+        let url = self.url(endpoints::post_webhook_ping::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string())
+            .replace("{subscription_id}", &params.subscription_id.to_string());
+        let request = self
+            .reqwest_client
+            .post(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.fetch("post_webhook_ping", request).await?;
+        Ok(())
+    }
+
+    pub async fn get_organization_users(
+        &self,
+        params: endpoints::get_organization_users::PathParams,
+    ) -> Result<endpoints::get_organization_users::ResponseBody, Error> {
+        let url = self.url(endpoints::get_organization_users::BASE_URL)
+            .replace("{organization_id}", &params.organization_id.to_string());
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_organization_users", request).await
+    }
+
+    pub async fn post_organization_invitation(
+        &self,
+        params: endpoints::post_organization_invitation::PathParams,
+        body: endpoints::post_organization_invitation::RequestBody,
+    ) -> Result<endpoints::post_organization_invitation::ResponseBody, Error> {
+        let url = self.url(endpoints::post_organization_invitation::BASE_URL)
+            .replace("{organization_id}", &params.organization_id.to_string());
+        let request = self
+            .reqwest_client
+            .post(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("post_organization_invitation", request).await
+    }
+
+    pub async fn put_organization_users(
+        &self,
+        params: endpoints::put_organization_users::PathParams,
+        body: endpoints::put_organization_users::RequestBody,
+    ) -> Result<(), Error> {
+        let url = self.url(endpoints::put_organization_users::BASE_URL)
+            .replace("{organization_id}", &params.organization_id.to_string())
+            .replace("{organization_user_id}", &params.organization_user_id.to_string());
+        let request = self
+            .reqwest_client
+            .put(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.fetch("put_organization_users", request).await?;
+        Ok(())
+    }
+
+    pub async fn patch_organization_users(
+        &self,
+        params: endpoints::patch_organization_users::PathParams,
+        body: endpoints::patch_organization_users::RequestBody,
+    ) -> Result<(), Error> {
+        let url = self.url(endpoints::patch_organization_users::BASE_URL)
+            .replace("{organization_id}", &params.organization_id.to_string());
+        let request = self
+            .reqwest_client
+            .patch(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.fetch("patch_organization_users", request).await?;
+        Ok(())
+    }
+
+    pub async fn get_workspace_users(
+        &self,
+        params: endpoints::get_workspace_users::PathParams,
+    ) -> Result<endpoints::get_workspace_users::ResponseBody, Error> {
+        let url = self.url(endpoints::get_workspace_users::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_workspace_users", request).await
+    }
+
+    pub async fn put_workspace_users(
+        &self,
+        params: endpoints::put_workspace_users::PathParams,
+        body: endpoints::put_workspace_users::RequestBody,
+    ) -> Result<endpoints::put_workspace_users::ResponseBody, Error> {
+        let url = self.url(endpoints::put_workspace_users::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string())
+            .replace("{workspace_user_id}", &params.workspace_user_id.to_string());
+        let request = self
+            .reqwest_client
+            .put(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("put_workspace_users", request).await
+    }
+
+    pub async fn delete_workspace_users(
+        &self,
+        params: endpoints::delete_workspace_users::PathParams,
+    ) -> Result<(), Error> {
+        let url = self.url(endpoints::delete_workspace_users::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string())
+            .replace("{workspace_user_id}", &params.workspace_user_id.to_string());
+        let request = self
+            .reqwest_client
+            .delete(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.fetch("delete_workspace_users", request).await?;
+        Ok(())
+    }
+
+    pub async fn get_organization_groups(
+        &self,
+        params: endpoints::get_organization_groups::PathParams,
+    ) -> Result<endpoints::get_organization_groups::ResponseBody, Error> {
+        let url = self.url(endpoints::get_organization_groups::BASE_URL)
+            .replace("{organization_id}", &params.organization_id.to_string());
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_organization_groups", request).await
+    }
+
+    pub async fn post_organization_groups(
+        &self,
+        params: endpoints::post_organization_groups::PathParams,
+        body: endpoints::post_organization_groups::RequestBody,
+    ) -> Result<endpoints::post_organization_groups::ResponseBody, Error> {
+        let url = self.url(endpoints::post_organization_groups::BASE_URL)
+            .replace("{organization_id}", &params.organization_id.to_string());
+        let request = self
+            .reqwest_client
+            .post(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("post_organization_groups", request).await
+    }
+
+    pub async fn put_organization_groups(
+        &self,
+        params: endpoints::put_organization_groups::PathParams,
+        body: endpoints::put_organization_groups::RequestBody,
+    ) -> Result<endpoints::put_organization_groups::ResponseBody, Error> {
+        let url = self.url(endpoints::put_organization_groups::BASE_URL)
+            .replace("{organization_id}", &params.organization_id.to_string())
+            .replace("{group_id}", &params.group_id.to_string());
+        let request = self
+            .reqwest_client
+            .put(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("put_organization_groups", request).await
+    }
+
+    pub async fn patch_organization_groups(
+        &self,
+        params: endpoints::patch_organization_groups::PathParams,
+        body: endpoints::patch_organization_groups::RequestBody,
+    ) -> Result<endpoints::patch_organization_groups::ResponseBody, Error> {
+        let url = self.url(endpoints::patch_organization_groups::BASE_URL)
+            .replace("{organization_id}", &params.organization_id.to_string())
+            .replace("{group_id}", &params.group_id.to_string());
+        let request = self
+            .reqwest_client
+            .patch(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("patch_organization_groups", request).await
+    }
+
+    pub async fn delete_organization_groups(
+        &self,
+        params: endpoints::delete_organization_groups::PathParams,
+    ) -> Result<(), Error> {
+        let url = self.url(endpoints::delete_organization_groups::BASE_URL)
+            .replace("{organization_id}", &params.organization_id.to_string())
+            .replace("{group_id}", &params.group_id.to_string());
+        let request = self
+            .reqwest_client
+            .delete(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.fetch("delete_organization_groups", request).await?;
+        Ok(())
+    }
+
+    pub async fn get_workspace_groups(
+        &self,
+        params: endpoints::get_workspace_groups::PathParams,
+    ) -> Result<endpoints::get_workspace_groups::ResponseBody, Error> {
+        let url = self.url(endpoints::get_workspace_groups::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_workspace_groups", request).await
+    }
+
+    pub async fn get_project_users(
+        &self,
+        params: endpoints::get_project_users::PathParams,
+        query: endpoints::get_project_users::RequestBody,
+    ) -> Result<endpoints::get_project_users::ResponseBody, Error> {
+        let url = self.url(endpoints::get_project_users::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let mut url = reqwest::Url::parse(&url).unwrap();
+        if let Some(project_ids) = query.project_ids {
+            let project_ids: Vec<_> = project_ids.iter().map(i64::to_string).collect();
+            url.query_pairs_mut().append_pair("project_ids", &project_ids.join(","));
+        }
+        if let Some(user_id) = query.user_id {
+            url.query_pairs_mut().append_pair("user_id", &user_id.to_string());
+        }
+        if let Some(with_group_members) = query.with_group_members {
+            url.query_pairs_mut().append_pair("with_group_members", &with_group_members.to_string());
+        }
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_project_users", request).await
+    }
+
+    pub async fn post_project_users(
+        &self,
+        params: endpoints::post_project_users::PathParams,
+        body: endpoints::post_project_users::RequestBody,
+    ) -> Result<endpoints::post_project_users::ResponseBody, Error> {
+        let url = self.url(endpoints::post_project_users::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .post(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("post_project_users", request).await
+    }
+
+    pub async fn put_project_users(
+        &self,
+        params: endpoints::put_project_users::PathParams,
+        body: endpoints::put_project_users::RequestBody,
+    ) -> Result<endpoints::put_project_users::ResponseBody, Error> {
+        let url = self.url(endpoints::put_project_users::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string())
+            .replace("{project_user_id}", &params.project_user_id.to_string());
+        let request = self
+            .reqwest_client
+            .put(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("put_project_users", request).await
+    }
+
+    pub async fn patch_project_users(
+        &self,
+        params: endpoints::patch_project_users::PathParams,
+        body: endpoints::patch_project_users::RequestBody,
+    ) -> Result<endpoints::patch_project_users::ResponseBody, Error> {
+        let project_user_ids: Vec<_> = params.project_user_ids.iter().map(i64::to_string).collect();
+        let url = self.url(endpoints::patch_project_users::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string())
+            .replace("{project_user_ids}", &project_user_ids.join(","));
+        let request = self
+            .reqwest_client
+            .patch(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("patch_project_users", request).await
+    }
+
+    pub async fn delete_project_users(
+        &self,
+        params: endpoints::delete_project_users::PathParams,
+    ) -> Result<(), Error> {
+        let url = self.url(endpoints::delete_project_users::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string())
+            .replace("{project_user_id}", &params.project_user_id.to_string());
+        let request = self
+            .reqwest_client
+            .delete(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.fetch("delete_project_users", request).await?;
+        Ok(())
+    }
+
+    pub async fn put_workspaces(
+        &self,
+        params: endpoints::put_workspaces::PathParams,
+        body: endpoints::put_workspaces::RequestBody,
+    ) -> Result<endpoints::put_workspaces::ResponseBody, Error> {
+        let url = self.url(endpoints::put_workspaces::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .put(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("put_workspaces", request).await
+    }
+
+    pub async fn get_preferences(
+        &self,
+    ) -> Result<endpoints::get_preferences::ResponseBody, Error> {
+        let url = self.url(endpoints::get_preferences::BASE_URL);
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_preferences", request).await
+    }
+
+    pub async fn post_preferences(
+        &self,
+        body: endpoints::post_preferences::RequestBody,
+    ) -> Result<(), Error> {
+        let url = self.url(endpoints::post_preferences::BASE_URL);
+        let request = self
+            .reqwest_client
+            .post(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.fetch("post_preferences", request).await?;
+        Ok(())
+    }
+
+    pub async fn get_workspace_preferences(
+        &self,
+        params: endpoints::get_workspace_preferences::PathParams,
+    ) -> Result<endpoints::get_workspace_preferences::ResponseBody, Error> {
+        let url = self.url(endpoints::get_workspace_preferences::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_workspace_preferences", request).await
+    }
+
+    pub async fn post_workspace_preferences(
+        &self,
+        params: endpoints::post_workspace_preferences::PathParams,
+        body: endpoints::post_workspace_preferences::RequestBody,
+    ) -> Result<(), Error> {
+        let url = self.url(endpoints::post_workspace_preferences::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .post(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.fetch("post_workspace_preferences", request).await?;
+        Ok(())
+    }
+
+    pub async fn get_dashboard_all_activity(
+        &self,
+        params: endpoints::get_dashboard_all_activity::PathParams,
+    ) -> Result<endpoints::get_dashboard_all_activity::ResponseBody, Error> {
+        let url = self.url(endpoints::get_dashboard_all_activity::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_dashboard_all_activity", request).await
+    }
+
+    pub async fn get_dashboard_most_active(
+        &self,
+        params: endpoints::get_dashboard_most_active::PathParams,
+    ) -> Result<endpoints::get_dashboard_most_active::ResponseBody, Error> {
+        let url = self.url(endpoints::get_dashboard_most_active::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_dashboard_most_active", request).await
+    }
+
+    pub async fn post_reports_search_time_entries(
+        &self,
+        params: endpoints::post_reports_search_time_entries::PathParams,
+        body: endpoints::post_reports_search_time_entries::RequestBody,
+    ) -> Result<endpoints::post_reports_search_time_entries::ResponseBody, Error> {
+        let url = self.url(endpoints::post_reports_search_time_entries::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .post(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("post_reports_search_time_entries", request).await
+    }
+
+    pub async fn get_saved_reports(
+        &self,
+        params: endpoints::get_saved_reports::PathParams,
+    ) -> Result<endpoints::get_saved_reports::ResponseBody, Error> {
+        let url = self.url(endpoints::get_saved_reports::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_saved_reports", request).await
+    }
+
+    pub async fn post_saved_reports(
+        &self,
+        params: endpoints::post_saved_reports::PathParams,
+        body: endpoints::post_saved_reports::RequestBody,
+    ) -> Result<endpoints::post_saved_reports::ResponseBody, Error> {
+        let url = self.url(endpoints::post_saved_reports::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .post(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("post_saved_reports", request).await
+    }
+
+    pub async fn put_saved_reports(
+        &self,
+        params: endpoints::put_saved_reports::PathParams,
+        body: endpoints::put_saved_reports::RequestBody,
+    ) -> Result<endpoints::put_saved_reports::ResponseBody, Error> {
+        let url = self.url(endpoints::put_saved_reports::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string())
+            .replace("{saved_report_id}", &params.saved_report_id.to_string());
+        let request = self
+            .reqwest_client
+            .put(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("put_saved_reports", request).await
+    }
+
+    pub async fn get_shared_report(
+        &self,
+        params: endpoints::get_shared_report::PathParams,
+    ) -> Result<endpoints::get_shared_report::ResponseBody, Error> {
+        let url = self.url(endpoints::get_shared_report::BASE_URL)
+            .replace("{report_token}", &path_segment(&params.report_token));
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_shared_report", request).await
+    }
+
+    pub async fn post_shared_report(
+        &self,
+        params: endpoints::post_shared_report::PathParams,
+        body: endpoints::post_shared_report::RequestBody,
+    ) -> Result<endpoints::post_shared_report::ResponseBody, Error> {
+        let url = self.url(endpoints::post_shared_report::BASE_URL)
+            .replace("{report_token}", &path_segment(&params.report_token));
+        let request = self
+            .reqwest_client
+            .post(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("post_shared_report", request).await
+    }
+
+    /// Downloads the CSV or PDF export of a report, the same file the web UI offers, into
+    /// `writer` and returns its size. The export is streamed to `writer` as it arrives, except
+    /// while recording or replaying fixtures.
+    pub async fn export_report<W: AsyncWrite + Unpin>(
+        &self,
+        workspace_id: i64,
+        report: ReportKind,
+        format: ExportFormat,
+        filters: &ReportFilters,
+        writer: &mut W,
+    ) -> Result<u64, Error> {
+        use ExportFormat::{Csv, Pdf};
+        use ReportKind::{Detailed, Summary, Weekly};
+        let (endpoint, base_url) = match (report, format) {
+            (Detailed, Csv) => (
+                "post_reports_search_time_entries_csv",
+                endpoints::post_reports_search_time_entries_csv::BASE_URL,
+            ),
+            (Detailed, Pdf) => (
+                "post_reports_search_time_entries_pdf",
+                endpoints::post_reports_search_time_entries_pdf::BASE_URL,
+            ),
+            (Summary, Csv) => (
+                "post_reports_summary_time_entries_csv",
+                endpoints::post_reports_summary_time_entries_csv::BASE_URL,
+            ),
+            (Summary, Pdf) => (
+                "post_reports_summary_time_entries_pdf",
+                endpoints::post_reports_summary_time_entries_pdf::BASE_URL,
+            ),
+            (Weekly, Csv) => (
+                "post_reports_weekly_time_entries_csv",
+                endpoints::post_reports_weekly_time_entries_csv::BASE_URL,
+            ),
+            (Weekly, Pdf) => (
+                "post_reports_weekly_time_entries_pdf",
+                endpoints::post_reports_weekly_time_entries_pdf::BASE_URL,
+            ),
+        };
+        let url = self.url(base_url)
+            .replace("{workspace_id}", &workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .post(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(filters);
+        let size = self.execute_into(endpoint, request.build()?, writer).await?;
+        writer.flush().await?;
+        Ok(size)
+    }
+
+    pub async fn get_workspace_track_reminders(
+        &self,
+        params: endpoints::get_workspace_track_reminders::PathParams,
+    ) -> Result<endpoints::get_workspace_track_reminders::ResponseBody, Error> {
+        let url = self.url(endpoints::get_workspace_track_reminders::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .get(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.send("get_workspace_track_reminders", request).await
+    }
+
+    pub async fn post_track_reminders(
+        &self,
+        params: endpoints::post_track_reminders::PathParams,
+        body: endpoints::post_track_reminders::RequestBody,
+    ) -> Result<endpoints::post_track_reminders::ResponseBody, Error> {
+        let violations = validation::validate_track_reminder(&body);
+        if !violations.is_empty() {
+            return Err(Error::InvalidTrackReminder(violations));
+        }
+        let url = self.url(endpoints::post_track_reminders::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string());
+        let request = self
+            .reqwest_client
+            .post(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("post_track_reminders", request).await
+    }
+
+    pub async fn put_track_reminders(
+        &self,
+        params: endpoints::put_track_reminders::PathParams,
+        body: endpoints::put_track_reminders::RequestBody,
+    ) -> Result<endpoints::put_track_reminders::ResponseBody, Error> {
+        let violations = validation::validate_track_reminder(&body);
+        if !violations.is_empty() {
+            return Err(Error::InvalidTrackReminder(violations));
+        }
+        let url = self.url(endpoints::put_track_reminders::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string())
+            .replace("{reminder_id}", &params.reminder_id.to_string());
+        let request = self
+            .reqwest_client
+            .put(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()))
+            .json(&body);
+        self.send("put_track_reminders", request).await
+    }
+
+    pub async fn delete_track_reminders(
+        &self,
+        params: endpoints::delete_track_reminders::PathParams,
+    ) -> Result<(), Error> {
+        let url = self.url(endpoints::delete_track_reminders::BASE_URL)
+            .replace("{workspace_id}", &params.workspace_id.to_string())
+            .replace("{reminder_id}", &params.reminder_id.to_string());
+        let request = self
+            .reqwest_client
+            .delete(url)
+            .basic_auth(self.auth.user.expose(), Some(self.auth.password.expose()));
+        self.fetch("delete_track_reminders", request).await?;
+        Ok(())
     }
 }
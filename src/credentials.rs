@@ -0,0 +1,247 @@
+use crate::secret::{self, Secret};
+use argon2::{Algorithm, Argon2, Version};
+use openssl::symm::{self, Cipher};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+const KEY_LEN: usize = 32;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("crypto error: {0}")]
+    Crypto(#[from] openssl::error::ErrorStack),
+
+    #[error("no credentials saved for profile {0}")]
+    UnknownProfile(String),
+
+    /// Also returned when the file was tampered with, AES-GCM can't tell the two apart
+    #[error("wrong passphrase for profile {0}")]
+    WrongPassphrase(String),
+
+    #[error("malformed credentials for profile {0}")]
+    Malformed(String),
+
+    #[error("KDF parameters out of bounds: {0:?}")]
+    KdfParams(KdfParams),
+}
+
+/// Argon2id cost parameters, stored with every entry so they can be raised later without
+/// breaking existing files.
+///
+/// They're read back from the file, so anything outside [`KdfParams::MAX_MEMORY_KIB`],
+/// [`KdfParams::MAX_ITERATIONS`] and [`KdfParams::MAX_LANES`] is rejected before deriving a key
+/// rather than letting an edited file ask for terabytes of memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KdfParams {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub lanes: u32,
+}
+
+impl KdfParams {
+    /// 1 GiB
+    pub const MAX_MEMORY_KIB: u32 = 1024 * 1024;
+    pub const MAX_ITERATIONS: u32 = 64;
+    pub const MAX_LANES: u32 = 16;
+
+    fn argon2(self) -> Option<Argon2<'static>> {
+        if self.memory_kib > Self::MAX_MEMORY_KIB
+            || !(1..=Self::MAX_ITERATIONS).contains(&self.iterations)
+            || !(1..=Self::MAX_LANES).contains(&self.lanes)
+        {
+            return None;
+        }
+        let params =
+            argon2::Params::new(self.memory_kib, self.iterations, self.lanes, Some(KEY_LEN))
+                .ok()?;
+        Some(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+    }
+}
+
+impl Default for KdfParams {
+    /// OWASP's minimum recommendation for Argon2id
+    fn default() -> Self {
+        Self {
+            memory_kib: 19 * 1024,
+            iterations: 2,
+            lanes: 1,
+        }
+    }
+}
+
+/// One profile's API token, encrypted with AES-256-GCM under a key derived from the passphrase.
+/// The profile name is authenticated too, so entries can't be swapped between profiles.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    kdf: KdfParams,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+    tag: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct StoreFile {
+    profiles: BTreeMap<String, Entry>,
+}
+
+/// API tokens per profile in one JSON file, each encrypted with its own passphrase
+pub struct CredentialStore {
+    path: PathBuf,
+    file: StoreFile,
+
+    /// Used for newly saved entries
+    pub kdf: KdfParams,
+}
+
+impl CredentialStore {
+    /// `$TOGGLRUST_CREDENTIALS`, or `togglrust/credentials.json` in the user's config directory
+    pub fn default_path() -> Option<PathBuf> {
+        if let Some(path) = std::env::var_os("TOGGLRUST_CREDENTIALS") {
+            return Some(PathBuf::from(path));
+        }
+        let config = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+        Some(config.join("togglrust").join("credentials.json"))
+    }
+
+    /// A missing file is an empty store, it's created on the first save
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+        let file = match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => StoreFile::default(),
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Self {
+            path,
+            file,
+            kdf: KdfParams::default(),
+        })
+    }
+
+    pub fn profiles(&self) -> impl Iterator<Item = &str> {
+        self.file.profiles.keys().map(String::as_str)
+    }
+
+    /// Encrypts `token` and writes the file, replacing any token saved for the profile before
+    pub fn save(
+        &mut self,
+        profile: &str,
+        token: &Secret,
+        passphrase: &Secret,
+    ) -> Result<(), Error> {
+        let mut salt = [0; SALT_LEN];
+        let mut nonce = [0; NONCE_LEN];
+        openssl::rand::rand_bytes(&mut salt)?;
+        openssl::rand::rand_bytes(&mut nonce)?;
+        let mut key = derive_key(passphrase, &salt, self.kdf)?;
+        let mut tag = [0; TAG_LEN];
+        let ciphertext = symm::encrypt_aead(
+            Cipher::aes_256_gcm(),
+            &key,
+            Some(&nonce),
+            profile.as_bytes(),
+            token.expose().as_bytes(),
+            &mut tag,
+        );
+        secret::zeroize(&mut key);
+
+        self.file.profiles.insert(
+            profile.to_string(),
+            Entry {
+                kdf: self.kdf,
+                salt: hex::encode(salt),
+                nonce: hex::encode(nonce),
+                ciphertext: hex::encode(ciphertext?),
+                tag: hex::encode(tag),
+            },
+        );
+        self.write()
+    }
+
+    pub fn load(&self, profile: &str, passphrase: &Secret) -> Result<Secret, Error> {
+        let entry = self
+            .file
+            .profiles
+            .get(profile)
+            .ok_or_else(|| Error::UnknownProfile(profile.to_string()))?;
+        let decode =
+            |value: &str| hex::decode(value).map_err(|_| Error::Malformed(profile.to_string()));
+        let (salt, nonce, ciphertext, tag) = (
+            decode(&entry.salt)?,
+            decode(&entry.nonce)?,
+            decode(&entry.ciphertext)?,
+            decode(&entry.tag)?,
+        );
+
+        let mut key = derive_key(passphrase, &salt, entry.kdf)
+            .map_err(|_| Error::Malformed(profile.to_string()))?;
+        let plaintext = symm::decrypt_aead(
+            Cipher::aes_256_gcm(),
+            &key,
+            Some(&nonce),
+            profile.as_bytes(),
+            &ciphertext,
+            &tag,
+        );
+        secret::zeroize(&mut key);
+
+        let plaintext = plaintext.map_err(|_| Error::WrongPassphrase(profile.to_string()))?;
+        String::from_utf8(plaintext)
+            .map(Secret::from)
+            .map_err(|err| {
+                secret::zeroize(&mut err.into_bytes());
+                Error::Malformed(profile.to_string())
+            })
+    }
+
+    /// Returns whether there was anything to remove
+    pub fn remove(&mut self, profile: &str) -> Result<bool, Error> {
+        if self.file.profiles.remove(profile).is_none() {
+            return Ok(false);
+        }
+        self.write()?;
+        Ok(true)
+    }
+
+    /// Writes to a temporary file first so a crash can't leave a truncated store behind
+    fn write(&self) -> Result<(), Error> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let temporary = self.path.with_extension("json.tmp");
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options.open(&temporary)?;
+        file.write_all(&serde_json::to_vec_pretty(&self.file)?)?;
+        file.sync_all()?;
+        fs::rename(&temporary, &self.path)?;
+        Ok(())
+    }
+}
+
+fn derive_key(passphrase: &Secret, salt: &[u8], kdf: KdfParams) -> Result<[u8; KEY_LEN], Error> {
+    let argon2 = kdf.argon2().ok_or(Error::KdfParams(kdf))?;
+    let mut key = [0; KEY_LEN];
+    argon2
+        .hash_password_into(passphrase.expose().as_bytes(), salt, &mut key)
+        .map_err(|_| Error::KdfParams(kdf))?;
+    Ok(key)
+}
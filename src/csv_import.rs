@@ -0,0 +1,585 @@
+use crate::api::TogglApi;
+use crate::client;
+use crate::endpoints::post_time_entries;
+use crate::models::{self, Project, Tag, TimeEntry, Workspace};
+use crate::validation::{validate_time_entry, Violation};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::io;
+use thiserror::Error;
+
+/// Written to `created_with` for every entry created by the importer
+pub const CREATED_WITH: &str = "togglrust csv import";
+
+const DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+];
+const TIME_FORMATS: &[&str] = &["%H:%M:%S", "%H:%M"];
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+
+    #[error("column {0:?} is not in the CSV header")]
+    MissingColumn(String),
+
+    #[error("line {line}: {source} ({} entries were created before it)", created.len())]
+    Client {
+        line: u64,
+        /// Entries created before the failed request, so the caller can report or resume
+        created: Vec<TimeEntry>,
+        #[source]
+        source: client::Error,
+    },
+}
+
+/// Why a single CSV row can't be imported
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RowError {
+    #[error("can't parse date/time {0:?}")]
+    InvalidDateTime(String),
+
+    #[error("{0} doesn't exist in the target timezone")]
+    NonexistentLocalTime(NaiveDateTime),
+
+    #[error("can't parse duration {0:?}")]
+    InvalidDuration(String),
+
+    #[error("row has neither a stop time nor a duration")]
+    MissingDuration,
+
+    #[error("entry stops before it starts")]
+    NegativeDuration,
+
+    #[error("unknown client {0:?}")]
+    UnknownClient(String),
+
+    #[error("unknown project {0:?}")]
+    UnknownProject(String),
+
+    #[error("unknown tag {0:?}")]
+    UnknownTag(String),
+
+    #[error("can't parse billable flag {0:?}")]
+    InvalidBillable(String),
+
+    #[error("{0}")]
+    Constraint(Violation),
+}
+
+/// Maps CSV header names to time entry fields. Columns that are absent from the file are ignored,
+/// except for `start`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColumnMapping {
+    /// Start date, or date and time if `start_time` isn't set
+    pub start: String,
+
+    /// Start time, for spreadsheets that keep date and time in separate columns
+    pub start_time: Option<String>,
+
+    /// Stop time, either a full date and time or a time on the start date
+    pub stop: Option<String>,
+
+    /// Duration as `H:MM[:SS]` or decimal hours, used when there is no stop time
+    pub duration: Option<String>,
+    pub description: Option<String>,
+    pub project: Option<String>,
+
+    /// Client name, used to tell apart projects with the same name
+    pub client: Option<String>,
+    pub tags: Option<String>,
+    pub tag_separator: char,
+    pub billable: Option<String>,
+
+    /// chrono format string for dates and times, if they aren't ISO 8601
+    pub datetime_format: Option<String>,
+}
+
+impl Default for ColumnMapping {
+    fn default() -> Self {
+        Self {
+            start: String::from("Start"),
+            start_time: None,
+            stop: Some(String::from("Stop")),
+            duration: Some(String::from("Duration")),
+            description: Some(String::from("Description")),
+            project: Some(String::from("Project")),
+            client: Some(String::from("Client")),
+            tags: Some(String::from("Tags")),
+            tag_separator: ',',
+            billable: Some(String::from("Billable")),
+            datetime_format: None,
+        }
+    }
+}
+
+/// The cached workspace data rows are resolved against
+pub struct ImportContext<'a> {
+    pub workspace: &'a Workspace,
+
+    /// Timezone the CSV's local times are in, usually the user's timezone from `get_me`
+    pub timezone: Tz,
+    pub projects: &'a [Project],
+    pub clients: &'a [models::Client],
+    pub tags: &'a [Tag],
+
+    /// Entries that already exist, used to detect duplicates
+    pub existing: &'a [TimeEntry],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateOf {
+    /// An entry that already exists in Toggl
+    TimeEntry(i64),
+
+    /// An earlier line of the same file
+    Line(u64),
+}
+
+#[derive(Debug, Clone)]
+pub enum RowOutcome {
+    Create(post_time_entries::RequestBody),
+    Duplicate(DuplicateOf),
+    Invalid(Vec<RowError>),
+}
+
+#[derive(Debug, Clone)]
+pub struct PlannedRow {
+    /// Line number in the CSV file
+    pub line: u64,
+    pub outcome: RowOutcome,
+}
+
+/// What an import would do, computed without making any requests
+#[derive(Debug, Clone)]
+pub struct ImportPlan {
+    pub rows: Vec<PlannedRow>,
+}
+
+type DuplicateKey = (i64, i64, Option<i64>, String);
+
+fn duplicate_key(
+    start: DateTime<Utc>,
+    duration: Duration,
+    project_id: Option<i64>,
+    description: Option<&str>,
+) -> DuplicateKey {
+    (
+        start.timestamp(),
+        duration.num_seconds(),
+        project_id,
+        description.unwrap_or_default().trim().to_string(),
+    )
+}
+
+impl ImportPlan {
+    /// Parses a CSV file and resolves every row against the context
+    pub fn new<R: io::Read>(
+        reader: R,
+        mapping: &ColumnMapping,
+        context: &ImportContext,
+    ) -> Result<Self, Error> {
+        let mut reader = csv::Reader::from_reader(reader);
+        let headers = reader.headers()?.clone();
+        let column = |name: &Option<String>| {
+            name.as_ref()
+                .and_then(|name| headers.iter().position(|header| header.trim() == name))
+        };
+        let columns = Columns {
+            start: headers
+                .iter()
+                .position(|header| header.trim() == mapping.start)
+                .ok_or_else(|| Error::MissingColumn(mapping.start.clone()))?,
+            start_time: column(&mapping.start_time),
+            stop: column(&mapping.stop),
+            duration: column(&mapping.duration),
+            description: column(&mapping.description),
+            project: column(&mapping.project),
+            client: column(&mapping.client),
+            tags: column(&mapping.tags),
+            billable: column(&mapping.billable),
+        };
+
+        let mut seen: HashMap<DuplicateKey, DuplicateOf> = context
+            .existing
+            .iter()
+            .filter(|entry| entry.server_deleted_at.is_none() && entry.duration >= Duration::zero())
+            .map(|entry| {
+                let key = duplicate_key(
+                    entry.start,
+                    entry.duration,
+                    entry.project_id,
+                    entry.description.as_deref(),
+                );
+                (key, DuplicateOf::TimeEntry(entry.id))
+            })
+            .collect();
+
+        let mut rows = Vec::new();
+        for record in reader.records() {
+            let record = record?;
+            let line = record.position().map(|pos| pos.line()).unwrap_or_default();
+            let outcome = match parse_row(&record, &columns, mapping, context) {
+                Err(errors) => RowOutcome::Invalid(errors),
+                Ok(body) => {
+                    let key = duplicate_key(
+                        body.start,
+                        body.duration.unwrap_or_else(Duration::zero),
+                        body.project_id,
+                        body.description.as_deref(),
+                    );
+                    match seen.get(&key) {
+                        Some(duplicate_of) => RowOutcome::Duplicate(*duplicate_of),
+                        None => {
+                            seen.insert(key, DuplicateOf::Line(line));
+                            RowOutcome::Create(body)
+                        }
+                    }
+                }
+            };
+            rows.push(PlannedRow { line, outcome });
+        }
+
+        Ok(Self { rows })
+    }
+
+    pub fn to_create(&self) -> impl Iterator<Item = (u64, &post_time_entries::RequestBody)> {
+        self.rows.iter().filter_map(|row| match &row.outcome {
+            RowOutcome::Create(body) => Some((row.line, body)),
+            _ => None,
+        })
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.rows
+            .iter()
+            .any(|row| matches!(row.outcome, RowOutcome::Invalid(_)))
+    }
+
+    /// Human readable summary of the plan: `+` for entries that would be created, `=` for
+    /// duplicates and `!` for rows that can't be imported
+    pub fn diff(&self, timezone: Tz) -> String {
+        let mut out = String::new();
+        let (mut created, mut duplicates, mut invalid) = (0, 0, 0);
+        for row in &self.rows {
+            match &row.outcome {
+                RowOutcome::Create(body) => {
+                    created += 1;
+                    let duration = body.duration.unwrap_or_else(Duration::zero);
+                    writeln!(
+                        out,
+                        "+ line {}: {} {}:{:02} project={} {}",
+                        row.line,
+                        body.start.with_timezone(&timezone).format("%Y-%m-%d %H:%M"),
+                        duration.num_hours(),
+                        duration.num_minutes() % 60,
+                        body.project_id
+                            .map(|id| id.to_string())
+                            .unwrap_or_else(|| String::from("-")),
+                        body.description.as_deref().unwrap_or_default(),
+                    )
+                    .unwrap();
+                }
+                RowOutcome::Duplicate(DuplicateOf::TimeEntry(id)) => {
+                    duplicates += 1;
+                    writeln!(out, "= line {}: duplicate of time entry {}", row.line, id).unwrap();
+                }
+                RowOutcome::Duplicate(DuplicateOf::Line(line)) => {
+                    duplicates += 1;
+                    writeln!(out, "= line {}: duplicate of line {}", row.line, line).unwrap();
+                }
+                RowOutcome::Invalid(errors) => {
+                    invalid += 1;
+                    for error in errors {
+                        writeln!(out, "! line {}: {}", row.line, error).unwrap();
+                    }
+                }
+            }
+        }
+        writeln!(
+            out,
+            "{} to create, {} duplicates, {} invalid",
+            created, duplicates, invalid
+        )
+        .unwrap();
+        out
+    }
+
+    /// Creates the planned entries one by one, stopping at the first failed request.
+    ///
+    /// On failure the error carries the entries that were already created. Planning again with
+    /// them as existing entries marks those rows as duplicates, so the import can be resumed.
+    pub async fn execute(&self, api: &impl TogglApi) -> Result<Vec<TimeEntry>, Error> {
+        let mut created = Vec::new();
+        for (line, body) in self.to_create() {
+            let response = api
+                .post_time_entries(
+                    post_time_entries::PathParams {
+                        workspace_id: body.workspace_id,
+                    },
+                    body.clone(),
+                )
+                .await;
+            match response {
+                Ok(response) => created.extend(response.0),
+                Err(source) => {
+                    return Err(Error::Client {
+                        line,
+                        created,
+                        source,
+                    })
+                }
+            }
+        }
+        Ok(created)
+    }
+}
+
+struct Columns {
+    start: usize,
+    start_time: Option<usize>,
+    stop: Option<usize>,
+    duration: Option<usize>,
+    description: Option<usize>,
+    project: Option<usize>,
+    client: Option<usize>,
+    tags: Option<usize>,
+    billable: Option<usize>,
+}
+
+fn parse_row(
+    record: &csv::StringRecord,
+    columns: &Columns,
+    mapping: &ColumnMapping,
+    context: &ImportContext,
+) -> Result<post_time_entries::RequestBody, Vec<RowError>> {
+    let field = |index: Option<usize>| {
+        index
+            .and_then(|index| record.get(index))
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+    };
+    let format = mapping.datetime_format.as_deref();
+    let mut errors = Vec::new();
+
+    let start_value = match field(columns.start_time) {
+        Some(time) => format!(
+            "{} {}",
+            field(Some(columns.start)).unwrap_or_default(),
+            time
+        ),
+        None => field(Some(columns.start)).unwrap_or_default().to_string(),
+    };
+    let start = match parse_datetime(&start_value, format, context.timezone) {
+        Ok(start) => Some(start),
+        Err(error) => {
+            errors.push(error);
+            None
+        }
+    };
+
+    let mut duration = None;
+    if let Some(start) = start {
+        let result = match (field(columns.stop), field(columns.duration)) {
+            (Some(stop), _) => {
+                parse_stop(stop, start, format, context.timezone).map(|stop| stop - start)
+            }
+            (None, Some(duration)) => parse_duration(duration).and_then(|value| {
+                // A huge duration would put the stop time out of range
+                start
+                    .checked_add_signed(value)
+                    .map(|_| value)
+                    .ok_or_else(|| RowError::InvalidDuration(duration.to_string()))
+            }),
+            (None, None) => Err(RowError::MissingDuration),
+        };
+        match result {
+            Ok(value) if value < Duration::zero() => errors.push(RowError::NegativeDuration),
+            Ok(value) => duration = Some(value),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    let client_id = match field(columns.client) {
+        Some(name) => match context.clients.iter().find(|client| {
+            client.wid == context.workspace.id
+                && client.server_deleted_at.is_none()
+                && client.name.eq_ignore_ascii_case(name)
+        }) {
+            Some(client) => Some(client.id),
+            None => {
+                errors.push(RowError::UnknownClient(name.to_string()));
+                None
+            }
+        },
+        None => None,
+    };
+
+    let project_id = match field(columns.project) {
+        Some(name) => match context.projects.iter().find(|project| {
+            project.workspace_id == context.workspace.id
+                && project.server_deleted_at.is_none()
+                && project.name.eq_ignore_ascii_case(name)
+                && (client_id.is_none() || project.client_id.or(project.cid) == client_id)
+        }) {
+            Some(project) => Some(project.id),
+            None => {
+                errors.push(RowError::UnknownProject(name.to_string()));
+                None
+            }
+        },
+        None => None,
+    };
+
+    let mut tag_ids = Vec::new();
+    if let Some(tags) = field(columns.tags) {
+        for name in tags
+            .split(mapping.tag_separator)
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+        {
+            match context.tags.iter().find(|tag| {
+                tag.workspace_id == context.workspace.id
+                    && tag.deleted_at.is_none()
+                    && tag.name.eq_ignore_ascii_case(name)
+            }) {
+                Some(tag) => tag_ids.push(tag.id),
+                None => errors.push(RowError::UnknownTag(name.to_string())),
+            }
+        }
+    }
+
+    let billable = match field(columns.billable).map(str::to_ascii_lowercase) {
+        None => None,
+        Some(value) => match value.as_str() {
+            "1" | "true" | "yes" | "y" => Some(true),
+            "0" | "false" | "no" | "n" => Some(false),
+            _ => {
+                errors.push(RowError::InvalidBillable(value));
+                None
+            }
+        },
+    };
+
+    let (Some(start), Some(duration)) = (start, duration) else {
+        return Err(errors);
+    };
+    let mut body = post_time_entries::RequestBody::new(context.workspace.id, start, CREATED_WITH);
+    body.billable = billable;
+    body.description = field(columns.description).map(str::to_string);
+    body.duration = Some(duration);
+    body.stop = start.checked_add_signed(duration);
+    body.project_id = project_id;
+    body.tag_ids = (!tag_ids.is_empty()).then_some(tag_ids);
+
+    errors.extend(
+        validate_time_entry(&body, context.workspace, context.projects)
+            .into_iter()
+            .map(RowError::Constraint),
+    );
+    if errors.is_empty() {
+        Ok(body)
+    } else {
+        Err(errors)
+    }
+}
+
+fn to_utc(naive: NaiveDateTime, timezone: Tz) -> Result<DateTime<Utc>, RowError> {
+    timezone
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|datetime| datetime.with_timezone(&Utc))
+        .ok_or(RowError::NonexistentLocalTime(naive))
+}
+
+fn parse_naive_datetime(value: &str, format: Option<&str>) -> Option<NaiveDateTime> {
+    match format {
+        Some(format) => NaiveDateTime::parse_from_str(value, format).ok(),
+        None => DATETIME_FORMATS
+            .iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok()),
+    }
+}
+
+fn parse_datetime(
+    value: &str,
+    format: Option<&str>,
+    timezone: Tz,
+) -> Result<DateTime<Utc>, RowError> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Ok(datetime.with_timezone(&Utc));
+    }
+    if let Some(naive) = parse_naive_datetime(value, format) {
+        return to_utc(naive, timezone);
+    }
+    // A bare date starts at midnight, which only makes sense together with a duration
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return to_utc(date.and_time(NaiveTime::MIN), timezone);
+    }
+    Err(RowError::InvalidDateTime(value.to_string()))
+}
+
+/// Parses a stop value, which may be just a time of day on the start date. A time before the
+/// start time is taken to be on the next day.
+fn parse_stop(
+    value: &str,
+    start: DateTime<Utc>,
+    format: Option<&str>,
+    timezone: Tz,
+) -> Result<DateTime<Utc>, RowError> {
+    if let Ok(stop) = parse_datetime(value, format, timezone) {
+        return Ok(stop);
+    }
+    let time = TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveTime::parse_from_str(value, format).ok())
+        .ok_or_else(|| RowError::InvalidDateTime(value.to_string()))?;
+    let local_start = start.with_timezone(&timezone);
+    let stop = to_utc(local_start.date_naive().and_time(time), timezone)?;
+    if stop < start {
+        let next_day = local_start
+            .date_naive()
+            .succ_opt()
+            .unwrap_or(local_start.date_naive());
+        return to_utc(next_day.and_time(time), timezone);
+    }
+    Ok(stop)
+}
+
+/// Parses `H:MM`, `H:MM:SS` or decimal hours
+fn parse_duration(value: &str) -> Result<Duration, RowError> {
+    let invalid = || RowError::InvalidDuration(value.to_string());
+    if value.contains(':') {
+        let parts = value
+            .split(':')
+            .map(|part| part.parse::<i64>().map_err(|_| invalid()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let (hours, minutes, seconds) = match parts.as_slice() {
+            [hours, minutes] => (*hours, *minutes, 0),
+            [hours, minutes, seconds] => (*hours, *minutes, *seconds),
+            _ => return Err(invalid()),
+        };
+        return Duration::try_hours(hours)
+            .zip(Duration::try_minutes(minutes))
+            .zip(Duration::try_seconds(seconds))
+            .and_then(|((hours, minutes), seconds)| {
+                hours.checked_add(&minutes)?.checked_add(&seconds)
+            })
+            .ok_or_else(invalid);
+    }
+    let hours: f64 = value.replace(',', ".").parse().map_err(|_| invalid())?;
+    let seconds = (hours * 3600.0).round();
+    // Casting saturates, so anything outside i64 has to be caught first
+    if !seconds.is_finite() || seconds.abs() >= i64::MAX as f64 {
+        return Err(invalid());
+    }
+    Duration::try_seconds(seconds as i64).ok_or_else(invalid)
+}
@@ -0,0 +1,111 @@
+use crate::models::{Activity, MostActive, Project, WorkspaceUser};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+
+/// What someone is tracking right now
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrentEntry {
+    /// Time Entry ID
+    pub time_entry_id: i64,
+    pub description: Option<String>,
+    pub project_id: Option<i64>,
+
+    /// Name of the project, `None` without a project or if it isn't in the given projects
+    pub project_name: Option<String>,
+    pub start: DateTime<Utc>,
+
+    /// How long the entry has been running
+    pub elapsed: Duration,
+}
+
+/// One line of the team status board
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusRow {
+    pub user_id: i64,
+
+    /// Name of the user, `None` if they aren't in the given workspace users
+    pub user_name: Option<String>,
+
+    /// The user's running entry, `None` if they aren't tracking anything
+    pub current: Option<CurrentEntry>,
+
+    /// Time the user tracked recently according to the most active ranking
+    pub recent: Duration,
+}
+
+/// Joins the dashboard's activity feed and most active ranking with project and user data,
+/// giving one row per user that shows up in either.
+///
+/// `projects` and `users` can come from a cache, anything missing from them only leaves the
+/// names empty. Rows of people tracking right now come first, longest running first, followed by
+/// everyone else by the time they tracked recently.
+pub fn status_board(
+    activities: &[Activity],
+    most_active: &[MostActive],
+    projects: &[Project],
+    users: &[WorkspaceUser],
+    now: DateTime<Utc>,
+) -> Vec<StatusRow> {
+    let project_names: HashMap<i64, &str> = projects
+        .iter()
+        .map(|project| (project.id, project.name.as_str()))
+        .collect();
+    let user_names: HashMap<i64, &str> = users
+        .iter()
+        .map(|user| (user.uid, user.name.as_str()))
+        .collect();
+
+    let new_row = |user_id: i64| StatusRow {
+        user_id,
+        user_name: user_names.get(&user_id).map(|name| name.to_string()),
+        current: None,
+        recent: Duration::zero(),
+    };
+
+    let mut rows: HashMap<i64, StatusRow> = HashMap::new();
+    for activity in activities {
+        let row = rows
+            .entry(activity.user_id)
+            .or_insert_with(|| new_row(activity.user_id));
+        if !activity.is_running() {
+            continue;
+        }
+        // Only one entry runs at a time, but the feed can lag behind a restart
+        if row
+            .current
+            .as_ref()
+            .is_some_and(|current| current.start >= activity.start)
+        {
+            continue;
+        }
+        row.current = Some(CurrentEntry {
+            time_entry_id: activity.id,
+            description: activity.description.clone(),
+            project_id: activity.project_id,
+            project_name: activity
+                .project_id
+                .and_then(|project_id| project_names.get(&project_id))
+                .map(|name| name.to_string()),
+            start: activity.start,
+            elapsed: (now - activity.start).max(Duration::zero()),
+        });
+    }
+    for active in most_active {
+        rows.entry(active.user_id)
+            .or_insert_with(|| new_row(active.user_id))
+            .recent = active.duration;
+    }
+
+    let mut rows: Vec<StatusRow> = rows.into_values().collect();
+    rows.sort_by(|a, b| {
+        let started = |row: &StatusRow| row.current.as_ref().map(|current| current.start);
+        match (started(a), started(b)) {
+            (Some(a_start), Some(b_start)) => a_start.cmp(&b_start),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => b.recent.cmp(&a.recent),
+        }
+        .then(a.user_id.cmp(&b.user_id))
+    });
+    rows
+}
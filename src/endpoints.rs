@@ -1,6 +1,7 @@
 pub mod get_me {
     use crate::day_of_week::DayOfWeek;
     use crate::models;
+    use crate::secret::Secret;
     use chrono::{DateTime, Utc};
     use serde::{Deserialize, Serialize};
 
@@ -11,72 +12,102 @@ pub mod get_me {
         with_related_data: Option<bool>,
     }
 
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct ResponseBody {
-        api_token: Option<String>,
-        at: DateTime<Utc>,
-        beginning_of_week: DayOfWeek,
-        clients: Option<Vec<models::Client>>,
-        country_id: Option<i64>,
-        created_at: DateTime<Utc>,
-        default_workspace_id: Option<i64>,
-        email: String,
-        fullname: String,
-        has_password: bool,
-        id: i64,
-        image_url: String,
-        intercom_hash: Option<String>,
-        oauth_providers: Option<Vec<String>>,
-        openid_email: Option<String>,
-        openid_enabled: bool,
-        projects: Option<Vec<models::Project>>,
-        tags: Option<Vec<models::Tag>>,
-        tasks: Option<Vec<models::Task>>,
-        time_entries: Option<Vec<models::TimeEntry>>,
-        timezone: String,
-        updated_at: DateTime<Utc>,
-        workspaces: Option<Vec<models::Workspace>>,
+        pub api_token: Option<Secret>,
+        pub at: DateTime<Utc>,
+        pub beginning_of_week: DayOfWeek,
+        pub clients: Option<Vec<models::Client>>,
+        pub country_id: Option<i64>,
+        pub created_at: DateTime<Utc>,
+        pub default_workspace_id: Option<i64>,
+        pub email: String,
+        pub fullname: String,
+        pub has_password: bool,
+        pub id: i64,
+        pub image_url: String,
+        pub intercom_hash: Option<Secret>,
+        pub oauth_providers: Option<Vec<String>>,
+        pub openid_email: Option<String>,
+        pub openid_enabled: bool,
+        pub projects: Option<Vec<models::Project>>,
+        pub tags: Option<Vec<models::Tag>>,
+        pub tasks: Option<Vec<models::Task>>,
+        pub time_entries: Option<Vec<models::TimeEntry>>,
+        pub timezone: String,
+        pub updated_at: DateTime<Utc>,
+        pub workspaces: Option<Vec<models::Workspace>>,
+
+        #[serde(flatten)]
+        pub extra: models::Extra,
     }
+
+    models::impl_unknown_fields!(
+        ResponseBody,
+        clients,
+        projects,
+        tags,
+        tasks,
+        time_entries,
+        workspaces
+    );
 }
 
 pub mod put_me {
     use crate::day_of_week::DayOfWeek;
+    use crate::models;
+    use crate::secret::Secret;
     use chrono::{DateTime, Utc};
     use serde::{Deserialize, Serialize};
 
     pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/me";
 
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Serialize, Deserialize, Default)]
     pub struct RequestBody {
-        beginning_of_week: Option<DayOfWeek>,
-        country_id: Option<i64>,
-        current_password: Option<String>,
-        default_workspace_id: Option<i64>,
-        email: Option<String>,
-        fullname: Option<String>,
-        password: Option<String>,
-        timezone: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub beginning_of_week: Option<DayOfWeek>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub country_id: Option<i64>,
+
+        /// Required when changing the email or password
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub current_password: Option<Secret>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_workspace_id: Option<i64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub email: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub fullname: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub password: Option<Secret>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub timezone: Option<String>,
     }
 
     #[derive(Debug, Serialize, Deserialize)]
     pub struct ResponseBody {
-        api_token: Option<String>,
-        at: DateTime<Utc>,
-        beginning_of_week: DayOfWeek,
-        country_id: i64,
-        created_at: DateTime<Utc>,
-        default_workspace_id: i64,
-        email: String,
-        fullname: String,
-        has_password: bool,
-        id: i64,
-        image_url: String,
-        openid_email: String,
-        openid_enabled: bool,
-        // options: Option<models::Options>,
-        timezone: String,
-        updated_at: DateTime<Utc>,
+        pub api_token: Option<Secret>,
+        pub at: DateTime<Utc>,
+        pub beginning_of_week: DayOfWeek,
+        pub country_id: Option<i64>,
+        pub created_at: DateTime<Utc>,
+        pub default_workspace_id: Option<i64>,
+        pub email: String,
+        pub fullname: String,
+        pub has_password: bool,
+        pub id: i64,
+        pub image_url: String,
+        pub openid_email: Option<String>,
+        pub openid_enabled: bool,
+        pub options: Option<models::Preferences>,
+        pub timezone: String,
+        pub updated_at: DateTime<Utc>,
+
+        #[serde(flatten)]
+        pub extra: models::Extra,
     }
+
+    models::impl_unknown_fields!(ResponseBody, options);
 }
 
 pub mod get_clients {
@@ -88,15 +119,18 @@ pub mod get_clients {
     pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/me/clients";
 
     #[serde_as]
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Serialize, Deserialize, Default)]
     pub struct RequestBody {
-        #[serde_as(as = "TimestampSeconds<i64>")]
-        since: DateTime<Utc>,
+        /// Retrieve clients modified since this date using UNIX timestamp, including deleted ones.
+        #[serde_as(as = "Option<TimestampSeconds<i64>>")]
+        pub since: Option<DateTime<Utc>>,
     }
 
     #[serde_as]
-    #[derive(Debug, Serialize, Deserialize)]
-    pub struct ResponseBody(Vec<models::Client>);
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<models::Client>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
 }
 
 pub mod post_close_account {
@@ -110,25 +144,42 @@ pub mod get_features {
     pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/me/features";
 
     #[derive(Debug, Serialize, Deserialize)]
-    pub struct ResponseBody {
-        features: Vec<models::Feature>,
-        workspace_id: i64,
+    pub struct WorkspaceFeatures {
+        pub features: Vec<models::Feature>,
+        pub workspace_id: i64,
+
+        #[serde(flatten)]
+        pub extra: models::Extra,
     }
+
+    models::impl_unknown_fields!(WorkspaceFeatures, features);
+
+    /// One entry per workspace the user belongs to
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<WorkspaceFeatures>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
 }
 
 pub mod get_location {
+    use crate::models;
     use serde::{Deserialize, Serialize};
 
     pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/me/location";
 
     #[derive(Debug, Serialize, Deserialize)]
     pub struct ResponseBody {
-        city: Option<String>,
-        city_lat_long: Option<String>,
-        country_code: Option<String>,
-        country_name: Option<String>,
-        state: Option<String>,
+        pub city: Option<String>,
+        pub city_lat_long: Option<String>,
+        pub country_code: Option<String>,
+        pub country_name: Option<String>,
+        pub state: Option<String>,
+
+        #[serde(flatten)]
+        pub extra: models::Extra,
     }
+
+    models::impl_unknown_fields!(ResponseBody);
 }
 
 /// Used to check if authentication works
@@ -143,7 +194,9 @@ pub mod get_orgs {
     pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/me/organizations";
 
     #[derive(Debug, Serialize, Deserialize)]
-    pub struct ResponseBody(Vec<models::Organization>);
+    pub struct ResponseBody(pub Vec<models::Organization>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
 }
 
 pub mod get_projects {
@@ -165,8 +218,10 @@ pub mod get_projects {
         pub since: Option<DateTime<Utc>>,
     }
 
-    #[derive(Debug, Serialize, Deserialize)]
-    pub struct ResponseBody(Vec<models::Project>);
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<models::Project>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
 }
 
 pub mod get_tags {
@@ -178,15 +233,17 @@ pub mod get_tags {
     pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/me/tags";
 
     #[serde_as]
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Serialize, Deserialize, Default)]
     pub struct RequestBody {
         /// Retrieve tags modified/deleted since this date using UNIX timestamp.
         #[serde_as(as = "Option<TimestampSeconds<i64>>")]
-        since: Option<DateTime<Utc>>,
+        pub since: Option<DateTime<Utc>>,
     }
 
-    #[derive(Debug, Serialize, Deserialize)]
-    pub struct ResponseBody(Vec<models::Tag>);
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<models::Tag>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
 }
 
 pub mod get_tasks {
@@ -198,19 +255,21 @@ pub mod get_tasks {
     pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/me/tasks";
 
     #[serde_as]
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Serialize, Deserialize, Default)]
     pub struct RequestBody {
         /// Retrieve tasks modified/deleted since this date using UNIX timestamp.
         #[serde_as(as = "Option<TimestampSeconds<i64>>")]
-        since: Option<DateTime<Utc>>,
+        pub since: Option<DateTime<Utc>>,
 
         /// Include tasks marked as done
-        include_not_active: Option<bool>, // NOTE: Docs say this is a string, but I seriously hope
+        pub include_not_active: Option<bool>, // NOTE: Docs say this is a string, but I seriously hope
                                           // that's a typo
     }
 
-    #[derive(Debug, Serialize, Deserialize)]
-    pub struct ResponseBody(Vec<models::Task>);
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<models::Task>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
 }
 
 pub mod get_track_reminders {
@@ -220,9 +279,9 @@ pub mod get_track_reminders {
     pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/me/track_reminders";
 
     #[derive(Debug, Serialize, Deserialize)]
-    pub struct ResponseBody {
-        track_reminders: Vec<models::TrackReminder>,
-    }
+    pub struct ResponseBody(pub Vec<models::TrackReminder>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
 }
 
 pub mod get_workspaces {
@@ -234,15 +293,17 @@ pub mod get_workspaces {
     pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/me/workspaces";
 
     #[serde_as]
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Serialize, Deserialize, Default)]
     pub struct RequestBody {
         /// Retrieve tasks modified/deleted since this date using UNIX timestamp.
         #[serde_as(as = "Option<TimestampSeconds<i64>>")]
-        since: Option<DateTime<Utc>>,
+        pub since: Option<DateTime<Utc>>,
     }
 
-    #[derive(Debug, Serialize, Deserialize)]
-    pub struct ResponseBody(Vec<models::Workspace>);
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<models::Workspace>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
 }
 
 pub mod get_time_entries {
@@ -254,25 +315,27 @@ pub mod get_time_entries {
     pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/me/time_entries";
 
     #[serde_as]
-    #[derive(Debug, Serialize, Deserialize)]
+    #[derive(Debug, Serialize, Deserialize, Default)]
     pub struct RequestBody {
         /// Get entries modified since this date using UNIX timestamp, including deleted ones.
         #[serde_as(as = "Option<TimestampSeconds<i64>>")]
-        since: Option<DateTime<Utc>>,
+        pub since: Option<DateTime<Utc>>,
 
         /// Get entries with start time, before given date (YYYY-MM-DD) or with time in RFC3339 format.
         #[serde_as(as = "Option<TimestampSeconds<i64>>")]
-        before: Option<DateTime<Utc>>,
+        pub before: Option<DateTime<Utc>>,
 
         /// Get entries with start time, from start_date YYYY-MM-DD or with time in RFC3339 format. To be used with end_date.
-        start_date: Option<NaiveDate>,
+        pub start_date: Option<NaiveDate>,
 
         /// Get entries with start time, until end_date YYYY-MM-DD or with time in RFC3339 format. To be used with start_date.
-        end_date: Option<NaiveDate>,
+        pub end_date: Option<NaiveDate>,
     }
 
-    #[derive(Debug, Serialize, Deserialize)]
-    pub struct ResponseBody(Vec<models::TimeEntry>);
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<models::TimeEntry>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
 }
 
 pub mod get_current_time_entry {
@@ -281,26 +344,1523 @@ pub mod get_current_time_entry {
 
     pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/me/time_entries/current";
 
-    #[derive(Debug, Serialize, Deserialize)]
-    pub struct ResponseBody(Option<models::TimeEntry>);
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Option<models::TimeEntry>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
 }
 
 pub mod post_time_entries {
     use crate::models;
-    use chrono::{DateTime, Utc};
+    use chrono::{DateTime, Duration, Utc};
     use serde::{Deserialize, Serialize};
-    use serde_with::{serde_as, TimestampSeconds};
+    use serde_with::{serde_as, DurationSeconds};
 
     pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/time_entries";
 
     #[serde_as]
     #[derive(Debug, Serialize, Deserialize)]
     pub struct PathParams {
-        workspace_id: i64,
+        pub workspace_id: i64,
+    }
+
+    #[serde_as]
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct RequestBody {
+        /// Whether the time entry is marked as billable, optional, default false
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub billable: Option<bool>,
+
+        /// Must be provided when creating a time entry and should identify the service/application used to create it
+        pub created_with: String,
+
+        /// Time entry description, optional
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// Time entry duration. For running entries should be -1 * (Unix start time)
+        #[serde_as(as = "Option<DurationSeconds<i64>>")]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub duration: Option<Duration>,
+
+        /// Project ID, optional
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub project_id: Option<i64>,
+
+        /// Start time in UTC, required for creation
+        pub start: DateTime<Utc>,
+
+        /// Stop time in UTC, can be omitted if it's still running or created with "duration"
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub stop: Option<DateTime<Utc>>,
+
+        /// IDs of tags to add/remove
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tag_ids: Option<Vec<i64>>,
+
+        /// Names of tags to add/remove. If name does not exist as tag, one will be created automatically
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tags: Option<Vec<String>>,
+
+        /// Task ID, optional
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub task_id: Option<i64>,
+
+        /// Time Entry creator ID, if omitted will use the requester user ID
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub user_id: Option<i64>,
+
+        /// Workspace ID, required
+        pub workspace_id: i64,
+    }
+
+    impl RequestBody {
+        pub fn new(workspace_id: i64, start: DateTime<Utc>, created_with: &str) -> Self {
+            Self {
+                billable: None,
+                created_with: created_with.to_string(),
+                description: None,
+                duration: None,
+                project_id: None,
+                start,
+                stop: None,
+                tag_ids: None,
+                tags: None,
+                task_id: None,
+                user_id: None,
+                workspace_id,
+            }
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Option<models::TimeEntry>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod put_time_entries {
+    use crate::models;
+    use chrono::{DateTime, Duration, Utc};
+    use serde::{Deserialize, Serialize};
+    use serde_with::{serde_as, DurationSeconds};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/time_entries/{time_entry_id}";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+        pub time_entry_id: i64,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum TagAction {
+        Add,
+        Delete,
+    }
+
+    /// Fields left as `None` keep their current value
+    #[serde_as]
+    #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+    pub struct RequestBody {
+        /// Whether the time entry is marked as billable
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub billable: Option<bool>,
+
+        /// Time entry description
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// Time entry duration. For running entries should be -1 * (Unix start time)
+        #[serde_as(as = "Option<DurationSeconds<i64>>")]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub duration: Option<Duration>,
+
+        /// Project ID
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub project_id: Option<i64>,
+
+        /// Start time in UTC
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub start: Option<DateTime<Utc>>,
+
+        /// Stop time in UTC
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub stop: Option<DateTime<Utc>>,
+
+        /// Whether `tag_ids` and `tags` are added to or deleted from the entry's tags. Without
+        /// it they replace them.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tag_action: Option<TagAction>,
+
+        /// IDs of tags to add/remove
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tag_ids: Option<Vec<i64>>,
+
+        /// Names of tags to add/remove. If name does not exist as tag, one will be created automatically
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tags: Option<Vec<String>>,
+
+        /// Task ID
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub task_id: Option<i64>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::TimeEntry);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod delete_time_entries {
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/time_entries/{time_entry_id}";
+
+    #[derive(Debug)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+        pub time_entry_id: i64,
+    }
+}
+
+pub mod post_clients {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/clients";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct RequestBody {
+        /// Client name
+        pub name: String,
+
+        /// Client notes, optional
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub notes: Option<String>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::Client);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod post_projects {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/projects";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+    pub struct RequestBody {
+        /// Project name
+        pub name: String,
+
+        /// Whether the project is active or archived
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub active: Option<bool>,
+
+        /// Whether the project is billable, premium feature
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub billable: Option<bool>,
+
+        /// Client ID, optional
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub client_id: Option<i64>,
+
+        /// Project color
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub color: Option<String>,
+
+        /// Project currency, premium feature
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub currency: Option<String>,
+
+        /// Whether the project is private
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub is_private: Option<bool>,
+
+        /// Hourly rate, premium feature
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rate: Option<f32>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::Project);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod post_tasks {
+    use crate::models;
+    use chrono::Duration;
+    use serde::{Deserialize, Serialize};
+    use serde_with::{serde_as, DurationSeconds};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/projects/{project_id}/tasks";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+        pub project_id: i64,
+    }
+
+    #[serde_as]
+    #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+    pub struct RequestBody {
+        /// Task name
+        pub name: String,
+
+        /// Use false to mark the task as done
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub active: Option<bool>,
+
+        /// Task estimation
+        #[serde_as(as = "Option<DurationSeconds<i64>>")]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub estimated_seconds: Option<Duration>,
+
+        /// Creator ID, if omitted will use the requester user ID
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub user_id: Option<i64>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::Task);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod post_tags {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/tags";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct RequestBody {
+        /// Tag name
+        pub name: String,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::Tag);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod get_webhook_subscriptions {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/webhooks/api/v1/subscriptions/{workspace_id}";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<models::WebhookSubscription>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod post_webhook_subscriptions {
+    use crate::models;
+    use crate::secret::Secret;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/webhooks/api/v1/subscriptions/{workspace_id}";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct RequestBody {
+        /// Description of the subscription, must be unique in the workspace
+        pub description: String,
+
+        /// Whether the subscription receives events
+        pub enabled: bool,
+
+        /// Which events are sent to the subscription
+        pub event_filters: Vec<models::EventFilter>,
+
+        /// Secret used to sign the events, generated by Toggl if omitted
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub secret: Option<Secret>,
+
+        /// URL the events are posted to
+        pub url_callback: String,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::WebhookSubscription);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod put_webhook_subscriptions {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/webhooks/api/v1/subscriptions/{workspace_id}/{subscription_id}";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+        pub subscription_id: i64,
+    }
+
+    pub use super::post_webhook_subscriptions::RequestBody;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::WebhookSubscription);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+/// Enables or disables a subscription
+pub mod patch_webhook_subscriptions {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/webhooks/api/v1/subscriptions/{workspace_id}/{subscription_id}";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+        pub subscription_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct RequestBody {
+        pub enabled: bool,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::WebhookSubscription);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod delete_webhook_subscriptions {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/webhooks/api/v1/subscriptions/{workspace_id}/{subscription_id}";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+        pub subscription_id: i64,
     }
 
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::WebhookSubscription);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+/// Lists the entities and actions that can be used in event filters
+pub mod get_webhook_event_filters {
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
 
+    pub const BASE_URL: &str = "https://api.track.toggl.com/webhooks/api/v1/event_filters";
 
+    /// Entity name to the actions available for it
     #[derive(Debug, Serialize, Deserialize)]
-    pub struct ResponseBody(Option<models::TimeEntry>);
+    pub struct ResponseBody(pub HashMap<String, Vec<String>>);
+
+    /// Any entity name is a valid key, so there's nothing to be unknown
+    impl crate::models::UnknownFields for ResponseBody {
+        fn unknown_fields(&self, _path: &str, _found: &mut Vec<String>) {}
+    }
+}
+
+/// Sends a ping event to the subscription's callback URL
+pub mod post_webhook_ping {
+    pub const BASE_URL: &str = "https://api.track.toggl.com/webhooks/api/v1/ping/{workspace_id}/{subscription_id}";
+
+    #[derive(Debug)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+        pub subscription_id: i64,
+    }
+}
+
+
+pub mod get_organization_users {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/organizations/{organization_id}/users";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub organization_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<models::User>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+/// Invites people to the organization by email, adding them to the given workspaces once they join
+pub mod post_organization_invitation {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+    use serde_with::{serde_as, DefaultOnNull};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/organizations/{organization_id}/invitation";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub organization_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct RequestBody {
+        /// Email addresses to invite
+        pub emails: Vec<String>,
+
+        /// Workspaces the invited users join
+        pub workspaces: Vec<InvitedWorkspace>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct InvitedWorkspace {
+        pub workspace_id: i64,
+
+        /// Whether the invited users become workspace admins
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub admin: Option<bool>,
+    }
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody {
+        /// One invitation per email address
+        #[serde_as(as = "DefaultOnNull")]
+        #[serde(default)]
+        pub data: Vec<models::Invitation>,
+
+        /// Problems with individual addresses, such as users that are already members
+        #[serde_as(as = "DefaultOnNull")]
+        #[serde(default)]
+        pub messages: Vec<String>,
+
+        #[serde(flatten)]
+        pub extra: models::Extra,
+    }
+
+    models::impl_unknown_fields!(ResponseBody, data);
+}
+
+/// Changes a single organization user. Set `inactive` to deactivate them or `organization_admin`
+/// to change their role.
+pub mod put_organization_users {
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/organizations/{organization_id}/users/{organization_user_id}";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub organization_id: i64,
+
+        /// The `id` of a [`crate::models::User`], not its `user_id`
+        pub organization_user_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+    pub struct RequestBody {
+        /// User's email address
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub email: Option<String>,
+
+        /// IDs of the groups the user is in, replaces the current groups
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub groups: Option<Vec<i64>>,
+
+        /// Whether the user is deactivated
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub inactive: Option<bool>,
+
+        /// User's name
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+
+        /// Whether the user is an admin of the organization
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub organization_admin: Option<bool>,
+
+        /// Workspaces the user is in and their role there, replaces the current workspaces
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub workspaces: Option<Vec<WorkspaceRole>>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct WorkspaceRole {
+        pub workspace_id: i64,
+
+        /// Whether the user is an admin of the workspace
+        pub admin: bool,
+    }
+}
+
+/// Removes users from the organization
+pub mod patch_organization_users {
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/organizations/{organization_id}/users";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub organization_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct RequestBody {
+        /// Organization user IDs to remove
+        pub delete: Vec<i64>,
+    }
+}
+
+pub mod get_workspace_users {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/workspace_users";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<models::WorkspaceUser>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod put_workspace_users {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/workspace_users/{workspace_user_id}";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+        pub workspace_user_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+    pub struct RequestBody {
+        /// Whether the user is an admin of the workspace
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub admin: Option<bool>,
+
+        /// Hourly cost of the user's time, premium feature
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub labour_cost: Option<f32>,
+
+        /// Hourly rate billed for the user's time, premium feature
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rate: Option<f32>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::WorkspaceUser);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+/// Removes a user from the workspace, they stay in the organization
+pub mod delete_workspace_users {
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/workspace_users/{workspace_user_id}";
+
+    #[derive(Debug)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+        pub workspace_user_id: i64,
+    }
+}
+
+pub mod get_organization_groups {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/organizations/{organization_id}/groups";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub organization_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<models::Group>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod post_organization_groups {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/organizations/{organization_id}/groups";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub organization_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+    pub struct RequestBody {
+        /// Group name
+        pub name: String,
+
+        /// User IDs of the members
+        pub users: Vec<i64>,
+
+        /// IDs of the workspaces the group is in
+        pub workspaces: Vec<i64>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::Group);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+/// Replaces a group's name, members and workspaces
+pub mod put_organization_groups {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/organizations/{organization_id}/groups/{group_id}";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub organization_id: i64,
+        pub group_id: i64,
+    }
+
+    pub use super::post_organization_groups::RequestBody;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::Group);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+/// Adds or removes members and workspaces without replacing the rest of the group
+pub mod patch_organization_groups {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/organizations/{organization_id}/groups/{group_id}";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub organization_id: i64,
+        pub group_id: i64,
+    }
+
+    /// Applied in order
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct RequestBody(pub Vec<Operation>);
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct Operation {
+        pub op: Op,
+        pub path: Target,
+        pub value: Vec<i64>,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Op {
+        Add,
+        Remove,
+        Replace,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    pub enum Target {
+        /// Values are user IDs
+        #[serde(rename = "/users")]
+        Users,
+
+        /// Values are workspace IDs
+        #[serde(rename = "/workspaces")]
+        Workspaces,
+    }
+
+    impl Operation {
+        pub fn add_users(user_ids: Vec<i64>) -> Self {
+            Self {
+                op: Op::Add,
+                path: Target::Users,
+                value: user_ids,
+            }
+        }
+
+        pub fn remove_users(user_ids: Vec<i64>) -> Self {
+            Self {
+                op: Op::Remove,
+                path: Target::Users,
+                value: user_ids,
+            }
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::Group);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod delete_organization_groups {
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/organizations/{organization_id}/groups/{group_id}";
+
+    #[derive(Debug)]
+    pub struct PathParams {
+        pub organization_id: i64,
+        pub group_id: i64,
+    }
+}
+
+pub mod get_workspace_groups {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/groups";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<models::Group>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod get_project_users {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/project_users";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, Default)]
+    pub struct RequestBody {
+        /// Only memberships of these projects
+        pub project_ids: Option<Vec<i64>>,
+
+        /// Only memberships of this user
+        pub user_id: Option<i64>,
+
+        /// Include the members of groups that were added to the projects
+        pub with_group_members: Option<bool>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<models::ProjectUser>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod post_project_users {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/project_users";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+    pub struct RequestBody {
+        /// Project ID
+        pub project_id: i64,
+
+        /// User ID
+        pub user_id: i64,
+
+        /// Hourly cost of the user's time on the project, premium feature
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub labor_cost: Option<f32>,
+
+        /// Whether the user can manage the project
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub manager: Option<bool>,
+
+        /// Hourly rate billed for the user's time on the project, premium feature
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rate: Option<f32>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::ProjectUser);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod put_project_users {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/project_users/{project_user_id}";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+        pub project_user_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+    pub struct RequestBody {
+        /// Hourly cost of the user's time on the project, premium feature
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub labor_cost: Option<f32>,
+
+        /// Whether the user can manage the project
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub manager: Option<bool>,
+
+        /// Hourly rate billed for the user's time on the project, premium feature
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rate: Option<f32>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::ProjectUser);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+/// Changes several project users at once
+pub mod patch_project_users {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+    use serde_json::Value;
+    use serde_with::{serde_as, DefaultOnNull};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/project_users/{project_user_ids}";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+
+        /// Sent comma separated
+        pub project_user_ids: Vec<i64>,
+    }
+
+    /// Applied to every project user in order
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct RequestBody(pub Vec<Operation>);
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct Operation {
+        pub op: Op,
+        pub path: Target,
+        pub value: Value,
+    }
+
+    pub use super::patch_organization_groups::Op;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    pub enum Target {
+        #[serde(rename = "/labor_cost")]
+        LaborCost,
+
+        #[serde(rename = "/manager")]
+        Manager,
+
+        #[serde(rename = "/rate")]
+        Rate,
+    }
+
+    impl Operation {
+        pub fn manager(manager: bool) -> Self {
+            Self {
+                op: Op::Replace,
+                path: Target::Manager,
+                value: manager.into(),
+            }
+        }
+
+        /// `None` removes the rate, so the project's rate applies again
+        pub fn rate(rate: Option<f32>) -> Self {
+            Self {
+                op: Op::Replace,
+                path: Target::Rate,
+                value: rate.into(),
+            }
+        }
+
+        pub fn labor_cost(labor_cost: Option<f32>) -> Self {
+            Self {
+                op: Op::Replace,
+                path: Target::LaborCost,
+                value: labor_cost.into(),
+            }
+        }
+    }
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody {
+        /// IDs of the project users that were changed
+        #[serde_as(as = "DefaultOnNull")]
+        #[serde(default)]
+        pub success: Vec<i64>,
+
+        #[serde_as(as = "DefaultOnNull")]
+        #[serde(default)]
+        pub failure: Vec<Failure>,
+
+        #[serde(flatten)]
+        pub extra: models::Extra,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Failure {
+        pub id: i64,
+        pub message: String,
+
+        #[serde(flatten)]
+        pub extra: models::Extra,
+    }
+
+    models::impl_unknown_fields!(ResponseBody, failure);
+    models::impl_unknown_fields!(Failure);
+}
+
+pub mod delete_project_users {
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/project_users/{project_user_id}";
+
+    #[derive(Debug)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+        pub project_user_id: i64,
+    }
+}
+
+pub mod put_workspaces {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    /// Only the fields that are set are changed
+    #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+    pub struct RequestBody {
+        /// Workspace name
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+
+        /// Currency used when projects don't set one, premium feature
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_currency: Option<String>,
+
+        /// Hourly rate used when projects and users don't set one, premium feature
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_hourly_rate: Option<f64>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub only_admins_may_create_projects: Option<bool>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub only_admins_may_create_tags: Option<bool>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub only_admins_see_billable_rates: Option<bool>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub only_admins_see_team_dashboard: Option<bool>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub projects_billable_by_default: Option<bool>,
+
+        /// Rounding mode, see [`crate::billing::Rounding::to_toggl`]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rounding: Option<i64>,
+
+        /// Minutes durations are rounded to, premium feature
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rounding_minutes: Option<i64>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::Workspace);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod get_preferences {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/me/preferences";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::Preferences);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod post_preferences {
+    use crate::day_of_week::DayOfWeek;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/me/preferences";
+
+    /// Only the fields that are set are changed
+    #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+    pub struct RequestBody {
+        #[serde(rename = "BeginningOfWeek", skip_serializing_if = "Option::is_none")]
+        pub beginning_of_week: Option<DayOfWeek>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub date_format: Option<String>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub duration_format: Option<String>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub pg_time_zone_name: Option<String>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub record_timeline: Option<bool>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub send_product_emails: Option<bool>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub send_timer_notifications: Option<bool>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub send_weekly_report: Option<bool>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub timeofday_format: Option<String>,
+    }
+}
+
+pub mod get_workspace_preferences {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/preferences";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::WorkspacePreferences);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod post_workspace_preferences {
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/preferences";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    /// Only the fields that are set are changed
+    #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+    pub struct RequestBody {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub hide_sidebar_signup: Option<bool>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub pg_time_zone_name: Option<String>,
+
+        /// Locks time entries on or before this date, e.g. `2024-01-31`
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub report_locked_at: Option<String>,
+    }
+}
+
+/// The latest time entries of everyone in the workspace, including running ones
+pub mod get_dashboard_all_activity {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/dashboard/all_activity";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<models::Activity>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+/// The users who tracked the most time in the workspace recently, most active first
+pub mod get_dashboard_most_active {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/dashboard/most_active";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<models::MostActive>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+/// Ad-hoc detailed report, one row per group of similar time entries
+pub mod post_reports_search_time_entries {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/reports/api/v3/workspace/{workspace_id}/search/time_entries";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    pub use crate::models::ReportFilters as RequestBody;
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<models::DetailedReportRow>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod get_saved_reports {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/reports/api/v3/workspace/{workspace_id}/saved";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<models::SavedReport>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod post_saved_reports {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/reports/api/v3/workspace/{workspace_id}/saved";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct RequestBody {
+        /// Report name
+        pub name: String,
+
+        /// Filters the report runs with
+        pub params: models::ReportFilters,
+
+        /// Whether anyone with the report's token can see it
+        pub public: bool,
+
+        /// Kind of report, e.g. `detailed`, `summary` or `weekly`
+        pub report_type: String,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::SavedReport);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod put_saved_reports {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/reports/api/v3/workspace/{workspace_id}/saved/{saved_report_id}";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+        pub saved_report_id: i64,
+    }
+
+    pub use super::post_saved_reports::RequestBody;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::SavedReport);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+/// The configuration of a shared report, works for anyone who has the token
+pub mod get_shared_report {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/reports/api/v3/shared/{report_token}";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub report_token: String,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::SavedReport);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+/// Runs a saved report with its stored filters
+pub mod post_shared_report {
+    use serde::{Deserialize, Serialize};
+    use serde_json::Value;
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/reports/api/v3/shared/{report_token}";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub report_token: String,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+    pub struct RequestBody {
+        /// Row to start from, the `row_number` after the last row of the previous page
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub first_row_number: Option<i64>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub page_size: Option<i64>,
+    }
+
+    /// Rows in the shape of the report's type, e.g. [`crate::models::DetailedReportRow`] for
+    /// detailed reports
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Value);
+
+    /// The shape depends on the report, so there's no model to compare against
+    impl crate::models::UnknownFields for ResponseBody {
+        fn unknown_fields(&self, _path: &str, _found: &mut Vec<String>) {}
+    }
+}
+
+/// Exports a detailed report as CSV
+pub mod post_reports_search_time_entries_csv {
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/reports/api/v3/workspace/{workspace_id}/search/time_entries.csv";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    pub use crate::models::ReportFilters as RequestBody;
+}
+
+/// Exports a detailed report as PDF
+pub mod post_reports_search_time_entries_pdf {
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/reports/api/v3/workspace/{workspace_id}/search/time_entries.pdf";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    pub use crate::models::ReportFilters as RequestBody;
+}
+
+/// Exports a summary report as CSV
+pub mod post_reports_summary_time_entries_csv {
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/reports/api/v3/workspace/{workspace_id}/summary/time_entries.csv";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    pub use crate::models::ReportFilters as RequestBody;
+}
+
+/// Exports a summary report as PDF
+pub mod post_reports_summary_time_entries_pdf {
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/reports/api/v3/workspace/{workspace_id}/summary/time_entries.pdf";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    pub use crate::models::ReportFilters as RequestBody;
+}
+
+/// Exports a weekly report as CSV
+pub mod post_reports_weekly_time_entries_csv {
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/reports/api/v3/workspace/{workspace_id}/weekly/time_entries.csv";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    pub use crate::models::ReportFilters as RequestBody;
+}
+
+/// Exports a weekly report as PDF
+pub mod post_reports_weekly_time_entries_pdf {
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/reports/api/v3/workspace/{workspace_id}/weekly/time_entries.pdf";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    pub use crate::models::ReportFilters as RequestBody;
+}
+
+pub mod get_workspace_track_reminders {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/track_reminders";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseBody(pub Vec<models::TrackReminder>);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+/// Checked with [`crate::validation::validate_track_reminder`] before it's sent
+pub mod post_track_reminders {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/track_reminders";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct RequestBody {
+        /// Frequency of the reminder in days, either 1 or 7
+        pub frequency: i64,
+
+        /// Users who tracked fewer hours than this in the period are reminded
+        pub threshold: i64,
+
+        /// Groups IDs to send the reminder to
+        pub group_ids: Vec<i64>,
+
+        /// User IDs to send the reminder to
+        pub user_ids: Vec<i64>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::TrackReminder);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+/// Checked with [`crate::validation::validate_track_reminder`] before it's sent
+pub mod put_track_reminders {
+    use crate::models;
+    use serde::{Deserialize, Serialize};
+
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/track_reminders/{reminder_id}";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+        pub reminder_id: i64,
+    }
+
+    pub use super::post_track_reminders::RequestBody;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResponseBody(pub models::TrackReminder);
+
+    models::impl_unknown_fields!(newtype ResponseBody);
+}
+
+pub mod delete_track_reminders {
+    pub const BASE_URL: &str = "https://api.track.toggl.com/api/v9/workspaces/{workspace_id}/track_reminders/{reminder_id}";
+
+    #[derive(Debug)]
+    pub struct PathParams {
+        pub workspace_id: i64,
+        pub reminder_id: i64,
+    }
 }
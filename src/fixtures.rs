@@ -0,0 +1,261 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Replaces the value of any of these keys, at any depth, when recording
+pub const REDACTED_KEYS: &[&str] = &[
+    "api_token",
+    "current_password",
+    "email",
+    "emails",
+    "intercom_hash",
+    "invitation_code",
+    "invite_url",
+    "openid_email",
+    "password",
+    "secret",
+    "token",
+];
+
+pub const REDACTED: &str = "[REDACTED]";
+
+/// A path segment following one of these is a token, like the `{report_token}` of a shared
+/// report, and is replaced in recorded and logged paths
+pub const TOKEN_SEGMENTS: &[&str] = &["shared"];
+
+/// `path` with its token segments replaced by [`REDACTED`], see [`TOKEN_SEGMENTS`]
+pub fn redact_path(path: &str) -> String {
+    let mut previous = "";
+    path.split('/')
+        .map(|segment| {
+            let redacted = match TOKEN_SEGMENTS.contains(&previous) && !segment.is_empty() {
+                true => REDACTED,
+                false => segment,
+            };
+            previous = segment;
+            redacted
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FixtureRequest {
+    pub method: String,
+
+    /// Path without scheme and host, so fixtures replay against any base URL. Tokens in it are
+    /// redacted, see [`redact_path`].
+    pub path: String,
+    pub query: Option<String>,
+    pub body: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FixtureResponse {
+    pub status: u16,
+
+    /// The response body, or null if it was empty or is binary
+    pub body: Value,
+
+    /// A binary body such as a PDF export, base64 encoded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_base64: Option<String>,
+}
+
+/// One captured request/response pair, stored as one JSON file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Fixture {
+    pub request: FixtureRequest,
+    pub response: FixtureResponse,
+}
+
+impl Fixture {
+    pub fn load(path: &Path) -> Result<Self, std::io::Error> {
+        let file = fs::File::open(path)?;
+        Ok(serde_json::from_reader(file)?)
+    }
+
+    /// A string body is sent as plain text, see [`Recorder`]
+    pub(crate) fn body_bytes(&self) -> Result<Vec<u8>, std::io::Error> {
+        if let Some(encoded) = &self.response.body_base64 {
+            return STANDARD
+                .decode(encoded)
+                .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err));
+        }
+        Ok(match &self.response.body {
+            Value::Null => Vec::new(),
+            Value::String(text) => text.as_bytes().to_vec(),
+            body => serde_json::to_vec(body).expect("JSON values always serialize"),
+        })
+    }
+}
+
+/// Replaces secrets and personal data with [`REDACTED`], keeping the JSON's shape
+pub fn redact(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if REDACTED_KEYS.contains(&key.as_str()) && !value.is_null() {
+                    *value = Value::String(String::from(REDACTED));
+                } else {
+                    redact(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+/// Parses a body with [`redact`] applied, for recording or logging it. Binary bodies are only
+/// described by their size.
+pub(crate) fn redacted_body(bytes: &[u8]) -> Value {
+    match body_value(bytes) {
+        Some(mut value) => {
+            redact(&mut value);
+            value
+        }
+        None => Value::String(format!("{} bytes of binary data", bytes.len())),
+    }
+}
+
+/// Bodies that aren't JSON, such as CSV exports, are kept as a string. `None` for binary bodies
+/// like PDF exports.
+fn body_value(bytes: &[u8]) -> Option<Value> {
+    if bytes.is_empty() {
+        return Some(Value::Null);
+    }
+    match serde_json::from_slice(bytes) {
+        Ok(value) => Some(value),
+        Err(_) => std::str::from_utf8(bytes)
+            .ok()
+            .map(|text| Value::String(text.to_string())),
+    }
+}
+
+/// Writes every exchange as `<sequence>-<method>-<path>.json` into a directory
+pub struct Recorder {
+    dir: PathBuf,
+    next: AtomicUsize,
+}
+
+impl Recorder {
+    /// Numbering continues after the fixtures already in `dir`
+    pub fn new(dir: impl Into<PathBuf>) -> Result<Self, std::io::Error> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        let existing = fs::read_dir(&dir)?
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+            .count();
+        Ok(Self {
+            dir,
+            next: AtomicUsize::new(existing),
+        })
+    }
+
+    pub(crate) fn record(
+        &self,
+        request: &reqwest::Request,
+        status: reqwest::StatusCode,
+        body: &[u8],
+    ) -> Result<PathBuf, std::io::Error> {
+        let request_body = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .map(redacted_body);
+        let (response_body, body_base64) = match body_value(body) {
+            Some(mut value) => {
+                redact(&mut value);
+                (value, None)
+            }
+            None => (Value::Null, Some(STANDARD.encode(body))),
+        };
+        let request_path = redact_path(request.url().path());
+        let fixture = Fixture {
+            request: FixtureRequest {
+                method: request.method().to_string(),
+                path: request_path.clone(),
+                query: request.url().query().map(str::to_string),
+                body: request_body,
+            },
+            response: FixtureResponse {
+                status: status.as_u16(),
+                body: response_body,
+                body_base64,
+            },
+        };
+
+        let sequence = self.next.fetch_add(1, Ordering::SeqCst);
+        let name: String = request_path
+            .trim_matches('/')
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let path = self.dir.join(format!(
+            "{:04}-{}-{}.json",
+            sequence,
+            request.method().as_str().to_lowercase(),
+            name
+        ));
+        fs::write(&path, serde_json::to_vec_pretty(&fixture)?)?;
+        Ok(path)
+    }
+}
+
+/// Answers requests from fixtures instead of the network.
+///
+/// Requests match on method, path with tokens redacted, and query. When several fixtures match
+/// they're served in file name order, and the last one keeps being served once they're used up.
+pub struct Replayer {
+    fixtures: Vec<Fixture>,
+    used: Mutex<Vec<bool>>,
+}
+
+impl Replayer {
+    pub fn new(fixtures: Vec<Fixture>) -> Self {
+        let used = Mutex::new(vec![false; fixtures.len()]);
+        Self { fixtures, used }
+    }
+
+    /// Loads every `.json` file in `dir`
+    pub fn load(dir: &Path) -> Result<Self, std::io::Error> {
+        let mut paths: Vec<_> = fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<_, _>>()?;
+        paths.retain(|path| path.extension().is_some_and(|ext| ext == "json"));
+        paths.sort();
+        let fixtures = paths
+            .iter()
+            .map(|path| Fixture::load(path))
+            .collect::<Result<_, _>>()?;
+        Ok(Self::new(fixtures))
+    }
+
+    pub(crate) fn respond(&self, request: &reqwest::Request) -> Option<&Fixture> {
+        let path = redact_path(request.url().path());
+        let matches = |fixture: &&Fixture| {
+            fixture.request.method == request.method().as_str()
+                && fixture.request.path == path
+                && fixture.request.query.as_deref() == request.url().query()
+        };
+        let mut used = self.used.lock().unwrap();
+        let unused = self
+            .fixtures
+            .iter()
+            .enumerate()
+            .find(|(index, fixture)| !used[*index] && matches(fixture));
+        match unused {
+            Some((index, fixture)) => {
+                used[index] = true;
+                Some(fixture)
+            }
+            None => self.fixtures.iter().rfind(matches),
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::models::{Client, Project, TimeEntry};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+const PRODID: &str = "-//togglrust//Toggl Track export//EN";
+
+/// Converts time entries into an RFC 5545 calendar with one VEVENT per entry.
+///
+/// Event UIDs are derived from the entry IDs, so re-importing an export updates the existing
+/// events instead of duplicating them. Running entries end at `now` and are marked tentative.
+/// Deleted entries are skipped.
+pub fn to_icalendar(
+    entries: &[TimeEntry],
+    projects: &[Project],
+    clients: &[Client],
+    now: DateTime<Utc>,
+) -> String {
+    let projects: HashMap<i64, &Project> = projects
+        .iter()
+        .map(|project| (project.id, project))
+        .collect();
+    let clients: HashMap<i64, &Client> = clients.iter().map(|client| (client.id, client)).collect();
+
+    let mut lines = vec![
+        String::from("BEGIN:VCALENDAR"),
+        String::from("VERSION:2.0"),
+        format!("PRODID:{}", PRODID),
+        String::from("CALSCALE:GREGORIAN"),
+    ];
+
+    for entry in entries
+        .iter()
+        .filter(|entry| entry.server_deleted_at.is_none())
+    {
+        let project = entry.project_id.and_then(|id| projects.get(&id));
+        let client = project
+            .and_then(|project| project.client_id.or(project.cid))
+            .and_then(|id| clients.get(&id));
+        let running = entry.stop.is_none() && entry.duration.num_seconds() < 0;
+        let end = match entry.stop {
+            Some(stop) => stop,
+            None if running => now,
+            None => entry.start + entry.duration,
+        };
+
+        let mut summary = entry
+            .description
+            .clone()
+            .filter(|description| !description.is_empty())
+            .unwrap_or_else(|| String::from("(no description)"));
+        match (project, client) {
+            (Some(project), Some(client)) => {
+                summary.push_str(&format!(" [{} / {}]", project.name, client.name))
+            }
+            (Some(project), None) => summary.push_str(&format!(" [{}]", project.name)),
+            _ => {}
+        }
+        if !entry.tags.is_empty() {
+            summary.push_str(&format!(" #{}", entry.tags.join(" #")));
+        }
+
+        let mut categories: Vec<String> = Vec::new();
+        categories.extend(project.map(|project| escape_text(&project.name)));
+        categories.extend(client.map(|client| escape_text(&client.name)));
+        categories.extend(entry.tags.iter().map(|tag| escape_text(tag)));
+
+        lines.push(String::from("BEGIN:VEVENT"));
+        lines.push(format!("UID:time-entry-{}@track.toggl.com", entry.id));
+        lines.push(format!("DTSTAMP:{}", format_timestamp(entry.at)));
+        lines.push(format!("LAST-MODIFIED:{}", format_timestamp(entry.at)));
+        lines.push(format!("DTSTART:{}", format_timestamp(entry.start)));
+        lines.push(format!("DTEND:{}", format_timestamp(end)));
+        lines.push(format!("SUMMARY:{}", escape_text(&summary)));
+        if let Some(description) = &entry.description {
+            lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+        }
+        if !categories.is_empty() {
+            lines.push(format!("CATEGORIES:{}", categories.join(",")));
+        }
+        if running {
+            lines.push(String::from("STATUS:TENTATIVE"));
+        } else {
+            lines.push(String::from("STATUS:CONFIRMED"));
+        }
+        if entry.billable {
+            lines.push(String::from("X-TOGGL-BILLABLE:TRUE"));
+        }
+        lines.push(String::from("TRANSP:OPAQUE"));
+        lines.push(String::from("END:VEVENT"));
+    }
+
+    lines.push(String::from("END:VCALENDAR"));
+
+    let mut out = String::new();
+    for line in lines {
+        out.push_str(&fold_line(&line));
+    }
+    out
+}
+
+fn format_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escapes a TEXT value as described in RFC 5545 section 3.3.11
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Folds a content line so that no physical line exceeds 75 octets, terminated by CRLF
+fn fold_line(line: &str) -> String {
+    let mut out = String::with_capacity(line.len() + 2);
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += len;
+    }
+    out.push_str("\r\n");
+    out
+}
@@ -0,0 +1,371 @@
+use crate::billing::Calculator;
+use crate::models::{Client, Project, ProjectUser, Task, TimeEntry, Workspace, WorkspaceUser};
+use chrono::NaiveDate;
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("invoice numbers use the prefix {found:?}, not {expected:?}")]
+    PrefixMismatch { expected: String, found: String },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LineItem {
+    pub project_id: Option<i64>,
+    pub project_name: Option<String>,
+    pub task_id: Option<i64>,
+    pub task_name: Option<String>,
+    pub description: String,
+    pub hours: f64,
+
+    /// Hourly rate, `None` for fixed fee projects
+    pub rate: Option<f64>,
+    pub currency: String,
+    pub amount: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Invoice {
+    pub number: String,
+    pub issued_on: NaiveDate,
+    pub client_id: i64,
+    pub client_name: String,
+
+    /// First day of the invoiced period
+    pub period_start: NaiveDate,
+
+    /// Last day of the invoiced period, inclusive
+    pub period_end: NaiveDate,
+    pub line_items: Vec<LineItem>,
+
+    /// Invoice total per currency
+    pub totals: BTreeMap<String, f64>,
+}
+
+/// Everything needed to invoice one client of a workspace
+pub struct InvoiceInput<'a> {
+    pub workspace: &'a Workspace,
+    pub client: &'a Client,
+    pub projects: &'a [Project],
+
+    /// Project memberships whose rates override the project's, may be empty
+    pub project_users: &'a [ProjectUser],
+
+    /// Workspace memberships whose rates override the workspace default, may be empty
+    pub workspace_users: &'a [WorkspaceUser],
+    pub tasks: &'a [Task],
+    pub time_entries: &'a [TimeEntry],
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+
+    /// Timezone the period's dates are in, usually the user's from `get_me`
+    pub timezone: Tz,
+
+    /// Fixed fee projects invoiced before, whose entries are listed without billing the fee again
+    pub fixed_fees_billed: &'a BTreeSet<i64>,
+}
+
+impl Invoice {
+    /// Builds an invoice from the client's billable entries that started within the period.
+    ///
+    /// Entries are grouped by project, task, description and rate, so people billed at different
+    /// rates get separate line items. Fixed fee projects are billed as a single line item carrying
+    /// the fee, unless the fee was billed before.
+    pub fn build(number: String, issued_on: NaiveDate, input: InvoiceInput) -> Self {
+        let calculator = Calculator::new(input.workspace, input.projects)
+            .with_project_users(input.project_users)
+            .with_workspace_users(input.workspace_users);
+        let projects: HashMap<i64, &Project> = input
+            .projects
+            .iter()
+            .filter(|project| project.client_id.or(project.cid) == Some(input.client.id))
+            .map(|project| (project.id, project))
+            .collect();
+        let task_names: HashMap<i64, &str> = input
+            .tasks
+            .iter()
+            .map(|task| (task.id, task.name.as_str()))
+            .collect();
+
+        // Rates are keyed by their bits, fixed fee items by zero so they stay a single item
+        let mut groups: BTreeMap<(Option<i64>, Option<i64>, String, u64), LineItem> =
+            BTreeMap::new();
+        for entry in input.time_entries {
+            let date = entry.start.with_timezone(&input.timezone).date_naive();
+            if date < input.period_start || date > input.period_end {
+                continue;
+            }
+            let Some(project) = entry.project_id.and_then(|id| projects.get(&id)) else {
+                continue;
+            };
+            let Some(entry_amount) = calculator.entry_amount(entry) else {
+                continue;
+            };
+
+            let fixed_fee = project.fixed_fee.is_some();
+            let fee_billed = input.fixed_fees_billed.contains(&project.id);
+            let (task_id, description) = if fixed_fee && fee_billed {
+                (None, String::from("Fixed fee, invoiced before"))
+            } else if fixed_fee {
+                (None, String::from("Fixed fee"))
+            } else {
+                (entry.task_id, entry.description.clone().unwrap_or_default())
+            };
+            let rate_key = if fixed_fee { 0 } else { entry_amount.rate.to_bits() };
+            let item = groups
+                .entry((Some(project.id), task_id, description.clone(), rate_key))
+                .or_insert_with(|| LineItem {
+                    project_id: Some(project.id),
+                    project_name: Some(project.name.clone()),
+                    task_id,
+                    task_name: task_id
+                        .and_then(|id| task_names.get(&id))
+                        .map(|name| name.to_string()),
+                    description,
+                    hours: 0.0,
+                    rate: (!fixed_fee).then_some(entry_amount.rate),
+                    currency: entry_amount.currency.clone(),
+                    amount: project
+                        .fixed_fee
+                        .filter(|_| !fee_billed)
+                        .map(f64::from)
+                        .unwrap_or(0.0),
+                });
+            item.hours += entry_amount.duration.num_seconds() as f64 / 3600.0;
+            item.amount += entry_amount.amount;
+        }
+
+        let line_items: Vec<LineItem> = groups.into_values().collect();
+        let mut totals = BTreeMap::new();
+        for item in &line_items {
+            *totals.entry(item.currency.clone()).or_insert(0.0) += item.amount;
+        }
+
+        Invoice {
+            number,
+            issued_on,
+            client_id: input.client.id,
+            client_name: input.client.name.clone(),
+            period_start: input.period_start,
+            period_end: input.period_end,
+            line_items,
+            totals,
+        }
+    }
+
+    /// Projects whose fixed fee this invoice bills
+    pub fn fixed_fee_projects(&self) -> impl Iterator<Item = i64> + '_ {
+        self.line_items
+            .iter()
+            .filter(|item| item.rate.is_none() && item.amount > 0.0)
+            .filter_map(|item| item.project_id)
+    }
+
+    pub fn to_json(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "# Invoice {}", self.number).unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "- **Client:** {}", self.client_name).unwrap();
+        writeln!(out, "- **Issued:** {}", self.issued_on).unwrap();
+        writeln!(
+            out,
+            "- **Period:** {} to {}",
+            self.period_start, self.period_end
+        )
+        .unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "| Project | Task | Description | Hours | Rate | Amount |"
+        )
+        .unwrap();
+        writeln!(out, "|---|---|---|---:|---:|---:|").unwrap();
+        for item in &self.line_items {
+            writeln!(
+                out,
+                "| {} | {} | {} | {:.2} | {} | {:.2} {} |",
+                escape_markdown(item.project_name.as_deref().unwrap_or("")),
+                escape_markdown(item.task_name.as_deref().unwrap_or("")),
+                escape_markdown(&item.description),
+                item.hours,
+                item.rate
+                    .map(|rate| format!("{:.2}", rate))
+                    .unwrap_or_default(),
+                item.amount,
+                item.currency,
+            )
+            .unwrap();
+        }
+        writeln!(out).unwrap();
+        for (currency, total) in &self.totals {
+            writeln!(out, "**Total ({}):** {:.2}", currency, total).unwrap();
+            writeln!(out).unwrap();
+        }
+        out
+    }
+
+    pub fn to_html(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "<!DOCTYPE html>").unwrap();
+        writeln!(out, "<html>").unwrap();
+        writeln!(
+            out,
+            "<head><meta charset=\"utf-8\"><title>Invoice {}</title></head>",
+            escape_html(&self.number)
+        )
+        .unwrap();
+        writeln!(out, "<body>").unwrap();
+        writeln!(out, "<h1>Invoice {}</h1>", escape_html(&self.number)).unwrap();
+        writeln!(out, "<dl>").unwrap();
+        writeln!(
+            out,
+            "<dt>Client</dt><dd>{}</dd>",
+            escape_html(&self.client_name)
+        )
+        .unwrap();
+        writeln!(out, "<dt>Issued</dt><dd>{}</dd>", self.issued_on).unwrap();
+        writeln!(
+            out,
+            "<dt>Period</dt><dd>{} to {}</dd>",
+            self.period_start, self.period_end
+        )
+        .unwrap();
+        writeln!(out, "</dl>").unwrap();
+        writeln!(out, "<table>").unwrap();
+        writeln!(out, "<thead><tr><th>Project</th><th>Task</th><th>Description</th><th>Hours</th><th>Rate</th><th>Amount</th></tr></thead>").unwrap();
+        writeln!(out, "<tbody>").unwrap();
+        for item in &self.line_items {
+            writeln!(
+                out,
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{:.2}</td><td>{}</td><td>{:.2} {}</td></tr>",
+                escape_html(item.project_name.as_deref().unwrap_or("")),
+                escape_html(item.task_name.as_deref().unwrap_or("")),
+                escape_html(&item.description),
+                item.hours,
+                item.rate.map(|rate| format!("{:.2}", rate)).unwrap_or_default(),
+                item.amount,
+                escape_html(&item.currency),
+            )
+            .unwrap();
+        }
+        writeln!(out, "</tbody>").unwrap();
+        writeln!(out, "<tfoot>").unwrap();
+        for (currency, total) in &self.totals {
+            writeln!(
+                out,
+                "<tr><th colspan=\"5\">Total ({})</th><td>{:.2}</td></tr>",
+                escape_html(currency),
+                total
+            )
+            .unwrap();
+        }
+        writeln!(out, "</tfoot>").unwrap();
+        writeln!(out, "</table>").unwrap();
+        writeln!(out, "</body>").unwrap();
+        writeln!(out, "</html>").unwrap();
+        out
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct NumberingState {
+    prefix: String,
+    next: u64,
+    #[serde(default)]
+    fixed_fees_billed: BTreeSet<i64>,
+}
+
+/// Hands out sequential invoice numbers and remembers which fixed fees were billed, persisted in
+/// a JSON file so numbers are never reused and fees are billed only once
+pub struct InvoiceNumbering {
+    path: PathBuf,
+    state: NumberingState,
+}
+
+impl InvoiceNumbering {
+    /// Opens the numbering file at `path`, starting at 1 with `prefix` if it doesn't exist yet.
+    /// Fails if the file numbers invoices with a different prefix.
+    pub fn open(path: impl AsRef<Path>, prefix: &str) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+        let state: NumberingState = match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => NumberingState {
+                prefix: prefix.to_string(),
+                next: 1,
+                fixed_fees_billed: BTreeSet::new(),
+            },
+            Err(err) => return Err(err.into()),
+        };
+        if state.prefix != prefix {
+            return Err(Error::PrefixMismatch {
+                expected: prefix.to_string(),
+                found: state.prefix,
+            });
+        }
+        Ok(Self { path, state })
+    }
+
+    /// Reserves the next invoice number and writes the updated counter to disk
+    pub fn next_number(&mut self) -> Result<String, Error> {
+        let number = format!("{}{:05}", self.state.prefix, self.state.next);
+        self.state.next += 1;
+        self.save()?;
+        Ok(number)
+    }
+
+    /// Fixed fee projects invoiced so far, to pass to [`InvoiceInput`]
+    pub fn fixed_fees_billed(&self) -> &BTreeSet<i64> {
+        &self.state.fixed_fees_billed
+    }
+
+    /// Remembers the fixed fees an issued invoice billed, so later invoices don't bill them again
+    pub fn record_fixed_fees(&mut self, invoice: &Invoice) -> Result<(), Error> {
+        self.state
+            .fixed_fees_billed
+            .extend(invoice.fixed_fee_projects());
+        self.save()
+    }
+
+    /// Writes to a temporary file first, so a crash can't leave a truncated file behind and
+    /// numbers can't be handed out twice
+    fn save(&self) -> Result<(), Error> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut temporary = self.path.as_os_str().to_owned();
+        temporary.push(".tmp");
+        let mut file = fs::File::create(&temporary)?;
+        io::Write::write_all(&mut file, &serde_json::to_vec_pretty(&self.state)?)?;
+        file.sync_all()?;
+        fs::rename(&temporary, &self.path)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+pub mod api;
+pub mod billing;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod client;
+pub mod credentials;
+pub mod csv_import;
+pub mod dashboard;
+pub mod day_of_week;
+pub mod endpoints;
+pub mod fixtures;
+pub mod ical;
+pub mod invoice;
+pub mod migrate;
+pub mod models;
+pub mod plaintext;
+pub mod reminders;
+#[cfg(feature = "schema")]
+pub mod schema;
+pub mod secret;
+pub mod timewarrior;
+pub mod validation;
+pub mod webhooks;
@@ -1,17 +1,342 @@
-mod client;
-mod day_of_week;
-mod endpoints;
-mod models;
-use client::{Auth, Client};
+use chrono::{NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
+use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+use togglrust::client::{Auth, Client};
+use togglrust::credentials::CredentialStore;
+use togglrust::csv_import::{self, ColumnMapping, ImportContext, ImportPlan};
+use togglrust::plaintext::{self, ImportTarget};
+use togglrust::secret::Secret;
+#[cfg(feature = "schema")]
+use togglrust::schema;
+use togglrust::{endpoints, ical, migrate, timewarrior};
+
+#[derive(Parser)]
+struct Cli {
+    /// Warn about response fields the models don't know about
+    #[arg(long, global = true)]
+    strict: bool,
+
+    /// Saved credentials to use when TOGGL_USER isn't set
+    #[arg(long, global = true, default_value = "default")]
+    profile: String,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Export time entries as an iCalendar (.ics) file
+    Ical {
+        /// First day to export (YYYY-MM-DD)
+        #[arg(long)]
+        start_date: Option<NaiveDate>,
+
+        /// Last day to export (YYYY-MM-DD)
+        #[arg(long)]
+        end_date: Option<NaiveDate>,
+
+        /// Where to write the calendar, defaults to stdout
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+
+    /// Create time entries from a CSV file
+    ImportCsv {
+        file: PathBuf,
+
+        /// JSON file mapping CSV columns to time entry fields
+        #[arg(long)]
+        mapping: Option<PathBuf>,
+
+        /// Workspace to import into, defaults to the user's default workspace
+        #[arg(long)]
+        workspace_id: Option<i64>,
+
+        /// Timezone of the times in the file, defaults to the user's timezone
+        #[arg(long)]
+        timezone: Option<Tz>,
+
+        /// Only print what would be created
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Sync a Timewarrior database with Toggl
+    TimewSync {
+        /// Timewarrior data directory, usually ~/.timewarrior/data
+        data_dir: PathBuf,
+
+        /// JSON file mapping Timewarrior tags to Toggl projects and tags
+        #[arg(long)]
+        mapping: PathBuf,
+
+        /// Where the ID correlation table is kept between runs
+        #[arg(long)]
+        state: PathBuf,
+    },
+
+    /// Export time entries as org-mode clocks or a ledger timeclock file
+    Export {
+        #[arg(value_enum)]
+        format: TextFormat,
+
+        /// First day to export (YYYY-MM-DD)
+        #[arg(long)]
+        start_date: Option<NaiveDate>,
+
+        /// Last day to export (YYYY-MM-DD)
+        #[arg(long)]
+        end_date: Option<NaiveDate>,
+
+        /// Where to write the export, defaults to stdout
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+
+        /// Timezone to write the times in, defaults to the user's timezone
+        #[arg(long)]
+        timezone: Option<Tz>,
+    },
+
+    /// Create time entries from org-mode clocks or a ledger timeclock file
+    Import {
+        #[arg(value_enum)]
+        format: TextFormat,
+
+        file: PathBuf,
+
+        /// Workspace to import into, defaults to the user's default workspace
+        #[arg(long)]
+        workspace_id: Option<i64>,
+
+        /// Timezone of the times in the file, defaults to the user's timezone
+        #[arg(long)]
+        timezone: Option<Tz>,
+
+        /// Only print what would be created
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Migrate a Clockify detailed report or Harvest time export into a workspace
+    Migrate {
+        #[arg(value_enum)]
+        source: MigrationSource,
+
+        file: PathBuf,
+
+        /// Progress journal, pass the same file again to resume an interrupted migration
+        #[arg(long)]
+        journal: PathBuf,
+
+        /// Workspace to migrate into, defaults to the user's default workspace
+        #[arg(long)]
+        workspace_id: Option<i64>,
+
+        /// Timezone of the times in the export, defaults to the user's timezone
+        #[arg(long)]
+        timezone: Option<Tz>,
+
+        /// Time of day the first Harvest entry of each day starts at
+        #[arg(long, default_value = "09:00:00")]
+        day_start: NaiveTime,
+    },
+
+    /// Compare the crate's endpoints and models against a saved Toggl OpenAPI or Swagger JSON
+    /// document, exiting with 1 if they differ. Doesn't talk to the API.
+    #[cfg(feature = "schema")]
+    CheckSchema { spec: PathBuf },
+
+    /// Save an API token for the profile, encrypted with a passphrase. The token is read from
+    /// TOGGL_API_TOKEN and the passphrase from TOGGL_PASSPHRASE if set, otherwise prompted for.
+    Login,
+
+    /// Forget the profile's saved API token
+    Logout,
+
+    /// Show who the credentials in use belong to
+    Whoami,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum MigrationSource {
+    Clockify,
+    Harvest,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum TextFormat {
+    Org,
+    Timeclock,
+}
 
 #[tokio::main]
 async fn main() {
+    let cli = Cli::parse();
+    match &cli.command {
+        #[cfg(feature = "schema")]
+        Some(Command::CheckSchema { spec }) => return check_schema(spec),
+        Some(Command::Login) => return login(&cli.profile).await,
+        Some(Command::Logout) => return logout(&cli.profile),
+        _ => {}
+    }
+    let mut client = Client::new(auth(&cli.profile));
+    if cli.strict {
+        client = client.strict();
+    }
+
+    match cli.command {
+        None => dump(&client).await,
+        Some(Command::Ical {
+            start_date,
+            end_date,
+            output,
+        }) => export_ical(&client, start_date, end_date, output).await,
+        Some(Command::ImportCsv {
+            file,
+            mapping,
+            workspace_id,
+            timezone,
+            dry_run,
+        }) => import_csv(&client, file, mapping, workspace_id, timezone, dry_run).await,
+        Some(Command::TimewSync {
+            data_dir,
+            mapping,
+            state,
+        }) => timew_sync(&client, data_dir, mapping, state).await,
+        Some(Command::Export {
+            format,
+            start_date,
+            end_date,
+            output,
+            timezone,
+        }) => export_text(&client, format, start_date, end_date, output, timezone).await,
+        Some(Command::Import {
+            format,
+            file,
+            workspace_id,
+            timezone,
+            dry_run,
+        }) => import_text(&client, format, file, workspace_id, timezone, dry_run).await,
+        Some(Command::Migrate {
+            source,
+            file,
+            journal,
+            workspace_id,
+            timezone,
+            day_start,
+        }) => {
+            migrate(
+                &client,
+                source,
+                file,
+                journal,
+                workspace_id,
+                timezone,
+                day_start,
+            )
+            .await
+        }
+        Some(Command::Whoami) => whoami(&client).await,
+        #[cfg(feature = "schema")]
+        Some(Command::CheckSchema { .. }) => unreachable!("handled before logging in"),
+        Some(Command::Login | Command::Logout) => unreachable!("handled before logging in"),
+    }
+
+    for drift in client.take_drift() {
+        eprintln!("warning: {}", drift);
+    }
+}
+
+/// TOGGL_USER and TOGGL_PASSWORD if set, otherwise the profile's saved API token
+fn auth(profile: &str) -> Auth {
+    if let Ok(user) = std::env::var("TOGGL_USER") {
+        return Auth {
+            user: user.into(),
+            password: std::env::var("TOGGL_PASSWORD")
+                .expect("Didn't find TOGGL_PASSWORD in environment")
+                .into(),
+        };
+    }
+    let store = credential_store();
+    if !store.profiles().any(|saved| saved == profile) {
+        panic!(
+            "Didn't find TOGGL_USER in environment or saved credentials for profile {}, run login first",
+            profile
+        );
+    }
+    let token = store
+        .load(profile, &passphrase(false))
+        .expect("Couldn't decrypt saved credentials");
+    Auth {
+        user: token,
+        password: Secret::from("api_token"),
+    }
+}
+
+fn credential_store() -> CredentialStore {
+    let path = CredentialStore::default_path()
+        .expect("Couldn't find a config directory, set TOGGLRUST_CREDENTIALS");
+    CredentialStore::open(path).expect("Couldn't read saved credentials")
+}
+
+/// Reads a secret from the terminal with echo turned off
+fn prompt(label: &str) -> Secret {
+    rpassword::prompt_password(format!("{}: ", label))
+        .map(Secret::from)
+        .expect("Couldn't read from the terminal")
+}
+
+fn passphrase(confirm: bool) -> Secret {
+    if let Ok(passphrase) = std::env::var("TOGGL_PASSPHRASE") {
+        return passphrase.into();
+    }
+    let passphrase = prompt("Passphrase");
+    if confirm && prompt("Repeat passphrase") != passphrase {
+        panic!("Passphrases don't match");
+    }
+    passphrase
+}
+
+async fn login(profile: &str) {
+    let token = match std::env::var("TOGGL_API_TOKEN") {
+        Ok(token) => Secret::from(token),
+        Err(_) => prompt("API token"),
+    };
     let client = Client::new(Auth {
-        user: std::env::var("TOGGL_USER").expect("Didn't find TOGGL_USER in environment"),
-        password: std::env::var("TOGGL_PASSWORD")
-            .expect("Didn't find TOGGL_PASSWORD in environment"),
+        user: token,
+        password: Secret::from("api_token"),
     });
+    client
+        .get_logged()
+        .await
+        .expect("Toggl didn't accept the API token");
+    let token = client.into_auth().user;
+
+    let mut store = credential_store();
+    store
+        .save(profile, &token, &passphrase(true))
+        .expect("Couldn't save credentials");
+    eprintln!("Saved credentials for profile {}", profile);
+}
+
+fn logout(profile: &str) {
+    let removed = credential_store()
+        .remove(profile)
+        .expect("Couldn't update saved credentials");
+    match removed {
+        true => eprintln!("Removed credentials for profile {}", profile),
+        false => eprintln!("No credentials saved for profile {}", profile),
+    }
+}
+
+async fn whoami(client: &Client) {
+    let me = client.get_me().await.expect("Couldn't fetch user");
+    println!("{} <{}>", me.fullname, me.email);
+}
 
+async fn dump(client: &Client) {
     let result = client.get_me().await;
     println!("{:#?}", result);
 
@@ -24,3 +349,370 @@ async fn main() {
         .await;
     println!("{:#?}", projects_result);
 }
+
+#[cfg(feature = "schema")]
+fn check_schema(spec: &std::path::Path) {
+    let spec = schema::Spec::from_json(
+        &std::fs::read_to_string(spec).expect("Couldn't read spec"),
+    )
+    .expect("Couldn't parse spec");
+    let differences = schema::compare(&spec).expect("Couldn't parse the crate's sources");
+    for difference in &differences {
+        println!("{}", difference);
+    }
+    if !differences.is_empty() {
+        eprintln!("{} differences", differences.len());
+        std::process::exit(1);
+    }
+}
+
+async fn export_ical(
+    client: &Client,
+    start_date: Option<NaiveDate>,
+    end_date: Option<NaiveDate>,
+    output: Option<PathBuf>,
+) {
+    let entries = client
+        .get_time_entries(endpoints::get_time_entries::RequestBody {
+            start_date,
+            // The API treats end_date as exclusive
+            end_date: end_date.and_then(|date| date.succ_opt()),
+            ..Default::default()
+        })
+        .await
+        .expect("Couldn't fetch time entries");
+    let projects = client
+        .get_projects(endpoints::get_projects::RequestBody {
+            include_archived: Some(true),
+            ..Default::default()
+        })
+        .await
+        .expect("Couldn't fetch projects");
+    let clients = client
+        .get_clients(endpoints::get_clients::RequestBody::default())
+        .await
+        .expect("Couldn't fetch clients");
+
+    let calendar = ical::to_icalendar(&entries.0, &projects.0, &clients.0, Utc::now());
+    match output {
+        Some(path) => std::fs::write(path, calendar).expect("Couldn't write calendar"),
+        None => print!("{}", calendar),
+    }
+}
+
+async fn import_csv(
+    client: &Client,
+    file: PathBuf,
+    mapping: Option<PathBuf>,
+    workspace_id: Option<i64>,
+    timezone: Option<Tz>,
+    dry_run: bool,
+) {
+    let mapping: ColumnMapping = match mapping {
+        Some(path) => serde_json::from_str(
+            &std::fs::read_to_string(path).expect("Couldn't read column mapping"),
+        )
+        .expect("Couldn't parse column mapping"),
+        None => ColumnMapping::default(),
+    };
+    let me = client.get_me().await.expect("Couldn't fetch user");
+    let timezone = timezone.unwrap_or_else(|| {
+        me.timezone.parse().unwrap_or_else(|_| {
+            panic!(
+                "Couldn't parse the user's timezone {:?}, pass --timezone",
+                me.timezone
+            )
+        })
+    });
+    let workspace_id = workspace_id
+        .or(me.default_workspace_id)
+        .expect("No workspace given and the user has no default workspace");
+
+    let workspaces = client
+        .get_workspaces(endpoints::get_workspaces::RequestBody::default())
+        .await
+        .expect("Couldn't fetch workspaces");
+    let workspace = workspaces
+        .0
+        .iter()
+        .find(|workspace| workspace.id == workspace_id)
+        .expect("Workspace not found");
+    let projects = client
+        .get_projects(endpoints::get_projects::RequestBody {
+            include_archived: Some(true),
+            ..Default::default()
+        })
+        .await
+        .expect("Couldn't fetch projects");
+    let clients = client
+        .get_clients(endpoints::get_clients::RequestBody::default())
+        .await
+        .expect("Couldn't fetch clients");
+    let tags = client
+        .get_tags(endpoints::get_tags::RequestBody::default())
+        .await
+        .expect("Couldn't fetch tags");
+    let contents = std::fs::read(file).expect("Couldn't read CSV file");
+
+    let mut context = ImportContext {
+        workspace,
+        timezone,
+        projects: &projects.0,
+        clients: &clients.0,
+        tags: &tags.0,
+        existing: &[],
+    };
+
+    // Plan once without existing entries to find out which dates need to be checked for duplicates
+    let plan = ImportPlan::new(contents.as_slice(), &mapping, &context).expect("Couldn't read CSV");
+    let dates: Vec<NaiveDate> = plan
+        .to_create()
+        .map(|(_, body)| body.start.date_naive())
+        .collect();
+    let (first, last) = (dates.iter().min(), dates.iter().max());
+    let existing = match (first, last) {
+        (Some(first), Some(last)) => {
+            client
+                .get_time_entries(endpoints::get_time_entries::RequestBody {
+                    start_date: first.pred_opt(),
+                    end_date: last.succ_opt().and_then(|date| date.succ_opt()),
+                    ..Default::default()
+                })
+                .await
+                .expect("Couldn't fetch existing time entries")
+                .0
+        }
+        _ => Vec::new(),
+    };
+    context.existing = &existing;
+    let plan = ImportPlan::new(contents.as_slice(), &mapping, &context).expect("Couldn't read CSV");
+
+    print!("{}", plan.diff(timezone));
+    if dry_run {
+        return;
+    }
+    if plan.has_errors() {
+        eprintln!("Not importing anything until all rows are valid");
+        std::process::exit(1);
+    }
+    match plan.execute(client).await {
+        Ok(created) => println!("Created {} time entries", created.len()),
+        Err(csv_import::Error::Client {
+            line,
+            created,
+            source,
+        }) => {
+            eprintln!("Import failed at line {}: {}", line, source);
+            for entry in &created {
+                eprintln!("Created {} at {}", entry.id, entry.start);
+            }
+            eprintln!(
+                "{} time entries were created, rerun the import to skip them as duplicates",
+                created.len()
+            );
+            std::process::exit(1);
+        }
+        Err(err) => panic!("Import failed: {}", err),
+    }
+}
+
+async fn timew_sync(client: &Client, data_dir: PathBuf, mapping: PathBuf, state: PathBuf) {
+    let mapping: timewarrior::TagMapping =
+        serde_json::from_str(&std::fs::read_to_string(mapping).expect("Couldn't read tag mapping"))
+            .expect("Couldn't parse tag mapping");
+    let mut sync = timewarrior::Sync::open(client, &mapping, data_dir, state)
+        .expect("Couldn't open Timewarrior database");
+    let report = sync.run().await.expect("Sync failed");
+    println!(
+        "Pushed {}, pulled {}, updated {}, removed {}, updated in Toggl {}, deleted from Toggl {}",
+        report.pushed,
+        report.pulled,
+        report.updated,
+        report.removed,
+        report.pushed_updates,
+        report.pushed_deletions
+    );
+}
+
+async fn export_text(
+    client: &Client,
+    format: TextFormat,
+    start_date: Option<NaiveDate>,
+    end_date: Option<NaiveDate>,
+    output: Option<PathBuf>,
+    timezone: Option<Tz>,
+) {
+    let me = client.get_me().await.expect("Couldn't fetch user");
+    let timezone = timezone.unwrap_or_else(|| {
+        me.timezone.parse().unwrap_or_else(|_| {
+            panic!(
+                "Couldn't parse the user's timezone {:?}, pass --timezone",
+                me.timezone
+            )
+        })
+    });
+    let entries = client
+        .get_time_entries(endpoints::get_time_entries::RequestBody {
+            start_date,
+            // The API treats end_date as exclusive
+            end_date: end_date.and_then(|date| date.succ_opt()),
+            ..Default::default()
+        })
+        .await
+        .expect("Couldn't fetch time entries");
+    let projects = client
+        .get_projects(endpoints::get_projects::RequestBody {
+            include_archived: Some(true),
+            ..Default::default()
+        })
+        .await
+        .expect("Couldn't fetch projects");
+
+    let text = match format {
+        TextFormat::Org => plaintext::org::export(&entries.0, &projects.0, timezone),
+        TextFormat::Timeclock => plaintext::timeclock::export(&entries.0, &projects.0, timezone),
+    };
+    match output {
+        Some(path) => std::fs::write(path, text).expect("Couldn't write export"),
+        None => print!("{}", text),
+    }
+}
+
+async fn import_text(
+    client: &Client,
+    format: TextFormat,
+    file: PathBuf,
+    workspace_id: Option<i64>,
+    timezone: Option<Tz>,
+    dry_run: bool,
+) {
+    let me = client.get_me().await.expect("Couldn't fetch user");
+    let timezone = timezone.unwrap_or_else(|| {
+        me.timezone.parse().unwrap_or_else(|_| {
+            panic!(
+                "Couldn't parse the user's timezone {:?}, pass --timezone",
+                me.timezone
+            )
+        })
+    });
+    let workspace_id = workspace_id
+        .or(me.default_workspace_id)
+        .expect("No workspace given and the user has no default workspace");
+    let projects = client
+        .get_projects(endpoints::get_projects::RequestBody::default())
+        .await
+        .expect("Couldn't fetch projects");
+    let text = std::fs::read_to_string(file).expect("Couldn't read file");
+
+    let target = ImportTarget {
+        workspace_id,
+        timezone,
+        projects: &projects.0,
+    };
+    let entries = match format {
+        TextFormat::Org => plaintext::org::import(&text, &target),
+        TextFormat::Timeclock => plaintext::timeclock::import(&text, &target),
+    }
+    .expect("Couldn't parse file");
+
+    for entry in entries {
+        println!(
+            "+ {} {} {}",
+            entry
+                .start
+                .with_timezone(&timezone)
+                .format("%Y-%m-%d %H:%M"),
+            entry
+                .duration
+                .map(|duration| duration.num_minutes())
+                .unwrap_or_default(),
+            entry.description.as_deref().unwrap_or_default()
+        );
+        if !dry_run {
+            client
+                .post_time_entries(
+                    endpoints::post_time_entries::PathParams { workspace_id },
+                    entry,
+                )
+                .await
+                .expect("Couldn't create time entry");
+        }
+    }
+}
+
+async fn migrate(
+    client: &Client,
+    source: MigrationSource,
+    file: PathBuf,
+    journal: PathBuf,
+    workspace_id: Option<i64>,
+    timezone: Option<Tz>,
+    day_start: NaiveTime,
+) {
+    let me = client.get_me().await.expect("Couldn't fetch user");
+    let timezone = timezone.unwrap_or_else(|| {
+        me.timezone.parse().unwrap_or_else(|_| {
+            panic!(
+                "Couldn't parse the user's timezone {:?}, pass --timezone",
+                me.timezone
+            )
+        })
+    });
+    let workspace_id = workspace_id
+        .or(me.default_workspace_id)
+        .expect("No workspace given and the user has no default workspace");
+
+    let file = std::fs::File::open(file).expect("Couldn't open export");
+    let entries = match source {
+        MigrationSource::Clockify => migrate::clockify::parse(file, timezone),
+        MigrationSource::Harvest => migrate::harvest::parse(file, timezone, day_start),
+    }
+    .expect("Couldn't parse export");
+
+    let clients = client
+        .get_clients(endpoints::get_clients::RequestBody::default())
+        .await
+        .expect("Couldn't fetch clients");
+    // Archived projects and inactive tasks still block their names
+    let projects = client
+        .get_projects(endpoints::get_projects::RequestBody {
+            include_archived: Some(true),
+            ..Default::default()
+        })
+        .await
+        .expect("Couldn't fetch projects");
+    let tasks = client
+        .get_tasks(endpoints::get_tasks::RequestBody {
+            include_not_active: Some(true),
+            ..Default::default()
+        })
+        .await
+        .expect("Couldn't fetch tasks");
+    let tags = client
+        .get_tags(endpoints::get_tags::RequestBody::default())
+        .await
+        .expect("Couldn't fetch tags");
+
+    let mut migration = migrate::Migration::open(
+        client,
+        workspace_id,
+        journal,
+        migrate::Existing {
+            clients: &clients.0,
+            projects: &projects.0,
+            tasks: &tasks.0,
+            tags: &tags.0,
+        },
+    )
+    .expect("Couldn't open journal");
+    let report = migration.run(&entries).await.expect("Migration failed");
+    println!(
+        "Created {} clients, {} projects, {} tasks, {} tags and {} time entries, skipped {} entries migrated earlier",
+        report.clients,
+        report.projects,
+        report.tasks,
+        report.tags,
+        report.time_entries,
+        report.skipped
+    );
+}
@@ -0,0 +1,658 @@
+use crate::api::TogglApi;
+use crate::client;
+use crate::endpoints::{post_clients, post_projects, post_tags, post_tasks, post_time_entries};
+use crate::models;
+use chrono::{DateTime, Duration, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs::{File, OpenOptions};
+use std::future::Future;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use thiserror::Error;
+use tracing::Instrument;
+
+/// Written to `created_with` for every migrated entry
+pub const CREATED_WITH: &str = "togglrust migration";
+
+/// Give up on a request after it was rate limited or failed this many times in a row
+const MAX_ATTEMPTS: u32 = 8;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("line {line}: {message}")]
+    Parse { line: u64, message: String },
+
+    #[error("HTTP error: {0}")]
+    Client(#[from] client::Error),
+}
+
+/// A time entry read from another time tracker's export
+#[derive(Debug, Clone)]
+pub struct SourceEntry {
+    /// Line in the export file, used to remember which entries were migrated
+    pub line: u64,
+    pub client: Option<String>,
+    pub project: Option<String>,
+    pub task: Option<String>,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub billable: bool,
+    pub start: DateTime<Utc>,
+    pub duration: Duration,
+}
+
+fn non_empty(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+fn to_utc(line: u64, naive: NaiveDateTime, timezone: Tz) -> Result<DateTime<Utc>, Error> {
+    timezone
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|datetime| datetime.with_timezone(&Utc))
+        .ok_or_else(|| Error::Parse {
+            line,
+            message: format!("{} doesn't exist in {}", naive, timezone),
+        })
+}
+
+fn parse_error(line: u64, message: String) -> Error {
+    Error::Parse { line, message }
+}
+
+/// Toggl reads a negative duration as a running entry, and the stop time has to be representable
+fn check_duration(line: u64, start: DateTime<Utc>, duration: Duration) -> Result<Duration, Error> {
+    if duration < Duration::zero() {
+        return Err(parse_error(line, format!("negative duration {}", duration)));
+    }
+    start
+        .checked_add_signed(duration)
+        .map(|_| duration)
+        .ok_or_else(|| parse_error(line, format!("duration {} is out of range", duration)))
+}
+
+/// Clockify's detailed report CSV export
+pub mod clockify {
+    use super::{check_duration, non_empty, parse_error, to_utc, Error, SourceEntry};
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+    use chrono_tz::Tz;
+    use std::io;
+
+    const DATE_FORMATS: &[&str] = &["%m/%d/%Y", "%d/%m/%Y", "%Y-%m-%d", "%d.%m.%Y"];
+    const TIME_FORMATS: &[&str] = &["%I:%M:%S %p", "%I:%M %p", "%H:%M:%S", "%H:%M"];
+
+    fn parse_datetime(
+        line: u64,
+        date: &str,
+        time: &str,
+        date_format: &str,
+    ) -> Result<NaiveDateTime, Error> {
+        let date = NaiveDate::parse_from_str(date.trim(), date_format)
+            .map_err(|_| parse_error(line, format!("invalid date {:?}", date)))?;
+        let time = TIME_FORMATS
+            .iter()
+            .find_map(|format| NaiveTime::parse_from_str(time.trim(), format).ok())
+            .ok_or_else(|| parse_error(line, format!("invalid time {:?}", time)))?;
+        Ok(date.and_time(time))
+    }
+
+    /// Reads a detailed report. Times are in the timezone configured in the Clockify profile of
+    /// whoever exported the report.
+    pub fn parse<R: io::Read>(reader: R, timezone: Tz) -> Result<Vec<SourceEntry>, Error> {
+        let mut reader = csv::Reader::from_reader(reader);
+        let headers = reader.headers()?.clone();
+        let column = |name: &str| headers.iter().position(|header| header.trim() == name);
+        let required = |name: &str| {
+            column(name).ok_or_else(|| parse_error(1, format!("missing column {:?}", name)))
+        };
+        let (start_date, start_time) = (required("Start Date")?, required("Start Time")?);
+        let (end_date, end_time) = (required("End Date")?, required("End Time")?);
+        let (project, client, task) = (column("Project"), column("Client"), column("Task"));
+        let (description, tags, billable) =
+            (column("Description"), column("Tags"), column("Billable"));
+
+        let mut records = Vec::new();
+        for record in reader.records() {
+            records.push(record?);
+        }
+        // The date format depends on the exporter's settings, so pick the first one that fits
+        // every row
+        let date_format = DATE_FORMATS
+            .iter()
+            .find(|format| {
+                records.iter().all(|record| {
+                    NaiveDate::parse_from_str(record.get(start_date).unwrap_or_default(), format)
+                        .is_ok()
+                })
+            })
+            .copied()
+            .unwrap_or(DATE_FORMATS[0]);
+
+        let mut entries = Vec::new();
+        for record in records {
+            let line = record.position().map(|pos| pos.line()).unwrap_or_default();
+            let field = |index: Option<usize>| non_empty(index.and_then(|index| record.get(index)));
+            let get = |index: usize| record.get(index).unwrap_or_default();
+            let start = parse_datetime(line, get(start_date), get(start_time), date_format)?;
+            let end = parse_datetime(line, get(end_date), get(end_time), date_format)?;
+            let start = to_utc(line, start, timezone)?;
+            let duration = check_duration(line, start, to_utc(line, end, timezone)? - start)?;
+            entries.push(SourceEntry {
+                line,
+                client: field(client),
+                project: field(project),
+                task: field(task),
+                description: field(description),
+                tags: field(tags)
+                    .map(|tags| {
+                        tags.split(',')
+                            .map(str::trim)
+                            .filter(|tag| !tag.is_empty())
+                            .map(str::to_string)
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                billable: field(billable).is_some_and(|value| value.eq_ignore_ascii_case("yes")),
+                start,
+                duration,
+            });
+        }
+        Ok(entries)
+    }
+}
+
+/// Harvest's time export CSV
+pub mod harvest {
+    use super::{check_duration, non_empty, parse_error, to_utc, Error, SourceEntry};
+    use chrono::{Duration, NaiveDate, NaiveTime};
+    use chrono_tz::Tz;
+    use std::collections::HashMap;
+    use std::io;
+
+    /// Reads a time export. Harvest only exports a date and a number of hours per entry, so the
+    /// entries of each day are laid out back to back starting at `day_start`.
+    pub fn parse<R: io::Read>(
+        reader: R,
+        timezone: Tz,
+        day_start: NaiveTime,
+    ) -> Result<Vec<SourceEntry>, Error> {
+        let mut reader = csv::Reader::from_reader(reader);
+        let headers = reader.headers()?.clone();
+        let column = |name: &str| headers.iter().position(|header| header.trim() == name);
+        let required = |name: &str| {
+            column(name).ok_or_else(|| parse_error(1, format!("missing column {:?}", name)))
+        };
+        let (date, hours) = (required("Date")?, required("Hours")?);
+        let (client, project, task) = (column("Client"), column("Project"), column("Task"));
+        let (notes, billable) = (column("Notes"), column("Billable?"));
+
+        let mut next_start: HashMap<NaiveDate, Duration> = HashMap::new();
+        let mut entries = Vec::new();
+        for record in reader.records() {
+            let record = record?;
+            let line = record.position().map(|pos| pos.line()).unwrap_or_default();
+            let field = |index: Option<usize>| non_empty(index.and_then(|index| record.get(index)));
+            let get = |index: usize| record.get(index).unwrap_or_default().trim();
+
+            let day = NaiveDate::parse_from_str(get(date), "%Y-%m-%d")
+                .map_err(|_| parse_error(line, format!("invalid date {:?}", get(date))))?;
+            let invalid = || parse_error(line, format!("invalid hours {:?}", get(hours)));
+            let hours: f64 = get(hours).parse().map_err(|_| invalid())?;
+            let seconds = (hours * 3600.0).round();
+            // Casting saturates, so anything outside i64 has to be caught first
+            if !seconds.is_finite() || seconds.abs() >= i64::MAX as f64 {
+                return Err(invalid());
+            }
+            let duration = Duration::try_seconds(seconds as i64).ok_or_else(invalid)?;
+            let offset = next_start.entry(day).or_insert_with(Duration::zero);
+            let start = day
+                .and_time(day_start)
+                .checked_add_signed(*offset)
+                .ok_or_else(invalid)?;
+            let start = to_utc(line, start, timezone)?;
+            let duration = check_duration(line, start, duration)?;
+            *offset = offset.checked_add(&duration).ok_or_else(invalid)?;
+
+            entries.push(SourceEntry {
+                line,
+                client: field(client),
+                project: field(project),
+                task: field(task),
+                description: field(notes),
+                tags: Vec::new(),
+                billable: field(billable).is_some_and(|value| value.eq_ignore_ascii_case("yes")),
+                start,
+                duration,
+            });
+        }
+        Ok(entries)
+    }
+}
+
+/// One line of the journal
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum JournalRecord {
+    Client {
+        name: String,
+        id: i64,
+    },
+    Project {
+        client_id: Option<i64>,
+        name: String,
+        id: i64,
+    },
+    Task {
+        project_id: i64,
+        name: String,
+        id: i64,
+    },
+    Tag {
+        name: String,
+        id: i64,
+    },
+    TimeEntry {
+        line: u64,
+        id: i64,
+    },
+}
+
+/// Append-only record of everything a migration created, so an interrupted migration can pick up
+/// where it stopped
+struct Journal {
+    file: File,
+}
+
+impl Journal {
+    fn open(path: &Path) -> Result<(Self, Vec<JournalRecord>), Error> {
+        let mut records = Vec::new();
+        // Where the last complete record ends
+        let mut good = 0;
+        match File::open(path) {
+            Ok(file) => {
+                let mut reader = BufReader::new(file);
+                let mut line = String::new();
+                while reader.read_line(&mut line)? > 0 {
+                    // A line cut short by a crash is the only one that can fail to parse, or
+                    // lack its newline
+                    if !line.ends_with('\n') {
+                        break;
+                    }
+                    match serde_json::from_str(&line) {
+                        Ok(record) => records.push(record),
+                        Err(_) => break,
+                    }
+                    good += line.len() as u64;
+                    line.clear();
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        // Drops the torn line, so the next record doesn't get glued onto it
+        if file.metadata()?.len() > good {
+            file.set_len(good)?;
+        }
+        Ok((Self { file }, records))
+    }
+
+    fn append(&mut self, record: &JournalRecord) -> Result<(), Error> {
+        let mut line = serde_json::to_string(record)?;
+        line.push('\n');
+        self.file.write_all(line.as_bytes())?;
+        self.file.sync_data()?;
+        Ok(())
+    }
+}
+
+/// What's already in the target workspace, so it isn't created again
+#[derive(Default)]
+pub struct Existing<'a> {
+    pub clients: &'a [models::Client],
+    pub projects: &'a [models::Project],
+    pub tasks: &'a [models::Task],
+    pub tags: &'a [models::Tag],
+}
+
+#[derive(Debug, Default)]
+pub struct MigrationReport {
+    pub clients: usize,
+    pub projects: usize,
+    pub tasks: usize,
+    pub tags: usize,
+    pub time_entries: usize,
+
+    /// Entries skipped because the journal says they were migrated before
+    pub skipped: usize,
+}
+
+fn key(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+/// Creates source entries in a workspace along with any clients, projects, tasks and tags they
+/// reference that don't exist yet.
+///
+/// Every created object is written to the journal before moving on. Running a migration again
+/// with the same journal and export file skips everything that was already done. Requests that
+/// are rate limited are retried with exponential backoff. Server errors aren't, every request
+/// creates something and Toggl may have created it before failing.
+pub struct Migration<'a, A> {
+    api: &'a A,
+    workspace_id: i64,
+    journal: Journal,
+    clients: HashMap<String, i64>,
+    projects: HashMap<(Option<i64>, String), i64>,
+    tasks: HashMap<(i64, String), i64>,
+    tags: HashMap<String, i64>,
+    migrated: HashSet<u64>,
+
+    /// Pause between requests to stay below Toggl's rate limit
+    pub request_interval: std::time::Duration,
+}
+
+impl<'a, A: TogglApi> Migration<'a, A> {
+    pub fn open(
+        api: &'a A,
+        workspace_id: i64,
+        journal_path: impl AsRef<Path>,
+        existing: Existing,
+    ) -> Result<Self, Error> {
+        let (journal, records) = Journal::open(journal_path.as_ref())?;
+        let mut migration = Self {
+            api,
+            workspace_id,
+            journal,
+            clients: existing
+                .clients
+                .iter()
+                .filter(|client| client.wid == workspace_id && client.server_deleted_at.is_none())
+                .map(|client| (key(&client.name), client.id))
+                .collect(),
+            projects: existing
+                .projects
+                .iter()
+                .filter(|project| {
+                    project.workspace_id == workspace_id && project.server_deleted_at.is_none()
+                })
+                .map(|project| {
+                    (
+                        (project.client_id.or(project.cid), key(&project.name)),
+                        project.id,
+                    )
+                })
+                .collect(),
+            tasks: existing
+                .tasks
+                .iter()
+                .filter(|task| {
+                    task.workspace_id == workspace_id && task.server_deleted_at.is_none()
+                })
+                .map(|task| ((task.project_id, key(&task.name)), task.id))
+                .collect(),
+            tags: existing
+                .tags
+                .iter()
+                .filter(|tag| tag.workspace_id == workspace_id && tag.deleted_at.is_none())
+                .map(|tag| (key(&tag.name), tag.id))
+                .collect(),
+            migrated: HashSet::new(),
+            request_interval: std::time::Duration::from_secs(1),
+        };
+
+        for record in records {
+            match record {
+                JournalRecord::Client { name, id } => {
+                    migration.clients.insert(key(&name), id);
+                }
+                JournalRecord::Project {
+                    client_id,
+                    name,
+                    id,
+                } => {
+                    migration.projects.insert((client_id, key(&name)), id);
+                }
+                JournalRecord::Task {
+                    project_id,
+                    name,
+                    id,
+                } => {
+                    migration.tasks.insert((project_id, key(&name)), id);
+                }
+                JournalRecord::Tag { name, id } => {
+                    migration.tags.insert(key(&name), id);
+                }
+                JournalRecord::TimeEntry { line, .. } => {
+                    migration.migrated.insert(line);
+                }
+            }
+        }
+        Ok(migration)
+    }
+
+    async fn send<T, F, Fut>(&self, mut request: F) -> Result<T, Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, client::Error>>,
+    {
+        let mut backoff = std::time::Duration::from_secs(1);
+        let mut attempt = 1;
+        loop {
+            tokio::time::sleep(self.request_interval).await;
+            let span = tracing::info_span!("toggl_attempt", retries = attempt - 1);
+            match request().instrument(span).await {
+                Ok(response) => return Ok(response),
+                Err(err)
+                    if attempt < MAX_ATTEMPTS
+                        && err.status() == Some(reqwest::StatusCode::TOO_MANY_REQUESTS) =>
+                {
+                    tracing::warn!(attempt, error = %err, backoff = ?backoff, "retrying");
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(std::time::Duration::from_secs(60));
+                    attempt += 1;
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+
+    async fn client_id(&mut self, name: &str, report: &mut MigrationReport) -> Result<i64, Error> {
+        if let Some(id) = self.clients.get(&key(name)) {
+            return Ok(*id);
+        }
+        let workspace_id = self.workspace_id;
+        let created = self
+            .send(|| {
+                self.api.post_clients(
+                    post_clients::PathParams { workspace_id },
+                    post_clients::RequestBody {
+                        name: name.to_string(),
+                        notes: None,
+                    },
+                )
+            })
+            .await?
+            .0;
+        self.journal.append(&JournalRecord::Client {
+            name: name.to_string(),
+            id: created.id,
+        })?;
+        self.clients.insert(key(name), created.id);
+        report.clients += 1;
+        Ok(created.id)
+    }
+
+    async fn project_id(
+        &mut self,
+        client_id: Option<i64>,
+        name: &str,
+        billable: bool,
+        report: &mut MigrationReport,
+    ) -> Result<i64, Error> {
+        if let Some(id) = self.projects.get(&(client_id, key(name))) {
+            return Ok(*id);
+        }
+        let workspace_id = self.workspace_id;
+        let created = self
+            .send(|| {
+                self.api.post_projects(
+                    post_projects::PathParams { workspace_id },
+                    post_projects::RequestBody {
+                        name: name.to_string(),
+                        active: Some(true),
+                        billable: billable.then_some(true),
+                        client_id,
+                        ..Default::default()
+                    },
+                )
+            })
+            .await?
+            .0;
+        self.journal.append(&JournalRecord::Project {
+            client_id,
+            name: name.to_string(),
+            id: created.id,
+        })?;
+        self.projects.insert((client_id, key(name)), created.id);
+        report.projects += 1;
+        Ok(created.id)
+    }
+
+    async fn task_id(
+        &mut self,
+        project_id: i64,
+        name: &str,
+        report: &mut MigrationReport,
+    ) -> Result<i64, Error> {
+        if let Some(id) = self.tasks.get(&(project_id, key(name))) {
+            return Ok(*id);
+        }
+        let workspace_id = self.workspace_id;
+        let created = self
+            .send(|| {
+                self.api.post_tasks(
+                    post_tasks::PathParams {
+                        workspace_id,
+                        project_id,
+                    },
+                    post_tasks::RequestBody {
+                        name: name.to_string(),
+                        active: Some(true),
+                        ..Default::default()
+                    },
+                )
+            })
+            .await?
+            .0;
+        self.journal.append(&JournalRecord::Task {
+            project_id,
+            name: name.to_string(),
+            id: created.id,
+        })?;
+        self.tasks.insert((project_id, key(name)), created.id);
+        report.tasks += 1;
+        Ok(created.id)
+    }
+
+    async fn tag_id(&mut self, name: &str, report: &mut MigrationReport) -> Result<i64, Error> {
+        if let Some(id) = self.tags.get(&key(name)) {
+            return Ok(*id);
+        }
+        let workspace_id = self.workspace_id;
+        let created = self
+            .send(|| {
+                self.api.post_tags(
+                    post_tags::PathParams { workspace_id },
+                    post_tags::RequestBody {
+                        name: name.to_string(),
+                    },
+                )
+            })
+            .await?
+            .0;
+        self.journal.append(&JournalRecord::Tag {
+            name: name.to_string(),
+            id: created.id,
+        })?;
+        self.tags.insert(key(name), created.id);
+        report.tags += 1;
+        Ok(created.id)
+    }
+
+    pub async fn run(&mut self, entries: &[SourceEntry]) -> Result<MigrationReport, Error> {
+        let mut report = MigrationReport::default();
+        for entry in entries {
+            if self.migrated.contains(&entry.line) {
+                report.skipped += 1;
+                continue;
+            }
+
+            let client_id = match &entry.client {
+                Some(name) => Some(self.client_id(name, &mut report).await?),
+                None => None,
+            };
+            let project_id = match &entry.project {
+                Some(name) => Some(
+                    self.project_id(client_id, name, entry.billable, &mut report)
+                        .await?,
+                ),
+                None => None,
+            };
+            let task_id = match (project_id, &entry.task) {
+                (Some(project_id), Some(name)) => {
+                    Some(self.task_id(project_id, name, &mut report).await?)
+                }
+                _ => None,
+            };
+            let mut tag_ids = Vec::new();
+            for tag in &entry.tags {
+                tag_ids.push(self.tag_id(tag, &mut report).await?);
+            }
+
+            let mut body =
+                post_time_entries::RequestBody::new(self.workspace_id, entry.start, CREATED_WITH);
+            body.billable = Some(entry.billable);
+            body.description = entry.description.clone();
+            body.duration = Some(entry.duration);
+            body.stop = entry.start.checked_add_signed(entry.duration);
+            body.project_id = project_id;
+            body.task_id = task_id;
+            body.tag_ids = (!tag_ids.is_empty()).then_some(tag_ids);
+
+            let workspace_id = self.workspace_id;
+            let created = self
+                .send(|| {
+                    self.api.post_time_entries(
+                        post_time_entries::PathParams { workspace_id },
+                        body.clone(),
+                    )
+                })
+                .await?
+                .0;
+            if let Some(created) = created {
+                self.journal.append(&JournalRecord::TimeEntry {
+                    line: entry.line,
+                    id: created.id,
+                })?;
+            }
+            self.migrated.insert(entry.line);
+            report.time_entries += 1;
+        }
+        Ok(report)
+    }
+}
@@ -1,8 +1,16 @@
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use crate::day_of_week::DayOfWeek;
+use crate::secret::Secret;
 use serde::{Deserialize, Serialize};
-use serde_with::{serde_as, DurationMilliSeconds, DurationSeconds};
+use serde_json::Value;
+use serde_with::{serde_as, DefaultOnNull, DurationMilliSeconds, DurationSeconds};
+use std::collections::HashMap;
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Fields the API sent that the model doesn't know about, kept so that new fields neither break
+/// deserialization nor get lost when a model is sent back
+pub type Extra = HashMap<String, Value>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Client {
     /// IsArchived is true if the client is archived
     pub archived: bool,
@@ -21,9 +29,12 @@ pub struct Client {
 
     /// Workspace ID
     pub wid: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project {
     pub active: bool,
     pub actual_hours: Option<i64>,
@@ -52,9 +63,12 @@ pub struct Project {
     pub template: Option<bool>,
     pub wid: i64,
     pub workspace_id: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RecurringProjectParameters {
     pub custom_period: Option<i64>,
     pub estimated_seconds: i64,
@@ -62,17 +76,23 @@ pub struct RecurringProjectParameters {
     pub parameter_start_date: DateTime<Utc>,
     pub period: RecurringPeriod,
     pub project_start_date: DateTime<Utc>,
+
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 // Who knows if this is right
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RecurringPeriod {
     pub end_date: Option<DateTime<Utc>>,
     pub start_date: DateTime<Utc>,
+
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 #[serde_as]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
     pub active: bool,
     pub at: DateTime<Utc>,
@@ -91,10 +111,13 @@ pub struct Task {
     pub tracked_time: Duration,
     pub user_id: Option<i64>,
     pub workspace_id: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 #[serde_as]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimeEntry {
     /// When was last updated
     pub at: DateTime<Utc>,
@@ -131,9 +154,13 @@ pub struct TimeEntry {
     pub stop: Option<DateTime<Utc>>,
 
     /// Tag IDs, null if tags were not provided or were later deleted
+    #[serde_as(as = "DefaultOnNull")]
+    #[serde(default)]
     pub tag_ids: Vec<i64>,
 
     /// Tag names, null if tags were not provided or were later deleted
+    #[serde_as(as = "DefaultOnNull")]
+    #[serde(default)]
     pub tags: Vec<String>,
 
     /// Task ID. Can be null if task was not provided or project was later deleted
@@ -153,9 +180,12 @@ pub struct TimeEntry {
 
     /// Workspace ID
     pub workspace_id: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tag {
     /// When was created/last modified
     pub at: DateTime<Utc>,
@@ -171,12 +201,17 @@ pub struct Tag {
 
     /// Workspace ID
     pub workspace_id: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Workspace {
     pub admin: bool,
-    pub api_token: String,
+
+    /// Null for users who aren't allowed to see the workspace's token
+    pub api_token: Option<Secret>,
     pub at: DateTime<Utc>,
     pub business_ws: bool,
     pub csv_upload: Option<CsvUpload>,
@@ -201,22 +236,31 @@ pub struct Workspace {
     pub subscription: Option<Subscription>,
     pub suspended_at: Option<DateTime<Utc>>,
     pub te_constraints: Option<TeConstraints>,
+
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CsvUpload {
     pub at: DateTime<Utc>,
     pub log_id: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Feature {
     pub enabled: bool,
     pub feature_id: i64,
     pub name: String,
+
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Organization {
     /// Whether the requester is an admin of the organization
     pub admin: bool,
@@ -247,7 +291,7 @@ pub struct Organization {
     pub owner: bool,
 
     /// Organization's subscription payment methods. Omitted if empty.
-    pub payment_methods: String,
+    pub payment_methods: Option<String>,
 
     /// Organization plan ID
     pub pricing_plan_id: i64,
@@ -262,9 +306,12 @@ pub struct Organization {
 
     /// Number of organization users
     pub user_count: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrialInfo {
     /// What was the previous plan before the trial
     pub last_pricing_plan_id: Option<i64>,
@@ -280,9 +327,13 @@ pub struct TrialInfo {
 
     /// When the trial ends
     pub trial_end_date: Option<DateTime<Utc>>,
+
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrackReminder {
     /// Reminder creation time
     pub created_at: DateTime<Utc>,
@@ -291,6 +342,8 @@ pub struct TrackReminder {
     pub frequency: i64,
 
     /// Groups IDs to send the reminder to
+    #[serde_as(as = "DefaultOnNull")]
+    #[serde(default)]
     pub group_ids: Vec<i64>,
 
     /// Reminder ID
@@ -300,13 +353,19 @@ pub struct TrackReminder {
     pub threshold: i64,
 
     /// User IDs to send the reminder to
+    #[serde_as(as = "DefaultOnNull")]
+    #[serde(default)]
     pub user_ids: Vec<i64>,
 
     /// Workspace ID
     pub workspace_id: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
     /// Whether the requester is an admin of the organization
     pub admin: bool,
@@ -321,6 +380,8 @@ pub struct User {
     pub email: String,
 
     /// User's groups
+    #[serde_as(as = "DefaultOnNull")]
+    #[serde(default)]
     pub groups: Vec<Group>,
 
     /// User ID
@@ -329,8 +390,8 @@ pub struct User {
     /// Whether the user is inactive
     pub inactive: bool,
 
-    /// User's invitation code
-    pub invitation_code: String,
+    /// Code from the user's invitation, none once they have joined
+    pub invitation_code: Option<String>,
 
     /// Whether the user has joined the organization
     pub joined: bool,
@@ -345,30 +406,62 @@ pub struct User {
     pub user_id: i64,
 
     /// User's workspaces
-    pub workspaces: Vec<Workspace>,
+    #[serde_as(as = "DefaultOnNull")]
+    #[serde(default)]
+    pub workspaces: Vec<UserWorkspace>,
+
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// A workspace an organization user is in, and their role there
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserWorkspace {
+    /// Whether the user is an admin of the workspace
+    pub admin: bool,
+
+    /// Whether the user is deactivated in the workspace
+    pub inactive: bool,
+
+    /// User's role in the workspace
+    pub role: Option<String>,
+
+    /// Workspace ID
+    pub workspace_id: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CardDetails {
     // Toggl doesn't seem to document this type
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContactDetail {
     // Toggl doesn't seem to document this type
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaymentDetail {
     // Toggl doesn't seem to document this type
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Period {
     // Toggl doesn't seem to document this type
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Subscription {
     pub auto_renew: bool,
     pub card_details: CardDetails,
@@ -386,22 +479,606 @@ pub struct Subscription {
     pub subscription_id: i64,
     pub subscription_period: Period,
     pub workspace_id: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeConstraints {
     pub description_present: bool,
     pub project_present: bool,
     pub tag_present: bool,
     pub task_present: bool,
     pub time_entry_constraints_enabled: bool,
+
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Group {
     pub at: DateTime<Utc>,
     pub group_id: i64,
     pub name: String,
-    pub users: Vec<User>,
+    #[serde_as(as = "DefaultOnNull")]
+    #[serde(default)]
+    pub users: Vec<GroupMember>,
+    #[serde_as(as = "DefaultOnNull")]
+    #[serde(default)]
     pub workspaces: Vec<i64>,
+
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// The short form of a user that groups list their members in
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupMember {
+    pub avatar_url: String,
+    pub joined: bool,
+    pub name: String,
+    pub user_id: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceUser {
+    /// Whether the user is active in the workspace
+    pub active: bool,
+
+    /// Whether the user is an admin of the workspace
+    pub admin: bool,
+
+    /// When was the last update
+    pub at: DateTime<Utc>,
+
+    /// User's email address
+    pub email: String,
+
+    /// IDs of the groups the user is in
+    #[serde_as(as = "DefaultOnNull")]
+    #[serde(default)]
+    pub group_ids: Vec<i64>,
+
+    /// Workspace user ID, not the same as the user ID
+    pub id: i64,
+
+    /// Whether the user has been deactivated
+    pub inactive: bool,
+
+    /// Hourly cost of the user's time, premium feature
+    pub labour_cost: Option<f32>,
+
+    /// User's name
+    pub name: String,
+
+    /// Hourly rate billed for the user's time, premium feature
+    pub rate: Option<f32>,
+
+    /// When the rate was last changed
+    pub rate_last_updated: Option<DateTime<Utc>>,
+
+    /// User's role in the workspace
+    pub role: Option<String>,
+
+    /// User's timezone
+    pub timezone: Option<String>,
+
+    /// User ID
+    pub uid: i64,
+
+    /// Workspace ID
+    pub wid: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// A user's membership of a project, which gives them access to private projects and can
+/// override the project's rate for their time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectUser {
+    /// When was the last update
+    pub at: DateTime<Utc>,
+
+    /// Group ID, set when the membership comes from a group
+    pub group_id: Option<i64>,
+
+    /// Project user ID
+    pub id: i64,
+
+    /// Hourly cost of the user's time on the project, premium feature
+    pub labor_cost: Option<f32>,
+
+    /// Whether the user can manage the project
+    pub manager: bool,
+
+    /// Project ID
+    pub project_id: i64,
+
+    /// Hourly rate billed for the user's time on the project, premium feature
+    pub rate: Option<f32>,
+
+    /// When the rate was last changed
+    pub rate_last_updated: Option<DateTime<Utc>>,
+
+    /// User ID
+    pub user_id: i64,
+
+    /// Workspace ID
+    pub workspace_id: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// An invitation sent to an email address to join an organization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Invitation {
+    pub email: String,
+    pub invitation_id: i64,
+    pub invite_url: String,
+    pub organization_id: i64,
+    pub recipient_id: Option<i64>,
+    pub sender_id: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// A recent time entry of someone in the workspace, as shown on the dashboard
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Activity {
+    /// Time entry description
+    pub description: Option<String>,
+
+    /// Time entry duration, -1 * (Unix start time) while it's running
+    #[serde_as(as = "DurationSeconds<i64>")]
+    pub duration: Duration,
+
+    /// Time entry ID
+    pub id: i64,
+
+    /// Project ID, if the entry has one
+    pub project_id: Option<i64>,
+
+    /// When the entry started
+    pub start: DateTime<Utc>,
+
+    /// When the entry stopped, null while it's running
+    pub stop: Option<DateTime<Utc>>,
+
+    /// User ID
+    pub user_id: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+impl Activity {
+    pub fn is_running(&self) -> bool {
+        self.stop.is_none() || self.duration < Duration::zero()
+    }
+}
+
+/// Time someone tracked recently, as ranked on the dashboard
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MostActive {
+    /// Total duration tracked
+    #[serde_as(as = "DurationSeconds<i64>")]
+    pub duration: Duration,
+
+    /// User ID
+    pub user_id: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Filters of a Reports API v3 report, used both for ad-hoc reports and the parameters stored
+/// with saved reports. Only set fields are sent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReportFilters {
+    /// First day of the report, inclusive
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<NaiveDate>,
+
+    /// Last day of the report, inclusive
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<NaiveDate>,
+
+    /// Only billable or only non-billable entries, premium feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billable: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_ids: Option<Vec<i64>>,
+
+    /// Only entries whose description contains this
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_ids: Option<Vec<i64>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_ids: Option<Vec<i64>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_ids: Option<Vec<i64>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_ids: Option<Vec<i64>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_ids: Option<Vec<i64>>,
+
+    /// Rounding mode, see [`crate::billing::Rounding::to_toggl`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rounding: Option<i64>,
+
+    /// Minutes durations are rounded to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rounding_minutes: Option<i64>,
+
+    /// Field to sort by, e.g. `date` or `duration`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<String>,
+
+    /// `ASC` or `DESC`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_dir: Option<String>,
+
+    /// Filters this struct doesn't have a field for, sent back unchanged
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// The kinds of reports the Reports API can export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportKind {
+    /// One row per group of similar time entries
+    Detailed,
+
+    /// Totals per project, client or user
+    Summary,
+
+    /// Totals per day of the week
+    Weekly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Csv,
+    Pdf,
+}
+
+/// A report configuration saved in the web UI
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedReport {
+    /// Saved report ID
+    pub id: i64,
+
+    /// Report name
+    pub name: String,
+
+    /// Filters of the report. Toggl stores them as a JSON string, which is parsed too.
+    #[serde(deserialize_with = "filters_or_json_string")]
+    pub params: ReportFilters,
+
+    /// Whether anyone with the token can see the report
+    pub public: bool,
+
+    /// Kind of report, e.g. `detailed`, `summary` or `weekly`
+    pub report_type: Option<String>,
+
+    /// Token to fetch or run the report by, set once it's shared
+    pub token: Option<String>,
+
+    /// Workspace ID
+    pub workspace_id: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+fn filters_or_json_string<'de, D>(deserializer: D) -> Result<ReportFilters, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::String(json) => serde_json::from_str(&json).map_err(serde::de::Error::custom),
+        Value::Null => Ok(ReportFilters::default()),
+        value => serde_json::from_value(value).map_err(serde::de::Error::custom),
+    }
+}
+
+/// One line of a detailed report: entries grouped by user, project, task, description and tags
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DetailedReportRow {
+    pub billable: bool,
+
+    /// Billed amount in cents, premium feature
+    pub billable_amount_in_cents: Option<i64>,
+    pub currency: Option<String>,
+    pub description: Option<String>,
+
+    /// Hourly rate in cents, premium feature
+    pub hourly_rate_in_cents: Option<i64>,
+    pub project_id: Option<i64>,
+
+    /// Position of the row in the whole report, used to request the next page
+    pub row_number: i64,
+    #[serde_as(as = "DefaultOnNull")]
+    #[serde(default)]
+    pub tag_ids: Vec<i64>,
+    pub task_id: Option<i64>,
+    #[serde_as(as = "DefaultOnNull")]
+    #[serde(default)]
+    pub time_entries: Vec<ReportTimeEntry>,
+    pub user_id: i64,
+    pub username: String,
+
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportTimeEntry {
+    /// When was last updated
+    pub at: DateTime<Utc>,
+
+    /// Time Entry ID
+    pub id: i64,
+
+    /// Tracked duration
+    #[serde_as(as = "DurationSeconds<i64>")]
+    pub seconds: Duration,
+    pub start: DateTime<Utc>,
+    pub stop: Option<DateTime<Utc>>,
+
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// The requesting user's preferences
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Preferences {
+    /// First day of the week in the user's reports and calendar
+    #[serde(rename = "BeginningOfWeek")]
+    pub beginning_of_week: Option<DayOfWeek>,
+
+    /// Features the user opted in to before their release
+    #[serde_as(as = "DefaultOnNull")]
+    #[serde(default)]
+    pub alpha_features: Vec<AlphaFeature>,
+
+    /// Date format, e.g. `YYYY-MM-DD`
+    pub date_format: String,
+
+    /// Duration format: `improved`, `classic` or `decimal`
+    pub duration_format: String,
+
+    /// User's timezone as a PostgreSQL timezone name
+    pub pg_time_zone_name: Option<String>,
+
+    /// Whether the desktop apps record a timeline of the applications used
+    pub record_timeline: bool,
+
+    /// Whether the user receives product emails
+    pub send_product_emails: bool,
+
+    /// Whether the user receives notifications about running timers
+    pub send_timer_notifications: bool,
+
+    /// Whether the user receives the weekly report email
+    pub send_weekly_report: bool,
+
+    /// Time of day format, e.g. `H:mm`
+    pub timeofday_format: String,
+
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlphaFeature {
+    pub code: String,
+    pub enabled: bool,
+
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Settings of a workspace that aren't part of [`Workspace`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspacePreferences {
+    /// Whether the signup link is hidden from the sidebar
+    pub hide_sidebar_signup: bool,
+
+    /// Workspace's timezone as a PostgreSQL timezone name
+    pub pg_time_zone_name: Option<String>,
+
+    /// Time entries on or before this date can't be changed by non-admins
+    pub report_locked_at: Option<String>,
+
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookSubscription {
+    /// When the subscription was created
+    pub created_at: DateTime<Utc>,
+
+    /// When the subscription was deleted
+    pub deleted_at: Option<DateTime<Utc>>,
+
+    /// Description of the subscription
+    pub description: String,
+
+    /// Whether the subscription receives events
+    pub enabled: bool,
+
+    /// Which events are sent to the subscription
+    #[serde_as(as = "DefaultOnNull")]
+    #[serde(default)]
+    pub event_filters: Vec<EventFilter>,
+
+    /// Whether there are events waiting to be delivered
+    pub has_pending_events: bool,
+
+    /// Secret used to sign the events
+    pub secret: Secret,
+
+    /// Subscription ID
+    pub subscription_id: i64,
+
+    /// When the subscription was last updated
+    pub updated_at: Option<DateTime<Utc>>,
+
+    /// URL the events are posted to
+    pub url_callback: String,
+
+    /// Creator ID
+    pub user_id: i64,
+
+    /// When the callback URL was validated, null if it hasn't been
+    pub validated_at: Option<DateTime<Utc>>,
+
+    /// Workspace ID
+    pub workspace_id: i64,
+
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventFilter {
+    /// Kind of action, e.g. "created", "updated", "deleted" or "*" for all
+    pub action: String,
+
+    /// Kind of entity, e.g. "time_entry", "project" or "*" for all
+    pub entity: String,
+
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// Lists the fields the API sent that a type doesn't know about, to notice when the API changes
+pub trait UnknownFields {
+    /// Appends the path of every unknown field at or below `path`
+    fn unknown_fields(&self, path: &str, found: &mut Vec<String>);
+}
+
+pub(crate) fn field_path(path: &str, field: &str) -> String {
+    match path {
+        "" => field.to_string(),
+        _ => format!("{}.{}", path, field),
+    }
+}
+
+impl<T: UnknownFields> UnknownFields for Option<T> {
+    fn unknown_fields(&self, path: &str, found: &mut Vec<String>) {
+        if let Some(value) = self {
+            value.unknown_fields(path, found);
+        }
+    }
+}
+
+impl<T: UnknownFields> UnknownFields for Vec<T> {
+    fn unknown_fields(&self, path: &str, found: &mut Vec<String>) {
+        for (index, value) in self.iter().enumerate() {
+            value.unknown_fields(&format!("{}[{}]", path, index), found);
+        }
+    }
+}
+
+/// Implements [`UnknownFields`] for a type with an `extra` map, recursing into the listed fields.
+/// `newtype` implements it for a tuple struct by delegating to its only field.
+macro_rules! impl_unknown_fields {
+    (newtype $type:ty) => {
+        impl $crate::models::UnknownFields for $type {
+            fn unknown_fields(&self, path: &str, found: &mut Vec<String>) {
+                $crate::models::UnknownFields::unknown_fields(&self.0, path, found);
+            }
+        }
+    };
+    ($type:ty $(, $field:ident)*) => {
+        impl $crate::models::UnknownFields for $type {
+            fn unknown_fields(&self, path: &str, found: &mut Vec<String>) {
+                found.extend(
+                    self.extra
+                        .keys()
+                        .map(|key| $crate::models::field_path(path, key)),
+                );
+                $(
+                    $crate::models::UnknownFields::unknown_fields(
+                        &self.$field,
+                        &$crate::models::field_path(path, stringify!($field)),
+                        found,
+                    );
+                )*
+            }
+        }
+    };
 }
+pub(crate) use impl_unknown_fields;
+
+impl_unknown_fields!(Client);
+impl_unknown_fields!(Project, current_period, recurring_parameters);
+impl_unknown_fields!(RecurringProjectParameters, period);
+impl_unknown_fields!(RecurringPeriod);
+impl_unknown_fields!(Task);
+impl_unknown_fields!(TimeEntry);
+impl_unknown_fields!(Tag);
+impl_unknown_fields!(Workspace, csv_upload, subscription, te_constraints);
+impl_unknown_fields!(CsvUpload);
+impl_unknown_fields!(Feature);
+impl_unknown_fields!(Organization, trial_info);
+impl_unknown_fields!(TrialInfo);
+impl_unknown_fields!(TrackReminder);
+impl_unknown_fields!(User, groups, workspaces);
+impl_unknown_fields!(UserWorkspace);
+impl_unknown_fields!(CardDetails);
+impl_unknown_fields!(ContactDetail);
+impl_unknown_fields!(PaymentDetail);
+impl_unknown_fields!(Period);
+impl_unknown_fields!(
+    Subscription,
+    card_details,
+    contact_detail,
+    payment_details,
+    subscription_period
+);
+impl_unknown_fields!(TeConstraints);
+impl_unknown_fields!(Group, users);
+impl_unknown_fields!(GroupMember);
+impl_unknown_fields!(WorkspaceUser);
+impl_unknown_fields!(Invitation);
+impl_unknown_fields!(ProjectUser);
+impl_unknown_fields!(Activity);
+impl_unknown_fields!(MostActive);
+impl_unknown_fields!(ReportFilters);
+impl_unknown_fields!(SavedReport, params);
+impl_unknown_fields!(DetailedReportRow, time_entries);
+impl_unknown_fields!(ReportTimeEntry);
+impl_unknown_fields!(Preferences, alpha_features);
+impl_unknown_fields!(AlphaFeature);
+impl_unknown_fields!(WorkspacePreferences);
+impl_unknown_fields!(WebhookSubscription, event_filters);
+impl_unknown_fields!(EventFilter);
@@ -0,0 +1,518 @@
+use crate::endpoints::post_time_entries;
+use crate::models::{Project, TimeEntry};
+use chrono::{DateTime, Duration, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use thiserror::Error;
+
+/// Written to `created_with` for every entry read from a plain text file
+pub const CREATED_WITH: &str = "togglrust plaintext import";
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("line {line}: {message}")]
+    Parse { line: usize, message: String },
+
+    #[error("line {line}: unknown project {name:?}")]
+    UnknownProject { line: usize, name: String },
+}
+
+/// Where imported entries go and how their local times are interpreted
+pub struct ImportTarget<'a> {
+    pub workspace_id: i64,
+    pub timezone: Tz,
+
+    /// Projects of the workspace, looked up by name
+    pub projects: &'a [Project],
+}
+
+impl ImportTarget<'_> {
+    fn project_id(&self, line: usize, name: &str) -> Result<i64, Error> {
+        self.projects
+            .iter()
+            .find(|project| {
+                project.workspace_id == self.workspace_id
+                    && project.server_deleted_at.is_none()
+                    && project.name == name
+            })
+            .map(|project| project.id)
+            .ok_or_else(|| Error::UnknownProject {
+                line,
+                name: name.to_string(),
+            })
+    }
+
+    fn to_utc(&self, line: usize, naive: NaiveDateTime) -> Result<DateTime<Utc>, Error> {
+        self.timezone
+            .from_local_datetime(&naive)
+            .earliest()
+            .map(|datetime| datetime.with_timezone(&Utc))
+            .ok_or_else(|| Error::Parse {
+                line,
+                message: format!("{} doesn't exist in {}", naive, self.timezone),
+            })
+    }
+
+    /// Converts a clock's start and stop, rejecting clocks that stop before they start
+    fn clock(
+        &self,
+        (start_line, start): (usize, NaiveDateTime),
+        (stop_line, stop): (usize, NaiveDateTime),
+    ) -> Result<(DateTime<Utc>, DateTime<Utc>), Error> {
+        let start = self.to_utc(start_line, start)?;
+        let stop = self.to_utc(stop_line, stop)?;
+        if stop < start {
+            return Err(Error::Parse {
+                line: stop_line,
+                message: String::from("clock stops before it starts"),
+            });
+        }
+        Ok((start, stop))
+    }
+
+    fn time_entry(
+        &self,
+        start: DateTime<Utc>,
+        stop: DateTime<Utc>,
+        project_id: Option<i64>,
+        description: Option<String>,
+        tags: Vec<String>,
+        billable: bool,
+    ) -> post_time_entries::RequestBody {
+        let mut body = post_time_entries::RequestBody::new(self.workspace_id, start, CREATED_WITH);
+        body.stop = Some(stop);
+        body.duration = Some(stop - start);
+        body.project_id = project_id;
+        body.description = description;
+        body.tags = (!tags.is_empty()).then_some(tags);
+        body.billable = Some(billable);
+        body
+    }
+}
+
+/// When a finished entry stopped. Entries created with only a duration have no stop time.
+fn stop(entry: &TimeEntry) -> Option<DateTime<Utc>> {
+    entry
+        .stop
+        .or_else(|| (entry.duration >= Duration::zero()).then(|| entry.start + entry.duration))
+}
+
+fn project_name(projects: &[Project], entry: &TimeEntry) -> Option<String> {
+    entry
+        .project_id
+        .and_then(|id| projects.iter().find(|project| project.id == id))
+        .map(|project| project.name.clone())
+}
+
+/// Org-mode `CLOCK:` lines, with a heading per project and a sub-heading per description.
+///
+/// Tags become heading tags and the billable flag is kept in a `BILLABLE` property, so entries
+/// are grouped under separate headings when either differs. Entries without a project or
+/// description go under placeholder headings marked with a `NO_PROJECT` or `NO_DESCRIPTION`
+/// property, so projects and descriptions that happen to read like the placeholders survive.
+pub mod org {
+    use super::{project_name, stop, Error, ImportTarget};
+    use crate::endpoints::post_time_entries;
+    use crate::models::{Project, TimeEntry};
+    use chrono::NaiveDateTime;
+    use chrono_tz::Tz;
+    use std::collections::BTreeMap;
+    use std::fmt::Write;
+
+    const NO_PROJECT: &str = "No project";
+    const NO_DESCRIPTION: &str = "(no description)";
+    const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %a %H:%M";
+
+    /// Description, tags and billable flag of a second level heading
+    type Heading = (Option<String>, Vec<String>, bool);
+
+    /// Org tags may only contain letters, numbers, `_` and `@`
+    fn to_org_tag(tag: &str) -> String {
+        tag.chars()
+            .map(|c| {
+                if c.is_alphanumeric() || c == '@' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+
+    /// Headings end at the line break, so multi-line text is joined into one line
+    fn to_heading(text: &str) -> String {
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    pub fn export(entries: &[TimeEntry], projects: &[Project], timezone: Tz) -> String {
+        let mut headings: BTreeMap<Option<String>, BTreeMap<Heading, Vec<&TimeEntry>>> =
+            BTreeMap::new();
+        for entry in entries
+            .iter()
+            .filter(|entry| entry.server_deleted_at.is_none())
+        {
+            let project = project_name(projects, entry).map(|name| to_heading(&name));
+            let description = entry
+                .description
+                .as_deref()
+                .map(to_heading)
+                .filter(|description| !description.is_empty());
+            let tags = entry.tags.iter().map(|tag| to_org_tag(tag)).collect();
+            headings
+                .entry(project)
+                .or_default()
+                .entry((description, tags, entry.billable))
+                .or_default()
+                .push(entry);
+        }
+
+        let mut out = String::new();
+        for (project, groups) in headings {
+            match project {
+                Some(project) => writeln!(out, "* {}", project).unwrap(),
+                None => {
+                    writeln!(out, "* {}", NO_PROJECT).unwrap();
+                    writeln!(out, "  :PROPERTIES:").unwrap();
+                    writeln!(out, "  :NO_PROJECT: t").unwrap();
+                    writeln!(out, "  :END:").unwrap();
+                }
+            }
+            for ((description, tags, billable), mut entries) in groups {
+                write!(
+                    out,
+                    "** {}",
+                    description.as_deref().unwrap_or(NO_DESCRIPTION)
+                )
+                .unwrap();
+                if !tags.is_empty() {
+                    write!(out, " :{}:", tags.join(":")).unwrap();
+                }
+                writeln!(out).unwrap();
+                if description.is_none() || billable {
+                    writeln!(out, "   :PROPERTIES:").unwrap();
+                    if description.is_none() {
+                        writeln!(out, "   :NO_DESCRIPTION: t").unwrap();
+                    }
+                    if billable {
+                        writeln!(out, "   :BILLABLE: t").unwrap();
+                    }
+                    writeln!(out, "   :END:").unwrap();
+                }
+                writeln!(out, "   :LOGBOOK:").unwrap();
+                entries.sort_by_key(|entry| std::cmp::Reverse(entry.start));
+                for entry in entries {
+                    let start = entry.start.with_timezone(&timezone);
+                    match stop(entry) {
+                        Some(stop) => {
+                            let stop = stop.with_timezone(&timezone);
+                            let minutes = (stop - start).num_minutes();
+                            writeln!(
+                                out,
+                                "   CLOCK: [{}]--[{}] => {:2}:{:02}",
+                                start.format(TIMESTAMP_FORMAT),
+                                stop.format(TIMESTAMP_FORMAT),
+                                minutes / 60,
+                                minutes % 60
+                            )
+                            .unwrap();
+                        }
+                        None => {
+                            writeln!(out, "   CLOCK: [{}]", start.format(TIMESTAMP_FORMAT)).unwrap()
+                        }
+                    }
+                }
+                writeln!(out, "   :END:").unwrap();
+            }
+        }
+        out
+    }
+
+    fn parse_timestamp(line: usize, text: &str) -> Result<NaiveDateTime, Error> {
+        let text = text.trim().trim_start_matches('[').trim_end_matches(']');
+        let mut parts = text.split_whitespace();
+        let (Some(date), Some(_), Some(time)) = (parts.next(), parts.next(), parts.next()) else {
+            return Err(Error::Parse {
+                line,
+                message: format!("invalid timestamp {:?}", text),
+            });
+        };
+        NaiveDateTime::parse_from_str(&format!("{} {}", date, time), "%Y-%m-%d %H:%M").map_err(
+            |_| Error::Parse {
+                line,
+                message: format!("invalid timestamp {:?}", text),
+            },
+        )
+    }
+
+    fn is_set(value: &str) -> bool {
+        matches!(value.trim(), "t" | "true" | "yes")
+    }
+
+    /// Splits `Heading text   :tag1:tag2:` into the text and its tags
+    fn split_heading(heading: &str) -> (String, Vec<String>) {
+        let heading = heading.trim();
+        if let Some((text, tags)) = heading.rsplit_once(char::is_whitespace) {
+            if tags.len() > 1 && tags.starts_with(':') && tags.ends_with(':') {
+                let tags = tags
+                    .split(':')
+                    .filter(|tag| !tag.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                return (text.trim().to_string(), tags);
+            }
+        }
+        (heading.to_string(), Vec::new())
+    }
+
+    /// Reads closed clocks. Open clocks are skipped since they can't be created as finished
+    /// entries.
+    pub fn import(
+        text: &str,
+        target: &ImportTarget,
+    ) -> Result<Vec<post_time_entries::RequestBody>, Error> {
+        let mut entries = Vec::new();
+        // Looked up when a clock needs it, a `NO_PROJECT` property can still follow the heading
+        let mut project: Option<(usize, String)> = None;
+        let mut description = None;
+        let mut tags: Vec<String> = Vec::new();
+        let mut billable = false;
+
+        for (index, raw) in text.lines().enumerate() {
+            let line = index + 1;
+            let trimmed = raw.trim();
+            let stars = raw.chars().take_while(|c| *c == '*').count();
+            if stars > 0 && raw[stars..].starts_with(' ') {
+                let (heading, heading_tags) = split_heading(&raw[stars..]);
+                if stars == 1 {
+                    project = Some((line, heading));
+                    description = None;
+                } else {
+                    description = Some(heading);
+                }
+                tags = heading_tags;
+                billable = false;
+            } else if let Some(value) = trimmed.strip_prefix(":BILLABLE:") {
+                billable = is_set(value);
+            } else if let Some(value) = trimmed.strip_prefix(":NO_PROJECT:") {
+                if is_set(value) {
+                    project = None;
+                }
+            } else if let Some(value) = trimmed.strip_prefix(":NO_DESCRIPTION:") {
+                if is_set(value) {
+                    description = None;
+                }
+            } else if let Some(clock) = trimmed.strip_prefix("CLOCK:") {
+                let range = clock.split("=>").next().unwrap_or_default();
+                let Some((start, stop)) = range.split_once("--") else {
+                    continue;
+                };
+                let (start, stop) = target.clock(
+                    (line, parse_timestamp(line, start)?),
+                    (line, parse_timestamp(line, stop)?),
+                )?;
+                let project_id = match &project {
+                    Some((line, name)) => Some(target.project_id(*line, name)?),
+                    None => None,
+                };
+                entries.push(target.time_entry(
+                    start,
+                    stop,
+                    project_id,
+                    description.clone(),
+                    tags.clone(),
+                    billable,
+                ));
+            }
+        }
+        Ok(entries)
+    }
+}
+
+/// The `timeclock` format read by ledger and hledger: an `i` line when a clock starts and an `o`
+/// line when it stops.
+///
+/// The project becomes the account name. Tags and the billable flag are written as hledger tags
+/// in a comment, which ledger ignores.
+pub mod timeclock {
+    use super::{project_name, stop, Error, ImportTarget};
+    use crate::endpoints::post_time_entries;
+    use crate::models::{Project, TimeEntry};
+    use chrono::NaiveDateTime;
+    use chrono_tz::Tz;
+    use std::fmt::Write;
+
+    const TIMESTAMP_FORMAT: &str = "%Y/%m/%d %H:%M:%S";
+
+    /// hledger tag names can't contain whitespace, commas or the `:` that ends them
+    fn to_timeclock_tag(tag: &str) -> String {
+        tag.replace(|c: char| c.is_whitespace() || c == ',' || c == ':', "_")
+    }
+
+    /// Writes finished entries in order of their start. A running entry can only be the last clock,
+    /// so only the latest one is written, after all finished entries.
+    pub fn export(entries: &[TimeEntry], projects: &[Project], timezone: Tz) -> String {
+        let (mut entries, running): (Vec<&TimeEntry>, Vec<&TimeEntry>) = entries
+            .iter()
+            .filter(|entry| entry.server_deleted_at.is_none())
+            .partition(|entry| stop(entry).is_some());
+        entries.sort_by_key(|entry| entry.start);
+        entries.extend(running.into_iter().max_by_key(|entry| entry.start));
+
+        let mut out = String::new();
+        for entry in entries {
+            write!(
+                out,
+                "i {}",
+                entry
+                    .start
+                    .with_timezone(&timezone)
+                    .format(TIMESTAMP_FORMAT)
+            )
+            .unwrap();
+            // Two spaces separate the account from the description
+            let account = project_name(projects, entry).map(|name| name.replace("  ", " "));
+            let description = entry
+                .description
+                .as_deref()
+                .map(|description| description.replace(['\n', ';'], " "));
+            match (account, description) {
+                (Some(account), Some(description)) => {
+                    write!(out, " {}  {}", account, description).unwrap()
+                }
+                (Some(account), None) => write!(out, " {}", account).unwrap(),
+                (None, Some(description)) => write!(out, " (no project)  {}", description).unwrap(),
+                (None, None) => {}
+            }
+            let mut tags: Vec<String> = entry
+                .tags
+                .iter()
+                .map(|tag| format!("{}:", to_timeclock_tag(tag)))
+                .collect();
+            if entry.billable {
+                tags.push(String::from("billable:"));
+            }
+            if !tags.is_empty() {
+                write!(out, "  ; {}", tags.join(", ")).unwrap();
+            }
+            writeln!(out).unwrap();
+            if let Some(stop) = stop(entry) {
+                writeln!(
+                    out,
+                    "o {}",
+                    stop.with_timezone(&timezone).format(TIMESTAMP_FORMAT)
+                )
+                .unwrap();
+            }
+        }
+        out
+    }
+
+    fn parse_timestamp(line: usize, text: &str) -> Result<(NaiveDateTime, &str), Error> {
+        let invalid = || Error::Parse {
+            line,
+            message: format!("invalid timestamp in {:?}", text),
+        };
+        let mut parts = text.splitn(3, ' ');
+        let date = parts.next().ok_or_else(invalid)?;
+        let time = parts.next().ok_or_else(invalid)?;
+        let rest = parts.next().unwrap_or_default();
+        let timestamp =
+            NaiveDateTime::parse_from_str(&format!("{} {}", date, time), TIMESTAMP_FORMAT)
+                .or_else(|_| {
+                    NaiveDateTime::parse_from_str(
+                        &format!("{} {}", date, time),
+                        "%Y-%m-%d %H:%M:%S",
+                    )
+                })
+                .map_err(|_| invalid())?;
+        Ok((timestamp, rest))
+    }
+
+    struct ClockIn {
+        line: usize,
+        start: NaiveDateTime,
+        project_id: Option<i64>,
+        description: Option<String>,
+        tags: Vec<String>,
+        billable: bool,
+    }
+
+    /// Reads `i`/`o` pairs. A trailing `i` without a matching `o` is a running clock and is
+    /// skipped.
+    pub fn import(
+        text: &str,
+        target: &ImportTarget,
+    ) -> Result<Vec<post_time_entries::RequestBody>, Error> {
+        let mut entries = Vec::new();
+        let mut clock_in: Option<ClockIn> = None;
+
+        for (index, raw) in text.lines().enumerate() {
+            let line = index + 1;
+            let trimmed = raw.trim();
+            if let Some(rest) = trimmed.strip_prefix("i ") {
+                if clock_in.is_some() {
+                    return Err(Error::Parse {
+                        line,
+                        message: String::from("clock-in while already clocked in"),
+                    });
+                }
+                let (start, rest) = parse_timestamp(line, rest.trim())?;
+                let (rest, comment) = match rest.split_once(';') {
+                    Some((rest, comment)) => (rest, Some(comment)),
+                    None => (rest, None),
+                };
+                let (account, description) = match rest.trim().split_once("  ") {
+                    Some((account, description)) => (account.trim(), Some(description.trim())),
+                    None => (rest.trim(), None),
+                };
+                let project_id = match account {
+                    "" | "(no project)" => None,
+                    name => Some(target.project_id(line, name)?),
+                };
+                let mut tags = Vec::new();
+                let mut billable = false;
+                for tag in comment
+                    .unwrap_or_default()
+                    .split(',')
+                    .filter_map(|tag| tag.trim().split_once(':'))
+                    .map(|(name, _)| name.trim())
+                {
+                    match tag {
+                        "billable" => billable = true,
+                        tag => tags.push(tag.to_string()),
+                    }
+                }
+                clock_in = Some(ClockIn {
+                    line,
+                    start,
+                    project_id,
+                    description: description
+                        .filter(|description| !description.is_empty())
+                        .map(str::to_string),
+                    tags,
+                    billable,
+                });
+            } else if let Some(rest) = trimmed
+                .strip_prefix("o ")
+                .or_else(|| trimmed.strip_prefix("O "))
+            {
+                let Some(clock_in) = clock_in.take() else {
+                    return Err(Error::Parse {
+                        line,
+                        message: String::from("clock-out without clock-in"),
+                    });
+                };
+                let (stop, _) = parse_timestamp(line, rest.trim())?;
+                let (start, stop) = target.clock((clock_in.line, clock_in.start), (line, stop))?;
+                entries.push(target.time_entry(
+                    start,
+                    stop,
+                    clock_in.project_id,
+                    clock_in.description,
+                    clock_in.tags,
+                    clock_in.billable,
+                ));
+            }
+        }
+        Ok(entries)
+    }
+}
@@ -0,0 +1,138 @@
+use crate::models::{TimeEntry, TrackReminder, WorkspaceUser};
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::collections::BTreeMap;
+
+/// Someone a track reminder would be sent to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reminded {
+    pub reminder_id: i64,
+    pub user_id: i64,
+
+    /// Groups of the reminder the user is in, empty if they're only reminded because the
+    /// reminder names them or targets the whole workspace
+    pub group_ids: Vec<i64>,
+
+    /// Time the user tracked in the reminder's period
+    pub tracked: Duration,
+}
+
+/// Predicts who the reminders would be sent to at the end of `today`, from cached entries.
+///
+/// A reminder looks at the last `frequency` days up to and including `today` in `timezone`, and
+/// reminds each of its users who tracked fewer than `threshold` hours in them. Its users are the
+/// active workspace members it names or that are in its groups, or every active member of the
+/// workspace when it names neither users nor groups. Running entries count up to `now`.
+///
+/// `entries` should cover the period for everyone in the workspace and `users` should hold the
+/// workspace's users, which is where group membership comes from.
+pub fn due_reminders(
+    reminders: &[TrackReminder],
+    entries: &[TimeEntry],
+    users: &[WorkspaceUser],
+    today: NaiveDate,
+    timezone: Tz,
+    now: DateTime<Utc>,
+) -> Vec<Reminded> {
+    let mut reminded = Vec::new();
+    for reminder in reminders {
+        let Some((start, end)) = period(reminder.frequency, today, timezone) else {
+            continue;
+        };
+        let threshold = Duration::hours(reminder.threshold);
+        for (user_id, group_ids) in recipients(reminder, users) {
+            let tracked = entries
+                .iter()
+                .filter(|entry| {
+                    entry.user_id == user_id
+                        && entry.workspace_id == reminder.workspace_id
+                        && entry.server_deleted_at.is_none()
+                })
+                .map(|entry| overlap(entry, start, end, now))
+                .fold(Duration::zero(), |total, tracked| total + tracked);
+            if tracked < threshold {
+                reminded.push(Reminded {
+                    reminder_id: reminder.reminder_id,
+                    user_id,
+                    group_ids,
+                    tracked,
+                });
+            }
+        }
+    }
+    reminded
+}
+
+/// Start and end of the `frequency` days ending with `today`, `None` for frequencies Toggl
+/// doesn't support
+fn period(
+    frequency: i64,
+    today: NaiveDate,
+    timezone: Tz,
+) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    if !matches!(frequency, 1 | 7) {
+        return None;
+    }
+    let midnight = |date: NaiveDate| {
+        let local = date.and_hms_opt(0, 0, 0)?;
+        // Midnight can fall into a DST gap, the earliest valid time after it is close enough
+        let local = timezone
+            .from_local_datetime(&local)
+            .earliest()
+            .or_else(|| {
+                timezone
+                    .from_local_datetime(&(local + Duration::hours(1)))
+                    .earliest()
+            })?;
+        Some(local.with_timezone(&Utc))
+    };
+    let start = midnight(today - Duration::days(frequency - 1))?;
+    let end = midnight(today + Duration::days(1))?;
+    Some((start, end))
+}
+
+/// Users a reminder applies to, with the reminder's groups each of them is in
+fn recipients(reminder: &TrackReminder, users: &[WorkspaceUser]) -> BTreeMap<i64, Vec<i64>> {
+    let members = users
+        .iter()
+        .filter(|user| user.wid == reminder.workspace_id && !user.inactive);
+    let mut recipients: BTreeMap<i64, Vec<i64>> = BTreeMap::new();
+    if reminder.user_ids.is_empty() && reminder.group_ids.is_empty() {
+        for user in members {
+            recipients.entry(user.uid).or_default();
+        }
+        return recipients;
+    }
+
+    for user in members {
+        if reminder.user_ids.contains(&user.uid) {
+            recipients.entry(user.uid).or_default();
+        }
+        let groups: Vec<i64> = user
+            .group_ids
+            .iter()
+            .filter(|group_id| reminder.group_ids.contains(group_id))
+            .copied()
+            .collect();
+        if !groups.is_empty() {
+            recipients.entry(user.uid).or_default().extend(groups);
+        }
+    }
+    recipients
+}
+
+/// How much of the entry falls between `start` and `end`
+fn overlap(
+    entry: &TimeEntry,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Duration {
+    let stop = match entry.stop {
+        Some(stop) => stop,
+        None if entry.duration < Duration::zero() => now,
+        None => entry.start + entry.duration,
+    };
+    let overlap = stop.min(end) - entry.start.max(start);
+    overlap.max(Duration::zero())
+}
@@ -0,0 +1,658 @@
+use crate::client::DEFAULT_BASE_URL;
+use serde_json::{Map, Value};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+use thiserror::Error;
+
+/// The crate's own sources, the single source of truth for what the client sends and expects
+const ENDPOINTS_SOURCE: &str = include_str!("endpoints.rs");
+const MODELS_SOURCE: &str = include_str!("models.rs");
+
+const METHODS: &[&str] = &["get", "post", "put", "patch", "delete"];
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("not a Swagger 2 or OpenAPI 3 document")]
+    UnknownFormat,
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("couldn't parse the crate's sources: {0}")]
+    Source(#[from] syn::Error),
+}
+
+/// JSON Schema types, the level at which fields are compared
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Array,
+    Boolean,
+    Integer,
+    Number,
+    Object,
+    String,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Kind::Array => "array",
+            Kind::Boolean => "boolean",
+            Kind::Integer => "integer",
+            Kind::Number => "number",
+            Kind::Object => "object",
+            Kind::String => "string",
+        };
+        f.write_str(name)
+    }
+}
+
+/// One way the crate disagrees with the spec
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Difference {
+    /// The spec has an operation the crate has no endpoint module for
+    MissingEndpoint { method: String, path: String },
+
+    /// The crate has an endpoint module the spec doesn't know, under the spec's base path
+    UnknownEndpoint { method: String, path: String },
+
+    /// The spec's response has a field the model doesn't
+    MissingField { model: String, field: String },
+
+    /// The model has a field the spec's response doesn't
+    UnknownField { model: String, field: String },
+
+    TypeMismatch {
+        location: String,
+        spec: Kind,
+        found: Kind,
+    },
+
+    /// The spec allows null where the crate would fail to deserialize it. The other way round is
+    /// harmless and not reported.
+    NotNullable { location: String },
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Difference::MissingEndpoint { method, path } => {
+                write!(f, "missing endpoint: {} {}", method, path)
+            }
+            Difference::UnknownEndpoint { method, path } => {
+                write!(f, "endpoint not in spec: {} {}", method, path)
+            }
+            Difference::MissingField { model, field } => {
+                write!(f, "missing field: {}.{}", model, field)
+            }
+            Difference::UnknownField { model, field } => {
+                write!(f, "field not in spec: {}.{}", model, field)
+            }
+            Difference::TypeMismatch {
+                location,
+                spec,
+                found,
+            } => write!(
+                f,
+                "type mismatch: {} is {} in the spec but {} in the crate",
+                location, spec, found
+            ),
+            Difference::NotNullable { location } => {
+                write!(f, "not nullable: {} can be null in the spec", location)
+            }
+        }
+    }
+}
+
+/// A field's type as written in the crate, reduced to what shows up in JSON
+#[derive(Debug, Clone)]
+enum Type {
+    Scalar(Kind),
+    Option(Box<Type>),
+    Vec(Box<Type>),
+
+    /// A struct, by its name in [`Source::models`]
+    Model(String),
+
+    /// Free-form JSON, never compared
+    Any,
+}
+
+impl Type {
+    fn kind(&self) -> Option<Kind> {
+        match self {
+            Type::Scalar(kind) => Some(*kind),
+            Type::Option(inner) => inner.kind(),
+            Type::Vec(_) => Some(Kind::Array),
+            Type::Model(_) => Some(Kind::Object),
+            Type::Any => None,
+        }
+    }
+
+    fn is_option(&self) -> bool {
+        matches!(self, Type::Option(_))
+    }
+
+    fn unwrap_option(&self) -> &Type {
+        match self {
+            Type::Option(inner) => inner.unwrap_option(),
+            other => other,
+        }
+    }
+
+    /// Swaps the innermost scalar, for `serde_as` adapters like `TimestampSeconds`
+    fn with_scalar(self, kind: Kind) -> Type {
+        match self {
+            Type::Scalar(_) => Type::Scalar(kind),
+            Type::Option(inner) => Type::Option(Box::new(inner.with_scalar(kind))),
+            Type::Vec(inner) => Type::Vec(Box::new(inner.with_scalar(kind))),
+            other => other,
+        }
+    }
+}
+
+struct Field {
+    name: String,
+    ty: Type,
+
+    /// `DefaultOnNull` turns null into the default value
+    null_default: bool,
+}
+
+struct Endpoint {
+    module: String,
+    method: String,
+    path: String,
+    response: Option<Type>,
+}
+
+/// Endpoints and models as parsed from the crate's sources
+struct Source {
+    endpoints: Vec<Endpoint>,
+    models: HashMap<String, Vec<Field>>,
+}
+
+impl Source {
+    fn parse() -> Result<Self, Error> {
+        let endpoints = syn::parse_file(ENDPOINTS_SOURCE)?;
+        let models = syn::parse_file(MODELS_SOURCE)?;
+        let model_items = Items::new(&models.items);
+
+        let mut source = Source {
+            endpoints: Vec::new(),
+            models: HashMap::new(),
+        };
+        source.add_structs(&model_items, None, &model_items)?;
+
+        for item in &endpoints.items {
+            let syn::Item::Mod(module) = item else {
+                continue;
+            };
+            let Some((_, items)) = &module.content else {
+                continue;
+            };
+            let name = module.ident.to_string();
+            let Some(method) = name.split('_').next().filter(|m| METHODS.contains(m)) else {
+                continue;
+            };
+            let module_items = Items::new(items);
+            let Some(url) = module_items.base_url() else {
+                continue;
+            };
+            source.add_structs(&module_items, Some(&name), &model_items)?;
+
+            let response = module_items
+                .structs
+                .get("ResponseBody")
+                .map(|body| response_type(body, &name, &module_items, &model_items));
+            source.endpoints.push(Endpoint {
+                method: method.to_uppercase(),
+                path: url.trim_start_matches(DEFAULT_BASE_URL).to_string(),
+                module: name,
+                response,
+            });
+        }
+        Ok(source)
+    }
+
+    fn add_structs(
+        &mut self,
+        items: &Items,
+        module: Option<&str>,
+        models: &Items,
+    ) -> Result<(), syn::Error> {
+        for (name, item) in &items.structs {
+            let syn::Fields::Named(fields) = &item.fields else {
+                continue;
+            };
+            let mut parsed = Vec::new();
+            for field in &fields.named {
+                let attrs = FieldAttrs::parse(&field.attrs)?;
+                if attrs.flatten || attrs.skip {
+                    continue;
+                }
+                let mut ty = resolve(&field.ty, module, items, models);
+                if let Some(kind) = attrs.scalar {
+                    ty = ty.with_scalar(kind);
+                }
+                let name = match (attrs.rename, &field.ident) {
+                    (Some(rename), _) => rename,
+                    (None, Some(ident)) => ident.to_string(),
+                    (None, None) => continue,
+                };
+                parsed.push(Field {
+                    name,
+                    ty,
+                    null_default: attrs.null_default,
+                });
+            }
+            self.models.insert(qualify(module, name), parsed);
+        }
+        Ok(())
+    }
+}
+
+/// Newtype response bodies stand for the type they wrap
+fn response_type(body: &syn::ItemStruct, module: &str, items: &Items, models: &Items) -> Type {
+    match &body.fields {
+        syn::Fields::Unnamed(fields) => fields.unnamed.first().map_or(Type::Any, |field| {
+            resolve(&field.ty, Some(module), items, models)
+        }),
+        _ => Type::Model(qualify(Some(module), "ResponseBody")),
+    }
+}
+
+fn qualify(module: Option<&str>, name: &str) -> String {
+    match module {
+        Some(module) => format!("{}::{}", module, name),
+        None => name.to_string(),
+    }
+}
+
+/// The structs and enums declared at one level of a source file
+struct Items<'a> {
+    structs: HashMap<String, &'a syn::ItemStruct>,
+    enums: HashSet<String>,
+    consts: HashMap<String, &'a syn::ItemConst>,
+}
+
+impl<'a> Items<'a> {
+    fn new(items: &'a [syn::Item]) -> Self {
+        let mut result = Items {
+            structs: HashMap::new(),
+            enums: HashSet::new(),
+            consts: HashMap::new(),
+        };
+        for item in items {
+            match item {
+                syn::Item::Struct(item) => {
+                    result.structs.insert(item.ident.to_string(), item);
+                }
+                syn::Item::Enum(item) => {
+                    result.enums.insert(item.ident.to_string());
+                }
+                syn::Item::Const(item) => {
+                    result.consts.insert(item.ident.to_string(), item);
+                }
+                _ => {}
+            }
+        }
+        result
+    }
+
+    fn base_url(&self) -> Option<String> {
+        match &*self.consts.get("BASE_URL")?.expr {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(url),
+                ..
+            }) => Some(url.value()),
+            _ => None,
+        }
+    }
+}
+
+/// Maps a Rust type to its JSON shape. `models::X` and bare names declared in `models.rs` are
+/// models, bare names declared in the endpoint module are local to it.
+fn resolve(ty: &syn::Type, module: Option<&str>, items: &Items, models: &Items) -> Type {
+    let syn::Type::Path(path) = ty else {
+        return Type::Any;
+    };
+    let Some(last) = path.path.segments.last() else {
+        return Type::Any;
+    };
+    let argument = || match &last.arguments {
+        syn::PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(resolve(ty, module, items, models)),
+            _ => None,
+        }),
+        _ => None,
+    };
+    let qualified = path.path.segments.len() > 1;
+    let name = last.ident.to_string();
+    match name.as_str() {
+        "Option" => Type::Option(Box::new(argument().unwrap_or(Type::Any))),
+        "Vec" => Type::Vec(Box::new(argument().unwrap_or(Type::Any))),
+        "Box" => argument().unwrap_or(Type::Any),
+        "bool" => Type::Scalar(Kind::Boolean),
+        "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "isize" | "usize" => {
+            Type::Scalar(Kind::Integer)
+        }
+        "f32" | "f64" => Type::Scalar(Kind::Number),
+        "String" | "Secret" | "DateTime" | "NaiveDate" | "NaiveDateTime" | "NaiveTime" | "Tz" => {
+            Type::Scalar(Kind::String)
+        }
+        // Both are serialized as numbers, see the `serde_as` attributes and `day_of_week`
+        "Duration" | "DayOfWeek" => Type::Scalar(Kind::Integer),
+        "HashMap" | "BTreeMap" => Type::Scalar(Kind::Object),
+        _ if !qualified && module.is_some() && items.structs.contains_key(&name) => {
+            Type::Model(qualify(module, &name))
+        }
+        _ if !qualified && items.enums.contains(&name) => Type::Scalar(Kind::String),
+        _ if models.structs.contains_key(&name) => Type::Model(name),
+        _ if models.enums.contains(&name) => Type::Scalar(Kind::String),
+        _ => Type::Any,
+    }
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    rename: Option<String>,
+    flatten: bool,
+    skip: bool,
+    null_default: bool,
+    scalar: Option<Kind>,
+}
+
+impl FieldAttrs {
+    fn parse(attrs: &[syn::Attribute]) -> Result<Self, syn::Error> {
+        let mut result = FieldAttrs::default();
+        for attr in attrs {
+            let serde = attr.path().is_ident("serde");
+            let serde_as = attr.path().is_ident("serde_as");
+            if !serde && !serde_as {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                let value = match meta.input.peek(syn::Token![=]) {
+                    true => Some(meta.value()?.parse::<syn::LitStr>()?.value()),
+                    false => None,
+                };
+                if serde && meta.path.is_ident("rename") {
+                    result.rename = value;
+                } else if serde && meta.path.is_ident("flatten") {
+                    result.flatten = true;
+                } else if serde
+                    && (meta.path.is_ident("skip") || meta.path.is_ident("skip_deserializing"))
+                {
+                    result.skip = true;
+                } else if serde_as && meta.path.is_ident("as") {
+                    let adapter = value.unwrap_or_default();
+                    result.null_default |= adapter.contains("DefaultOnNull");
+                    if adapter.contains("Timestamp") || adapter.contains("Duration") {
+                        result.scalar = Some(Kind::Integer);
+                    }
+                }
+                Ok(())
+            })?;
+        }
+        Ok(result)
+    }
+}
+
+/// A Swagger 2 or OpenAPI 3 document
+pub struct Spec {
+    /// Prefix of every path, e.g. `/api/v9`
+    base_path: String,
+    paths: Map<String, Value>,
+    schemas: Map<String, Value>,
+    openapi3: bool,
+}
+
+impl Spec {
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        Self::from_value(serde_json::from_str(json)?)
+    }
+
+    pub fn from_value(mut document: Value) -> Result<Self, Error> {
+        let openapi3 = document.get("openapi").is_some();
+        if !openapi3 && document.get("swagger").is_none() {
+            return Err(Error::UnknownFormat);
+        }
+        let base_path = match openapi3 {
+            true => document["servers"][0]["url"]
+                .as_str()
+                .map(|url| match url.find("://") {
+                    Some(scheme) => url[scheme + 3..]
+                        .find('/')
+                        .map_or("", |host| &url[scheme + 3 + host..]),
+                    None => url,
+                }),
+            false => document["basePath"].as_str(),
+        }
+        .unwrap_or("")
+        .trim_end_matches('/')
+        .to_string();
+        let mut take = |pointer: &str| match document.pointer_mut(pointer).map(Value::take) {
+            Some(Value::Object(map)) => map,
+            _ => Map::new(),
+        };
+        let paths = take("/paths");
+        let schemas = match openapi3 {
+            true => take("/components/schemas"),
+            false => take("/definitions"),
+        };
+        Ok(Self {
+            base_path,
+            paths,
+            schemas,
+            openapi3,
+        })
+    }
+
+    /// Method, full path and success response schema of every operation
+    fn operations(&self) -> Vec<(String, String, Option<&Value>)> {
+        let mut operations = Vec::new();
+        for (path, item) in &self.paths {
+            for method in METHODS {
+                let Some(operation) = item.get(*method) else {
+                    continue;
+                };
+                let response = ["200", "201"]
+                    .iter()
+                    .find_map(|status| operation["responses"].get(*status));
+                let schema = response.and_then(|response| match self.openapi3 {
+                    true => response.pointer("/content/application~1json/schema"),
+                    false => response.get("schema"),
+                });
+                operations.push((
+                    method.to_uppercase(),
+                    format!("{}{}", self.base_path, path),
+                    schema,
+                ));
+            }
+        }
+        operations
+    }
+
+    /// Follows `$ref`s, including the `allOf: [{$ref}]` wrapper some generators emit
+    fn resolve<'a>(&'a self, schema: &'a Value) -> &'a Value {
+        if let Some(reference) = schema["$ref"].as_str() {
+            let name = reference.rsplit('/').next().unwrap_or(reference);
+            return match self.schemas.get(name) {
+                Some(target) => self.resolve(target),
+                None => schema,
+            };
+        }
+        match schema["allOf"].as_array().map(Vec::as_slice) {
+            Some([single]) => self.resolve(single),
+            _ => schema,
+        }
+    }
+
+    fn kind(&self, schema: &Value) -> Option<Kind> {
+        let schema = self.resolve(schema);
+        let name = match &schema["type"] {
+            Value::String(name) => name.as_str(),
+            Value::Array(names) => names
+                .iter()
+                .filter_map(Value::as_str)
+                .find(|name| *name != "null")?,
+            _ if schema.get("properties").is_some() => "object",
+            _ => return None,
+        };
+        match name {
+            "array" => Some(Kind::Array),
+            "boolean" => Some(Kind::Boolean),
+            "integer" => Some(Kind::Integer),
+            "number" => Some(Kind::Number),
+            "object" => Some(Kind::Object),
+            "string" => Some(Kind::String),
+            _ => None,
+        }
+    }
+
+    fn nullable(&self, schema: &Value) -> bool {
+        let nullable = |schema: &Value| {
+            schema["x-nullable"] == true
+                || schema["nullable"] == true
+                || schema["type"]
+                    .as_array()
+                    .is_some_and(|names| names.iter().any(|name| name == "null"))
+        };
+        nullable(schema) || nullable(self.resolve(schema))
+    }
+}
+
+/// Normalizes placeholder names so `{workspace_id}` matches `{wid}`
+fn path_key(method: &str, path: &str) -> (String, String) {
+    let mut key = String::new();
+    let mut in_placeholder = false;
+    for c in path.trim_end_matches('/').chars() {
+        match c {
+            '{' => {
+                in_placeholder = true;
+                key.push_str("{}");
+            }
+            '}' => in_placeholder = false,
+            c if !in_placeholder => key.push(c),
+            _ => {}
+        }
+    }
+    (method.to_string(), key)
+}
+
+/// Compares every endpoint module and model the crate has against `spec`
+pub fn compare(spec: &Spec) -> Result<Vec<Difference>, Error> {
+    Ok(Checker {
+        spec,
+        source: &Source::parse()?,
+        seen: HashSet::new(),
+        differences: Vec::new(),
+    }
+    .run())
+}
+
+struct Checker<'a> {
+    spec: &'a Spec,
+    source: &'a Source,
+    seen: HashSet<String>,
+    differences: Vec<Difference>,
+}
+
+impl<'a> Checker<'a> {
+    fn run(mut self) -> Vec<Difference> {
+        let ours: HashMap<_, _> = self
+            .source
+            .endpoints
+            .iter()
+            .map(|endpoint| (path_key(&endpoint.method, &endpoint.path), endpoint))
+            .collect();
+        let mut theirs = BTreeSet::new();
+        let spec = self.spec;
+        for (method, path, schema) in spec.operations() {
+            let key = path_key(&method, &path);
+            theirs.insert(key.clone());
+            let Some(endpoint) = ours.get(&key) else {
+                self.differences
+                    .push(Difference::MissingEndpoint { method, path });
+                continue;
+            };
+            if let (Some(ours), Some(theirs)) = (&endpoint.response, schema) {
+                let location = format!("{} response", endpoint.module);
+                self.compare(&location, ours, theirs, false);
+            }
+        }
+        for endpoint in &self.source.endpoints {
+            if endpoint.path.starts_with(&spec.base_path)
+                && !theirs.contains(&path_key(&endpoint.method, &endpoint.path))
+            {
+                self.differences.push(Difference::UnknownEndpoint {
+                    method: endpoint.method.clone(),
+                    path: endpoint.path.clone(),
+                });
+            }
+        }
+        self.differences
+    }
+
+    fn compare(&mut self, location: &str, ours: &Type, theirs: &'a Value, check_null: bool) {
+        let spec = self.spec;
+        let (Some(found), Some(kind)) = (ours.kind(), spec.kind(theirs)) else {
+            return;
+        };
+        if found != kind {
+            self.differences.push(Difference::TypeMismatch {
+                location: location.to_string(),
+                spec: kind,
+                found,
+            });
+            return;
+        }
+        if check_null && !ours.is_option() && spec.nullable(theirs) {
+            self.differences.push(Difference::NotNullable {
+                location: location.to_string(),
+            });
+        }
+        let theirs = spec.resolve(theirs);
+        match ours.unwrap_option() {
+            Type::Vec(item) => {
+                let location = format!("{}[]", location);
+                self.compare(&location, item, &theirs["items"], false);
+            }
+            Type::Model(name) => self.compare_model(name, theirs),
+            _ => {}
+        }
+    }
+
+    fn compare_model(&mut self, model: &str, theirs: &'a Value) {
+        let Some(properties) = theirs["properties"].as_object() else {
+            return;
+        };
+        let source = self.source;
+        let Some(fields) = source.models.get(model) else {
+            return;
+        };
+        if !self.seen.insert(model.to_string()) {
+            return;
+        }
+        for name in properties.keys() {
+            if !fields.iter().any(|field| &field.name == name) {
+                self.differences.push(Difference::MissingField {
+                    model: model.to_string(),
+                    field: name.clone(),
+                });
+            }
+        }
+        for field in fields {
+            let Some(schema) = properties.get(&field.name) else {
+                self.differences.push(Difference::UnknownField {
+                    model: model.to_string(),
+                    field: field.name.clone(),
+                });
+                continue;
+            };
+            let location = format!("{}.{}", model, field.name);
+            self.compare(&location, &field.ty, schema, !field.null_default);
+        }
+    }
+}
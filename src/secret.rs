@@ -0,0 +1,62 @@
+use crate::fixtures::REDACTED;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::atomic::{compiler_fence, Ordering};
+
+/// A credential or token that prints as [`REDACTED`] and is overwritten with zeros when dropped.
+///
+/// Serializes as the plain string, so models round-trip unchanged. Use [`Secret::expose`] where
+/// the value is actually needed.
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl fmt::Display for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        // All zeros is valid UTF-8, so the String stays sound until it's gone
+        zeroize(unsafe { self.0.as_mut_vec() });
+    }
+}
+
+/// Overwrites `values` with zeros using volatile writes, so the compiler can't skip zeroing
+/// memory that's about to be freed
+pub(crate) fn zeroize<T: Copy + Default>(values: &mut [T]) {
+    for value in values.iter_mut() {
+        unsafe { std::ptr::write_volatile(value, T::default()) };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
@@ -0,0 +1,691 @@
+use crate::api::TogglApi;
+use crate::client;
+use crate::endpoints::{
+    delete_time_entries, get_time_entries, post_time_entries, put_time_entries,
+};
+use crate::models::TimeEntry;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Written to `created_with` for every entry pushed from Timewarrior
+pub const CREATED_WITH: &str = "togglrust timewarrior sync";
+
+const TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("{file}:{line}: {message}")]
+    Parse {
+        file: PathBuf,
+        line: usize,
+        message: String,
+    },
+
+    #[error("HTTP error: {0}")]
+    Client(#[from] client::Error),
+}
+
+/// One line of a Timewarrior data file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interval {
+    pub start: DateTime<Utc>,
+
+    /// `None` while the interval is still being tracked
+    pub end: Option<DateTime<Utc>>,
+    pub tags: Vec<String>,
+    pub annotation: Option<String>,
+}
+
+impl Interval {
+    /// Parses a line like `inc 20230501T070000Z - 20230501T083000Z # tag "other tag" # "notes"`
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let rest = line
+            .trim()
+            .strip_prefix("inc ")
+            .ok_or_else(|| String::from("line doesn't start with \"inc\""))?;
+        let (range, annotated) = match rest.split_once(" # ") {
+            Some((range, annotated)) => (range, Some(annotated)),
+            None => (rest.trim_end_matches(" #"), None),
+        };
+
+        let mut timestamps = range.split(" - ");
+        let start = parse_timestamp(timestamps.next().unwrap_or_default())?;
+        let end = timestamps.next().map(parse_timestamp).transpose()?;
+
+        let mut tags = Vec::new();
+        let mut annotation = None;
+        if let Some(annotated) = annotated {
+            let words = split_words(annotated)?;
+            let mut words = words.split(|word| word == "#");
+            tags = words.next().unwrap_or_default().to_vec();
+            annotation = words.next().map(|words| words.join(" "));
+        }
+
+        Ok(Self {
+            start,
+            end,
+            tags,
+            annotation,
+        })
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "inc {}", self.start.format(TIMESTAMP_FORMAT))?;
+        if let Some(end) = self.end {
+            write!(f, " - {}", end.format(TIMESTAMP_FORMAT))?;
+        }
+        if !self.tags.is_empty() || self.annotation.is_some() {
+            write!(f, " #")?;
+            for tag in &self.tags {
+                write!(f, " {}", quote_word(tag))?;
+            }
+        }
+        if let Some(annotation) = &self.annotation {
+            write!(f, " # {}", serde_json::to_string(annotation).unwrap())?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, String> {
+    NaiveDateTime::parse_from_str(value.trim(), TIMESTAMP_FORMAT)
+        .map(|naive| naive.and_utc())
+        .map_err(|_| format!("invalid timestamp {:?}", value))
+}
+
+/// Splits on whitespace, keeping double quoted words together
+fn split_words(text: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '"' {
+            chars.next();
+            let mut word = String::new();
+            loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') => word.extend(chars.next()),
+                    Some(c) => word.push(c),
+                    None => return Err(String::from("unterminated quote")),
+                }
+            }
+            words.push(word);
+        } else {
+            let mut word = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                word.push(c);
+                chars.next();
+            }
+            words.push(word);
+        }
+    }
+    Ok(words)
+}
+
+fn quote_word(word: &str) -> String {
+    if word.is_empty() || word == "#" || word.contains(|c: char| c.is_whitespace() || c == '"') {
+        serde_json::to_string(word).unwrap()
+    } else {
+        word.to_string()
+    }
+}
+
+/// All intervals of a Timewarrior `data` directory, one file per month
+pub struct Database {
+    data_dir: PathBuf,
+    intervals: BTreeMap<String, Vec<Interval>>,
+    dirty: BTreeSet<String>,
+}
+
+fn from_timestamp(timestamp: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(timestamp, 0).unwrap_or_default()
+}
+
+fn month_file(start: DateTime<Utc>) -> String {
+    start.format("%Y-%m.data").to_string()
+}
+
+/// Writes to a temporary file first so a crash can't leave a truncated file behind
+fn write_atomically(path: &Path, contents: &[u8]) -> Result<(), Error> {
+    let mut temporary = path.as_os_str().to_owned();
+    temporary.push(".tmp");
+    let mut file = fs::File::create(&temporary)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    fs::rename(&temporary, path)?;
+    Ok(())
+}
+
+impl Database {
+    pub fn open(data_dir: impl AsRef<Path>) -> Result<Self, Error> {
+        let data_dir = data_dir.as_ref().to_path_buf();
+        let mut intervals = BTreeMap::new();
+        for dir_entry in fs::read_dir(&data_dir)? {
+            let path = dir_entry?.path();
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if !name.ends_with(".data") || name.starts_with("undo") {
+                continue;
+            }
+            let mut file_intervals = Vec::new();
+            for (index, line) in fs::read_to_string(&path)?.lines().enumerate() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let interval = Interval::parse(line).map_err(|message| Error::Parse {
+                    file: path.clone(),
+                    line: index + 1,
+                    message,
+                })?;
+                file_intervals.push(interval);
+            }
+            intervals.insert(name.to_string(), file_intervals);
+        }
+        Ok(Self {
+            data_dir,
+            intervals,
+            dirty: BTreeSet::new(),
+        })
+    }
+
+    pub fn intervals(&self) -> impl Iterator<Item = &Interval> {
+        self.intervals.values().flatten()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        let file = month_file(interval.start);
+        let intervals = self.intervals.entry(file.clone()).or_default();
+        let index = intervals.partition_point(|other| other.start <= interval.start);
+        intervals.insert(index, interval);
+        self.dirty.insert(file);
+    }
+
+    pub fn remove(&mut self, start: DateTime<Utc>) -> Option<Interval> {
+        let file = month_file(start);
+        let intervals = self.intervals.get_mut(&file)?;
+        let index = intervals
+            .iter()
+            .position(|interval| interval.start == start)?;
+        self.dirty.insert(file);
+        Some(intervals.remove(index))
+    }
+
+    /// Writes back the files of every month that was changed
+    pub fn save(&mut self) -> Result<(), Error> {
+        for file in std::mem::take(&mut self.dirty) {
+            let mut contents = String::new();
+            for interval in self.intervals.get(&file).into_iter().flatten() {
+                contents.push_str(&interval.to_string());
+                contents.push('\n');
+            }
+            write_atomically(&self.data_dir.join(file), contents.as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+/// How Timewarrior tags translate to Toggl projects and tags
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TagMapping {
+    /// Workspace new entries are created in
+    pub workspace_id: i64,
+
+    /// Timewarrior tag to Toggl project ID
+    pub projects: HashMap<String, i64>,
+
+    /// Timewarrior tag to Toggl tag name
+    pub tags: HashMap<String, String>,
+
+    /// Send tags that appear in neither map to Toggl under their own name
+    pub keep_unmapped_tags: bool,
+}
+
+impl TagMapping {
+    pub fn to_time_entry(&self, interval: &Interval) -> Option<post_time_entries::RequestBody> {
+        let end = interval.end?;
+        let mut body =
+            post_time_entries::RequestBody::new(self.workspace_id, interval.start, CREATED_WITH);
+        let mut tags = Vec::new();
+        for tag in &interval.tags {
+            if let Some(project_id) = self.projects.get(tag) {
+                body.project_id.get_or_insert(*project_id);
+            } else if let Some(toggl_tag) = self.tags.get(tag) {
+                tags.push(toggl_tag.clone());
+            } else if self.keep_unmapped_tags {
+                tags.push(tag.clone());
+            }
+        }
+        body.description = interval.annotation.clone();
+        body.duration = Some(end - interval.start);
+        body.stop = Some(end);
+        body.tags = (!tags.is_empty()).then_some(tags);
+        Some(body)
+    }
+
+    /// Like [`TagMapping::to_time_entry`], for an interval that already has a time entry. Tags
+    /// and the description are replaced, but Toggl keeps the project if the interval lost its
+    /// project tag.
+    pub fn to_update(&self, interval: &Interval) -> Option<put_time_entries::RequestBody> {
+        let body = self.to_time_entry(interval)?;
+        Some(put_time_entries::RequestBody {
+            description: Some(body.description.unwrap_or_default()),
+            duration: body.duration,
+            project_id: body.project_id,
+            start: Some(body.start),
+            stop: body.stop,
+            tags: Some(body.tags.unwrap_or_default()),
+            ..Default::default()
+        })
+    }
+
+    pub fn to_interval(&self, entry: &TimeEntry) -> Option<Interval> {
+        let end = entry.stop?;
+        let mut tags = Vec::new();
+        if let Some(project_id) = entry.project_id {
+            tags.extend(
+                self.projects
+                    .iter()
+                    .filter(|(_, id)| **id == project_id)
+                    .map(|(tag, _)| tag.clone())
+                    .min(),
+            );
+        }
+        for toggl_tag in &entry.tags {
+            match self.tags.iter().find(|(_, name)| *name == toggl_tag) {
+                Some((tag, _)) => tags.push(tag.clone()),
+                None => tags.push(toggl_tag.clone()),
+            }
+        }
+        Some(Interval {
+            start: entry.start,
+            end: Some(end),
+            tags,
+            annotation: entry
+                .description
+                .clone()
+                .filter(|description| !description.is_empty()),
+        })
+    }
+
+    /// `entry` translated like [`TagMapping::to_interval`], for an interval that was pushed or
+    /// pulled before. Tags of `previous` that still describe the entry are kept as they are, as
+    /// are tags Toggl never got, so an update from Toggl can't lose local data. Returns an
+    /// interval equal to `previous` if the entry is what `previous` was pushed as.
+    pub fn update_interval(&self, previous: &Interval, entry: &TimeEntry) -> Option<Interval> {
+        let mut interval = self.to_interval(entry)?;
+        let mut tags: Vec<String> = previous
+            .tags
+            .iter()
+            .filter(|tag| match self.toggl_tag(tag) {
+                TogglTag::Project(project_id) => entry.project_id == Some(project_id),
+                TogglTag::Tag(name) => entry.tags.contains(&name),
+                TogglTag::None => true,
+            })
+            .cloned()
+            .collect();
+        let mut kept: Vec<TogglTag> = tags.iter().map(|tag| self.toggl_tag(tag)).collect();
+        for tag in interval.tags {
+            let toggl_tag = self.toggl_tag(&tag);
+            let represented = match &toggl_tag {
+                TogglTag::Project(_) => {
+                    kept.iter().any(|kept| matches!(kept, TogglTag::Project(_)))
+                }
+                _ => kept.contains(&toggl_tag),
+            };
+            if !represented {
+                tags.push(tag);
+                kept.push(toggl_tag);
+            }
+        }
+        interval.tags = tags;
+        Some(interval)
+    }
+
+    /// What a Timewarrior tag turns into in Toggl
+    fn toggl_tag(&self, tag: &str) -> TogglTag {
+        if let Some(project_id) = self.projects.get(tag) {
+            TogglTag::Project(*project_id)
+        } else if let Some(toggl_tag) = self.tags.get(tag) {
+            TogglTag::Tag(toggl_tag.clone())
+        } else if self.keep_unmapped_tags {
+            TogglTag::Tag(tag.to_string())
+        } else {
+            TogglTag::None
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum TogglTag {
+    Project(i64),
+    Tag(String),
+
+    /// Only exists in Timewarrior
+    None,
+}
+
+/// A Timewarrior interval and the Toggl time entry it was synced with
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Correlation {
+    pub time_entry_id: i64,
+
+    /// The interval as it was last pushed or pulled, as a data file line. Comparing it with the
+    /// database tells which intervals were changed or deleted locally since.
+    pub synced: String,
+}
+
+/// Persisted between runs so intervals aren't pushed or pulled twice
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SyncState {
+    /// When remote changes were last pulled, passed as `since` on the next pull
+    pub last_pulled_at: Option<DateTime<Utc>>,
+
+    /// Keyed by interval start timestamp. Timewarrior intervals never overlap, so the start time
+    /// identifies an interval.
+    pub correlations: BTreeMap<i64, Correlation>,
+}
+
+impl SyncState {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(serde_json::from_str(&contents)?),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        write_atomically(path.as_ref(), &serde_json::to_vec_pretty(self)?)
+    }
+
+    fn interval_for(&self, time_entry_id: i64) -> Option<i64> {
+        self.correlations
+            .iter()
+            .find(|(_, correlation)| correlation.time_entry_id == time_entry_id)
+            .map(|(start, _)| *start)
+    }
+
+    fn correlate(&mut self, interval: &Interval, time_entry_id: i64) {
+        self.correlations.insert(
+            interval.start.timestamp(),
+            Correlation {
+                time_entry_id,
+                synced: interval.to_string(),
+            },
+        );
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SyncReport {
+    pub pushed: usize,
+    pub pulled: usize,
+    pub updated: usize,
+    pub removed: usize,
+
+    /// Time entries updated with local changes
+    pub pushed_updates: usize,
+
+    /// Time entries deleted because their interval was deleted locally
+    pub pushed_deletions: usize,
+}
+
+/// Two-way sync between a Timewarrior database and Toggl.
+///
+/// Remote changes are pulled first and win over local ones. Afterwards local changes to synced
+/// intervals are pushed as updates, deleted intervals delete their time entry, and every closed
+/// interval that isn't correlated with a time entry yet is pushed as a new one. An interval whose
+/// start was moved is recognised by overlapping where it was last synced.
+pub struct Sync<'a, A> {
+    api: &'a A,
+    mapping: &'a TagMapping,
+    database: Database,
+    state: SyncState,
+    state_path: PathBuf,
+}
+
+impl<'a, A: TogglApi> Sync<'a, A> {
+    pub fn open(
+        api: &'a A,
+        mapping: &'a TagMapping,
+        data_dir: impl AsRef<Path>,
+        state_path: impl AsRef<Path>,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            api,
+            mapping,
+            database: Database::open(data_dir)?,
+            state: SyncState::load(&state_path)?,
+            state_path: state_path.as_ref().to_path_buf(),
+        })
+    }
+
+    pub async fn run(&mut self) -> Result<SyncReport, Error> {
+        let mut report = SyncReport::default();
+        self.pull(&mut report).await?;
+        self.database.save()?;
+        self.state.save(&self.state_path)?;
+        self.push(&mut report).await?;
+        Ok(report)
+    }
+
+    async fn pull(&mut self, report: &mut SyncReport) -> Result<(), Error> {
+        let pulled_at = Utc::now();
+        let entries = self
+            .api
+            .get_time_entries(get_time_entries::RequestBody {
+                since: self.state.last_pulled_at,
+                ..Default::default()
+            })
+            .await?;
+
+        for entry in entries.0 {
+            if entry.workspace_id != self.mapping.workspace_id {
+                continue;
+            }
+            let correlated = self.state.interval_for(entry.id);
+            if entry.server_deleted_at.is_some() {
+                if let Some(start) = correlated {
+                    self.database.remove(from_timestamp(start));
+                    self.state.correlations.remove(&start);
+                    report.removed += 1;
+                }
+                continue;
+            }
+
+            // Unchanged since it was synced, which is also how the entries pushed by the last
+            // run come back. Local changes made since then are pushed later.
+            let synced = correlated
+                .and_then(|start| self.state.correlations.get(&start))
+                .and_then(|correlation| Interval::parse(&correlation.synced).ok());
+            if let Some(synced) = &synced {
+                if self.mapping.update_interval(synced, &entry).as_ref() == Some(synced) {
+                    continue;
+                }
+            }
+
+            let previous = correlated.and_then(|start| {
+                self.database
+                    .intervals()
+                    .find(|interval| interval.start.timestamp() == start)
+                    .cloned()
+            });
+            let interval = match &previous {
+                Some(previous) => self.mapping.update_interval(previous, &entry),
+                None => self.mapping.to_interval(&entry),
+            };
+            // A running entry leaves the interval it was correlated with alone
+            let Some(interval) = interval else {
+                continue;
+            };
+            if previous.as_ref() == Some(&interval) {
+                continue;
+            }
+            match correlated {
+                Some(start) => {
+                    self.database.remove(from_timestamp(start));
+                    self.state.correlations.remove(&start);
+                }
+                // An interval that was tracked locally and never pushed takes precedence
+                None if self
+                    .database
+                    .intervals()
+                    .any(|other| other.start == interval.start) =>
+                {
+                    continue
+                }
+                None => {}
+            }
+            self.state.correlate(&interval, entry.id);
+            self.database.insert(interval);
+            match correlated {
+                Some(_) => report.updated += 1,
+                None => report.pulled += 1,
+            }
+        }
+
+        self.state.last_pulled_at = Some(pulled_at);
+        Ok(())
+    }
+
+    async fn push(&mut self, report: &mut SyncReport) -> Result<(), Error> {
+        self.push_changes(report).await?;
+
+        let pending: Vec<post_time_entries::RequestBody> = self
+            .database
+            .intervals()
+            .filter(|interval| {
+                !self
+                    .state
+                    .correlations
+                    .contains_key(&interval.start.timestamp())
+            })
+            .filter_map(|interval| self.mapping.to_time_entry(interval))
+            .collect();
+
+        for body in pending {
+            let start = body.start;
+            let response = self
+                .api
+                .post_time_entries(
+                    post_time_entries::PathParams {
+                        workspace_id: body.workspace_id,
+                    },
+                    body,
+                )
+                .await?;
+            if let Some(entry) = response.0 {
+                let interval = self
+                    .database
+                    .intervals()
+                    .find(|interval| interval.start == start)
+                    .cloned();
+                if let Some(interval) = interval {
+                    self.state.correlate(&interval, entry.id);
+                }
+                // Saved after every entry so an interrupted push doesn't create duplicates
+                self.state.save(&self.state_path)?;
+                report.pushed += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Updates or deletes the time entries of intervals changed locally since they were synced
+    async fn push_changes(&mut self, report: &mut SyncReport) -> Result<(), Error> {
+        let correlations: Vec<(i64, Correlation)> = self
+            .state
+            .correlations
+            .iter()
+            .map(|(start, correlation)| (*start, correlation.clone()))
+            .collect();
+        for (start, correlation) in correlations {
+            let Ok(synced) = Interval::parse(&correlation.synced) else {
+                continue;
+            };
+            let local = self
+                .database
+                .intervals()
+                .find(|interval| interval.start == synced.start)
+                .or_else(|| self.moved(&synced))
+                .cloned();
+            if local.as_ref() == Some(&synced) {
+                continue;
+            }
+            let (workspace_id, time_entry_id) =
+                (self.mapping.workspace_id, correlation.time_entry_id);
+            match local {
+                Some(interval) => {
+                    // Reopened, the update waits until it is stopped again
+                    let Some(body) = self.mapping.to_update(&interval) else {
+                        continue;
+                    };
+                    self.api
+                        .put_time_entries(
+                            put_time_entries::PathParams {
+                                workspace_id,
+                                time_entry_id,
+                            },
+                            body,
+                        )
+                        .await?;
+                    self.state.correlations.remove(&start);
+                    self.state.correlate(&interval, time_entry_id);
+                    report.pushed_updates += 1;
+                }
+                None => {
+                    let params = delete_time_entries::PathParams {
+                        workspace_id,
+                        time_entry_id,
+                    };
+                    match self.api.delete_time_entries(params).await {
+                        Ok(()) => {}
+                        // Deleted in Toggl as well
+                        Err(err) if err.status() == Some(reqwest::StatusCode::NOT_FOUND) => {}
+                        Err(err) => return Err(err.into()),
+                    }
+                    self.state.correlations.remove(&start);
+                    report.pushed_deletions += 1;
+                }
+            }
+            // Saved after every entry so an interrupted push doesn't repeat it
+            self.state.save(&self.state_path)?;
+        }
+        Ok(())
+    }
+
+    /// An uncorrelated closed interval overlapping where `synced` was, which is what moving the
+    /// start of a synced interval leaves behind
+    fn moved(&self, synced: &Interval) -> Option<&Interval> {
+        let synced_end = synced.end?;
+        self.database.intervals().find(|interval| {
+            interval.end.is_some_and(|end| end > synced.start)
+                && interval.start < synced_end
+                && !self
+                    .state
+                    .correlations
+                    .contains_key(&interval.start.timestamp())
+        })
+    }
+}
@@ -0,0 +1,118 @@
+use crate::endpoints::{post_time_entries, post_track_reminders};
+use crate::models::{Project, Workspace};
+use thiserror::Error;
+
+/// A reason why Toggl would reject a time entry
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum Violation {
+    #[error("time entry belongs to workspace {actual}, expected {expected}")]
+    WrongWorkspace { expected: i64, actual: i64 },
+
+    #[error("workspace requires a description")]
+    MissingDescription,
+
+    #[error("workspace requires a project")]
+    MissingProject,
+
+    #[error("workspace requires at least one tag")]
+    MissingTag,
+
+    #[error("workspace requires a task")]
+    MissingTask,
+
+    #[error("project {0} doesn't exist in the workspace")]
+    UnknownProject(i64),
+
+    #[error("project {0} is archived")]
+    ArchivedProject(i64),
+
+    #[error("project {0} was deleted")]
+    DeletedProject(i64),
+}
+
+/// Checks a draft time entry against the constraints of the workspace it will be created in.
+///
+/// `projects` should hold the projects of the workspace, including archived ones, so that a
+/// reference to an archived project can be told apart from an unknown one. Returns every
+/// violation found, so an empty list means the entry can be sent.
+pub fn validate_time_entry(
+    entry: &post_time_entries::RequestBody,
+    workspace: &Workspace,
+    projects: &[Project],
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    if entry.workspace_id != workspace.id {
+        violations.push(Violation::WrongWorkspace {
+            expected: workspace.id,
+            actual: entry.workspace_id,
+        });
+    }
+
+    if let Some(constraints) = workspace
+        .te_constraints
+        .as_ref()
+        .filter(|constraints| constraints.time_entry_constraints_enabled)
+    {
+        let has_description = entry
+            .description
+            .as_deref()
+            .is_some_and(|description| !description.trim().is_empty());
+        if constraints.description_present && !has_description {
+            violations.push(Violation::MissingDescription);
+        }
+        if constraints.project_present && entry.project_id.is_none() {
+            violations.push(Violation::MissingProject);
+        }
+        let has_tags = entry.tags.as_ref().is_some_and(|tags| !tags.is_empty())
+            || entry.tag_ids.as_ref().is_some_and(|ids| !ids.is_empty());
+        if constraints.tag_present && !has_tags {
+            violations.push(Violation::MissingTag);
+        }
+        if constraints.task_present && entry.task_id.is_none() {
+            violations.push(Violation::MissingTask);
+        }
+    }
+
+    if let Some(project_id) = entry.project_id {
+        match projects
+            .iter()
+            .find(|project| project.id == project_id && project.workspace_id == workspace.id)
+        {
+            None => violations.push(Violation::UnknownProject(project_id)),
+            Some(project) if project.server_deleted_at.is_some() => {
+                violations.push(Violation::DeletedProject(project_id))
+            }
+            Some(project) if !project.active => {
+                violations.push(Violation::ArchivedProject(project_id))
+            }
+            Some(_) => {}
+        }
+    }
+
+    violations
+}
+
+/// A reason why Toggl would reject a track reminder
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ReminderViolation {
+    #[error("frequency must be 1 or 7 days, got {0}")]
+    InvalidFrequency(i64),
+
+    #[error("threshold must be a positive number of hours, got {0}")]
+    InvalidThreshold(i64),
+}
+
+/// Checks a track reminder before it's created or updated, returning every violation found
+pub fn validate_track_reminder(
+    reminder: &post_track_reminders::RequestBody,
+) -> Vec<ReminderViolation> {
+    let mut violations = Vec::new();
+    if !matches!(reminder.frequency, 1 | 7) {
+        violations.push(ReminderViolation::InvalidFrequency(reminder.frequency));
+    }
+    if reminder.threshold <= 0 {
+        violations.push(ReminderViolation::InvalidThreshold(reminder.threshold));
+    }
+    violations
+}
@@ -0,0 +1,278 @@
+use crate::models;
+use crate::secret::Secret;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+#[cfg(feature = "webhooks")]
+use hyper::service::{make_service_fn, service_fn};
+#[cfg(feature = "webhooks")]
+use hyper::{Body, Method, Request, Response, Server};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::Sha256;
+use std::collections::HashMap;
+#[cfg(feature = "webhooks")]
+use std::convert::Infallible;
+#[cfg(feature = "webhooks")]
+use std::future::Future;
+#[cfg(feature = "webhooks")]
+use std::net::SocketAddr;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Header carrying the hex encoded HMAC-SHA256 of the request body, prefixed with `sha256=`
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature-256";
+
+/// Largest request body `Receiver::serve` reads. Toggl's deliveries are a few kilobytes.
+pub const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("request has no signature")]
+    MissingSignature,
+
+    #[error("request signature doesn't match")]
+    InvalidSignature,
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("request body is larger than {MAX_BODY_SIZE} bytes")]
+    BodyTooLarge,
+
+    #[cfg(feature = "webhooks")]
+    #[error("HTTP server error: {0}")]
+    Server(#[from] hyper::Error),
+}
+
+/// Checks a signature header against the body and the subscription's secret in constant time
+pub fn verify_signature(secret: &str, body: &[u8], signature: &str) -> bool {
+    let Some(signature) = signature
+        .trim()
+        .strip_prefix("sha256=")
+        .and_then(|hex_signature| hex::decode(hex_signature).ok())
+    else {
+        return false;
+    };
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take a key of any size");
+    mac.update(body);
+    mac.verify_slice(&signature).is_ok()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Metadata {
+    /// What happened, e.g. "created", "updated" or "deleted"
+    pub action: String,
+
+    /// Which kind of entity it happened to, e.g. "time_entry" or "project"
+    pub model: String,
+
+    /// Everything else Toggl sends along, like `path`, `request_type` and entity IDs
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// The body of a webhook request as Toggl sends it
+#[derive(Debug, Deserialize)]
+struct RawEvent {
+    event_id: i64,
+    created_at: DateTime<Utc>,
+    creator_id: i64,
+    metadata: Option<Metadata>,
+    payload: Value,
+    subscription_id: i64,
+    validation_code: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum Payload {
+    TimeEntry(models::TimeEntry),
+    Project(models::Project),
+    Client(models::Client),
+    Tag(models::Tag),
+
+    /// Entities this crate has no model for, and payloads that didn't match their model
+    Other(Value),
+}
+
+#[derive(Debug)]
+pub struct Event {
+    pub event_id: i64,
+    pub created_at: DateTime<Utc>,
+
+    /// ID of the user whose action caused the event
+    pub creator_id: i64,
+    pub subscription_id: i64,
+    pub metadata: Metadata,
+    pub payload: Payload,
+}
+
+#[derive(Debug)]
+pub enum Delivery {
+    /// Sent when a subscription is created or pinged. Toggl expects the validation code back
+    /// before it considers the callback URL validated.
+    Ping {
+        validation_code: Option<String>,
+    },
+    Event(Box<Event>),
+}
+
+fn parse_payload(model: &str, payload: Value) -> Payload {
+    fn parse<T: serde::de::DeserializeOwned>(
+        payload: Value,
+        wrap: impl FnOnce(T) -> Payload,
+    ) -> Payload {
+        match serde_json::from_value(payload.clone()) {
+            Ok(entity) => wrap(entity),
+            Err(_) => Payload::Other(payload),
+        }
+    }
+    match model {
+        "time_entry" => parse(payload, Payload::TimeEntry),
+        "project" => parse(payload, Payload::Project),
+        "client" => parse(payload, Payload::Client),
+        "tag" => parse(payload, Payload::Tag),
+        _ => Payload::Other(payload),
+    }
+}
+
+/// Verifies and decodes webhook requests for one subscription.
+///
+/// [`Receiver::receive`] works with the raw header and body so it can be used from any HTTP
+/// framework, while [`Receiver::serve`] runs a small standalone server when the `webhooks`
+/// feature is enabled.
+#[derive(Clone)]
+pub struct Receiver {
+    secret: Arc<Secret>,
+}
+
+impl Receiver {
+    /// Takes the subscription's [`models::WebhookSubscription::secret`], or the plain string
+    pub fn new(secret: impl Into<Secret>) -> Self {
+        Self {
+            secret: Arc::new(secret.into()),
+        }
+    }
+
+    pub fn receive(&self, signature: Option<&str>, body: &[u8]) -> Result<Delivery, Error> {
+        let signature = signature.ok_or(Error::MissingSignature)?;
+        if !verify_signature(self.secret.expose(), body, signature) {
+            return Err(Error::InvalidSignature);
+        }
+
+        let raw: RawEvent = serde_json::from_slice(body)?;
+        if raw.validation_code.is_some() || raw.payload.as_str() == Some("ping") {
+            return Ok(Delivery::Ping {
+                validation_code: raw.validation_code,
+            });
+        }
+        let metadata = raw.metadata.unwrap_or_else(|| Metadata {
+            action: String::new(),
+            model: String::new(),
+            extra: HashMap::new(),
+        });
+        Ok(Delivery::Event(Box::new(Event {
+            event_id: raw.event_id,
+            created_at: raw.created_at,
+            creator_id: raw.creator_id,
+            subscription_id: raw.subscription_id,
+            payload: parse_payload(&metadata.model, raw.payload),
+            metadata,
+        })))
+    }
+
+    /// The status and JSON body to answer a request with
+    pub fn response(result: &Result<Delivery, Error>) -> (StatusCode, String) {
+        match result {
+            Ok(Delivery::Ping {
+                validation_code: Some(validation_code),
+            }) => (
+                StatusCode::OK,
+                serde_json::json!({ "validation_code": validation_code }).to_string(),
+            ),
+            Ok(_) => (StatusCode::OK, String::from("{}")),
+            Err(Error::MissingSignature) | Err(Error::InvalidSignature) => {
+                (StatusCode::UNAUTHORIZED, String::from("{}"))
+            }
+            Err(Error::BodyTooLarge) => (StatusCode::PAYLOAD_TOO_LARGE, String::from("{}")),
+            Err(_) => (StatusCode::BAD_REQUEST, String::from("{}")),
+        }
+    }
+
+    #[cfg(feature = "webhooks")]
+    async fn handle<F, Fut>(
+        &self,
+        request: Request<Body>,
+        handler: F,
+    ) -> Result<Response<Body>, Infallible>
+    where
+        F: Fn(Event) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        if request.method() != Method::POST {
+            let mut response = Response::new(Body::empty());
+            *response.status_mut() = StatusCode::METHOD_NOT_ALLOWED;
+            return Ok(response);
+        }
+        let signature = request
+            .headers()
+            .get(SIGNATURE_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let body = http_body::Limited::new(request.into_body(), MAX_BODY_SIZE);
+        let result = match hyper::body::to_bytes(body).await {
+            Ok(body) => self.receive(signature.as_deref(), &body),
+            Err(err) => match err.downcast::<hyper::Error>() {
+                Ok(err) => Err(Error::Server(*err)),
+                Err(_) => Err(Error::BodyTooLarge),
+            },
+        };
+        let (status, body) = Self::response(&result);
+        if let Ok(Delivery::Event(event)) = result {
+            tokio::spawn(handler(*event));
+        }
+
+        let mut response = Response::new(Body::from(body));
+        *response.status_mut() = status;
+        response.headers_mut().insert(
+            hyper::header::CONTENT_TYPE,
+            hyper::header::HeaderValue::from_static("application/json"),
+        );
+        Ok(response)
+    }
+
+    /// Serves webhook requests on `addr` until `shutdown` completes, passing every verified event
+    /// to `handler`. Validation pings are answered automatically.
+    ///
+    /// Each event's handler runs in a task of its own, so deliveries are acknowledged without
+    /// waiting for it and a slow handler doesn't make Toggl time out and retry.
+    #[cfg(feature = "webhooks")]
+    pub async fn serve<F, Fut>(
+        self,
+        addr: SocketAddr,
+        handler: F,
+        shutdown: impl Future<Output = ()>,
+    ) -> Result<(), Error>
+    where
+        F: Fn(Event) -> Fut + Clone + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let make_service = make_service_fn(move |_| {
+            let receiver = self.clone();
+            let handler = handler.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    let receiver = receiver.clone();
+                    let handler = handler.clone();
+                    async move { receiver.handle(request, handler).await }
+                }))
+            }
+        });
+        Server::try_bind(&addr)?
+            .serve(make_service)
+            .with_graceful_shutdown(shutdown)
+            .await?;
+        Ok(())
+    }
+}
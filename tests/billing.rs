@@ -0,0 +1,207 @@
+mod common;
+
+use chrono::Duration;
+use togglrust::billing::{Calculator, Rounding, RoundingRule};
+use togglrust::endpoints::{get_project_users, get_workspace_users};
+
+#[tokio::test]
+async fn project_user_rates_override_the_project_rate() {
+    let client = common::replaying_client();
+    let workspace = client.get_workspaces(Default::default()).await.unwrap().0;
+    let projects = client.get_projects(Default::default()).await.unwrap().0;
+    let mut entries = client.get_time_entries(Default::default()).await.unwrap().0;
+    let project_users = client
+        .get_project_users(
+            get_project_users::PathParams {
+                workspace_id: workspace[0].id,
+            },
+            get_project_users::RequestBody {
+                project_ids: Some(vec![projects[0].id]),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap()
+        .0;
+
+    let calculator = Calculator::new(&workspace[0], &projects);
+    let amount = calculator.entry_amount(&entries[1]).unwrap();
+    assert_eq!(amount.rate, 95.0);
+    assert_eq!(amount.amount, 95.0);
+
+    let calculator = calculator.with_project_users(&project_users);
+    let amount = calculator.entry_amount(&entries[1]).unwrap();
+    assert_eq!(amount.rate, 120.0);
+    assert_eq!(amount.amount, 120.0);
+
+    // Members without a rate of their own are billed at the project's
+    entries[1].user_id = project_users[1].user_id;
+    assert_eq!(calculator.entry_amount(&entries[1]).unwrap().rate, 95.0);
+}
+
+#[tokio::test]
+async fn workspace_user_rates_apply_before_the_workspace_default() {
+    let client = common::replaying_client();
+    let workspace = client.get_workspaces(Default::default()).await.unwrap().0;
+    let mut projects = client.get_projects(Default::default()).await.unwrap().0;
+    let mut entries = client.get_time_entries(Default::default()).await.unwrap().0;
+    let workspace_users = client
+        .get_workspace_users(get_workspace_users::PathParams {
+            workspace_id: workspace[0].id,
+        })
+        .await
+        .unwrap()
+        .0;
+
+    projects[0].rate = None;
+    entries[1].user_id = workspace_users[0].uid;
+    let calculator = Calculator::new(&workspace[0], &projects);
+    assert_eq!(calculator.entry_amount(&entries[1]).unwrap().rate, 85.0);
+
+    let calculator = calculator.with_workspace_users(&workspace_users);
+    let amount = calculator.entry_amount(&entries[1]).unwrap();
+    assert_eq!(amount.rate, 95.0);
+    assert_eq!(amount.amount, 95.0);
+
+    // Members without a rate of their own are billed at the workspace default
+    entries[1].user_id = workspace_users[1].uid;
+    assert_eq!(calculator.entry_amount(&entries[1]).unwrap().rate, 85.0);
+
+    // A project rate still comes first
+    projects[0].rate = Some(100.0);
+    let calculator =
+        Calculator::new(&workspace[0], &projects).with_workspace_users(&workspace_users);
+    entries[1].user_id = workspace_users[0].uid;
+    assert_eq!(calculator.entry_amount(&entries[1]).unwrap().rate, 100.0);
+}
+
+fn rounded(mode: Rounding, minutes: i64, tracked: Duration) -> Duration {
+    RoundingRule { mode, minutes }.apply(tracked)
+}
+
+#[test]
+fn rounds_to_minutes() {
+    let tracked = Duration::minutes(37) + Duration::seconds(20);
+    assert_eq!(rounded(Rounding::Down, 1, tracked), Duration::minutes(37));
+    assert_eq!(
+        rounded(Rounding::Nearest, 1, tracked),
+        Duration::minutes(37)
+    );
+    assert_eq!(rounded(Rounding::Up, 1, tracked), Duration::minutes(38));
+
+    assert_eq!(rounded(Rounding::Down, 15, tracked), Duration::minutes(30));
+    assert_eq!(
+        rounded(Rounding::Nearest, 15, tracked),
+        Duration::minutes(30)
+    );
+    assert_eq!(rounded(Rounding::Up, 15, tracked), Duration::minutes(45));
+    let tracked = Duration::minutes(38);
+    assert_eq!(
+        rounded(Rounding::Nearest, 15, tracked),
+        Duration::minutes(45)
+    );
+
+    // Halfway rounds up, exact multiples stay as they are
+    let halfway = Duration::minutes(7) + Duration::seconds(30);
+    assert_eq!(
+        rounded(Rounding::Nearest, 15, halfway),
+        Duration::minutes(15)
+    );
+    for mode in [Rounding::Down, Rounding::Nearest, Rounding::Up] {
+        assert_eq!(
+            rounded(mode, 15, Duration::minutes(45)),
+            Duration::minutes(45)
+        );
+    }
+}
+
+#[test]
+fn rounds_to_hours() {
+    let tracked = Duration::minutes(89);
+    assert_eq!(rounded(Rounding::Down, 60, tracked), Duration::hours(1));
+    assert_eq!(rounded(Rounding::Nearest, 60, tracked), Duration::hours(1));
+    assert_eq!(rounded(Rounding::Up, 60, tracked), Duration::hours(2));
+
+    let tracked = Duration::minutes(90);
+    assert_eq!(rounded(Rounding::Down, 60, tracked), Duration::hours(1));
+    assert_eq!(rounded(Rounding::Nearest, 60, tracked), Duration::hours(2));
+    assert_eq!(rounded(Rounding::Up, 60, tracked), Duration::hours(2));
+
+    assert_eq!(
+        rounded(Rounding::Down, 60, Duration::minutes(59)),
+        Duration::zero()
+    );
+    assert_eq!(
+        rounded(Rounding::Up, 60, Duration::seconds(1)),
+        Duration::hours(1)
+    );
+}
+
+#[tokio::test]
+async fn applies_the_workspace_rounding() {
+    let client = common::replaying_client();
+    let mut workspace = client.get_workspaces(Default::default()).await.unwrap().0;
+    let projects = client.get_projects(Default::default()).await.unwrap().0;
+    let mut entries = client.get_time_entries(Default::default()).await.unwrap().0;
+    entries[1].duration = Duration::minutes(50);
+
+    // The recorded workspace rounds up to 15 minutes
+    let amount = Calculator::new(&workspace[0], &projects)
+        .entry_amount(&entries[1])
+        .unwrap();
+    assert_eq!(amount.duration, Duration::hours(1));
+    assert_eq!(amount.amount, 95.0);
+
+    let rule = RoundingRule {
+        mode: Rounding::Down,
+        minutes: 60,
+    };
+    let amount = Calculator::new(&workspace[0], &projects)
+        .with_rounding(Some(rule))
+        .entry_amount(&entries[1])
+        .unwrap();
+    assert_eq!((amount.duration, amount.amount), (Duration::zero(), 0.0));
+
+    let amount = Calculator::new(&workspace[0], &projects)
+        .with_rounding(None)
+        .entry_amount(&entries[1])
+        .unwrap();
+    assert_eq!(amount.duration, Duration::minutes(50));
+
+    workspace[0].rounding_minutes = 0;
+    assert_eq!(RoundingRule::from_workspace(&workspace[0]), None);
+    workspace[0].rounding_minutes = 60;
+    workspace[0].rounding = -1;
+    assert_eq!(RoundingRule::from_workspace(&workspace[0]), Some(rule));
+}
+
+#[tokio::test]
+async fn fixed_fee_projects_bill_the_fee() {
+    let client = common::replaying_client();
+    let workspace = client.get_workspaces(Default::default()).await.unwrap().0;
+    let mut projects = client.get_projects(Default::default()).await.unwrap().0;
+    let entries = client.get_time_entries(Default::default()).await.unwrap().0;
+    projects[0].fixed_fee = Some(1500.0);
+
+    let calculator = Calculator::new(&workspace[0], &projects);
+    let amount = calculator.entry_amount(&entries[1]).unwrap();
+    assert_eq!((amount.duration, amount.amount), (Duration::hours(1), 0.0));
+
+    let mut more = entries[1].clone();
+    more.id += 1;
+    more.start += Duration::days(1);
+    let entries = [entries[0].clone(), entries[1].clone(), more];
+    let [total] = &calculator.project_amounts(&entries)[..] else {
+        panic!("expected a single project");
+    };
+    assert_eq!(total.project_id, Some(projects[0].id));
+    assert!(total.fixed_fee);
+    assert_eq!((total.duration, total.amount), (Duration::hours(2), 1500.0));
+    assert_eq!(
+        calculator.totals_by_currency(&entries).get("EUR"),
+        Some(&1500.0)
+    );
+
+    // The running entry isn't billed at all
+    assert!(calculator.entry_amount(&entries[0]).is_none());
+}
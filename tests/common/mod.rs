@@ -0,0 +1,36 @@
+//! Helpers shared by the integration tests, all built on the recorded fixtures
+#![allow(dead_code)]
+
+use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
+use togglrust::client::Client;
+use togglrust::fixtures::{Fixture, Replayer};
+use togglrust::models::TimeEntry;
+
+pub fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+/// The response body of a single fixture
+pub fn fixture_body(name: &str) -> serde_json::Value {
+    Fixture::load(&fixtures_dir().join(name))
+        .unwrap()
+        .response
+        .body
+}
+
+/// The response body of a single fixture, deserialized
+pub fn recorded<T: DeserializeOwned>(name: &str) -> T {
+    serde_json::from_value(fixture_body(name)).unwrap()
+}
+
+/// A client answering from every recorded fixture
+pub fn replaying_client() -> Client {
+    Client::replaying(Replayer::load(&fixtures_dir()).unwrap())
+}
+
+/// One of the recorded time entries: the first is running, the second a finished hour
+pub fn recorded_entry(index: usize) -> TimeEntry {
+    let entries: Vec<TimeEntry> = recorded("0008-get-api_v9_me_time_entries.json");
+    entries.into_iter().nth(index).unwrap()
+}
@@ -0,0 +1,131 @@
+use std::path::PathBuf;
+use togglrust::credentials::{CredentialStore, Error, KdfParams};
+use togglrust::secret::Secret;
+
+const TOKEN: &str = "1971800d4d82861d8f2c1651fea4d212";
+
+fn store_path(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "togglrust-credentials-{}-{}",
+        name,
+        std::process::id()
+    ));
+    std::fs::remove_dir_all(&dir).ok();
+    dir.join("credentials.json")
+}
+
+/// Cheap enough to keep the tests fast
+fn open(path: &PathBuf) -> CredentialStore {
+    let mut store = CredentialStore::open(path).unwrap();
+    store.kdf = KdfParams {
+        memory_kib: 64,
+        iterations: 1,
+        lanes: 1,
+    };
+    store
+}
+
+#[test]
+fn saves_and_loads_tokens() {
+    let path = store_path("round-trip");
+    let passphrase = Secret::from("correct horse battery staple");
+    let mut store = open(&path);
+    store
+        .save("work", &Secret::from(TOKEN), &passphrase)
+        .unwrap();
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    assert!(!contents.contains(TOKEN));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    let store = open(&path);
+    assert_eq!(store.profiles().collect::<Vec<_>>(), vec!["work"]);
+    assert_eq!(store.load("work", &passphrase).unwrap().expose(), TOKEN);
+    assert!(matches!(
+        store.load("work", &Secret::from("guess")),
+        Err(Error::WrongPassphrase(_))
+    ));
+    assert!(matches!(
+        store.load("home", &passphrase),
+        Err(Error::UnknownProfile(_))
+    ));
+}
+
+#[test]
+fn entries_are_bound_to_their_profile() {
+    let path = store_path("swap");
+    let passphrase = Secret::from("correct horse battery staple");
+    open(&path)
+        .save("work", &Secret::from(TOKEN), &passphrase)
+        .unwrap();
+
+    let mut json: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+    json["profiles"]["home"] = json["profiles"]["work"].clone();
+    std::fs::write(&path, serde_json::to_vec(&json).unwrap()).unwrap();
+
+    let store = open(&path);
+    assert!(store.load("work", &passphrase).is_ok());
+    assert!(matches!(
+        store.load("home", &passphrase),
+        Err(Error::WrongPassphrase(_))
+    ));
+}
+
+#[test]
+fn removes_profiles() {
+    let path = store_path("remove");
+    let passphrase = Secret::from("correct horse battery staple");
+    let mut store = open(&path);
+    store
+        .save("work", &Secret::from(TOKEN), &passphrase)
+        .unwrap();
+    store
+        .save("home", &Secret::from("another token"), &passphrase)
+        .unwrap();
+
+    assert!(store.remove("work").unwrap());
+    assert!(!store.remove("work").unwrap());
+    let store = open(&path);
+    assert_eq!(store.profiles().collect::<Vec<_>>(), vec!["home"]);
+    assert_eq!(
+        store.load("home", &passphrase).unwrap().expose(),
+        "another token"
+    );
+}
+
+#[test]
+fn rejects_tampered_kdf_params() {
+    let path = store_path("kdf");
+    let passphrase = Secret::from("correct horse battery staple");
+    open(&path)
+        .save("work", &Secret::from(TOKEN), &passphrase)
+        .unwrap();
+
+    for (key, value) in [("memory_kib", u32::MAX), ("lanes", 0), ("iterations", 0)] {
+        let mut json: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        json["profiles"]["home"] = json["profiles"]["work"].clone();
+        json["profiles"]["home"]["kdf"][key] = value.into();
+        std::fs::write(&path, serde_json::to_vec(&json).unwrap()).unwrap();
+
+        let store = open(&path);
+        assert!(store.load("work", &passphrase).is_ok());
+        assert!(matches!(
+            store.load("home", &passphrase),
+            Err(Error::Malformed(_))
+        ));
+    }
+
+    let mut store = open(&path);
+    store.kdf.lanes = 0;
+    assert!(matches!(
+        store.save("work", &Secret::from(TOKEN), &passphrase),
+        Err(Error::KdfParams(_))
+    ));
+}
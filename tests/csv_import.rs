@@ -0,0 +1,317 @@
+mod common;
+
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+use common::{recorded, recorded_entry};
+use togglrust::csv_import::{
+    ColumnMapping, DuplicateOf, Error, ImportContext, ImportPlan, RowError, RowOutcome,
+};
+use togglrust::endpoints::post_time_entries;
+use togglrust::models::{Client, Project, Tag, TimeEntry, Workspace};
+use togglrust::validation::Violation;
+
+const PROJECT_ID: i64 = 198765432;
+const TAG_ID: i64 = 15432109;
+
+/// The recorded workspace, which requires a description and a project
+struct Data {
+    workspace: Workspace,
+    projects: Vec<Project>,
+    clients: Vec<Client>,
+    tags: Vec<Tag>,
+}
+
+impl Data {
+    fn load() -> Self {
+        let workspaces: Vec<Workspace> = recorded("0011-get-api_v9_me_workspaces.json");
+        Self {
+            workspace: workspaces.into_iter().next().unwrap(),
+            projects: recorded("0005-get-api_v9_me_projects.json"),
+            clients: recorded("0001-get-api_v9_me_clients.json"),
+            tags: recorded("0006-get-api_v9_me_tags.json"),
+        }
+    }
+
+    fn context<'a>(&'a self, timezone: Tz, existing: &'a [TimeEntry]) -> ImportContext<'a> {
+        ImportContext {
+            workspace: &self.workspace,
+            timezone,
+            projects: &self.projects,
+            clients: &self.clients,
+            tags: &self.tags,
+            existing,
+        }
+    }
+}
+
+fn plan(csv: &str, mapping: &ColumnMapping, timezone: Tz) -> ImportPlan {
+    let data = Data::load();
+    ImportPlan::new(csv.as_bytes(), mapping, &data.context(timezone, &[])).unwrap()
+}
+
+fn created(plan: &ImportPlan) -> Vec<&post_time_entries::RequestBody> {
+    plan.rows
+        .iter()
+        .map(|row| match &row.outcome {
+            RowOutcome::Create(body) => body,
+            outcome => panic!("line {}: expected an entry, got {:?}", row.line, outcome),
+        })
+        .collect()
+}
+
+fn errors(plan: &ImportPlan) -> Vec<Vec<RowError>> {
+    plan.rows
+        .iter()
+        .map(|row| match &row.outcome {
+            RowOutcome::Invalid(errors) => errors.clone(),
+            outcome => panic!("line {}: expected errors, got {:?}", row.line, outcome),
+        })
+        .collect()
+}
+
+fn utc(value: &str) -> DateTime<Utc> {
+    value.parse().unwrap()
+}
+
+#[test]
+fn maps_default_columns() {
+    let plan = plan(
+        "Start,Stop,Description,Project,Client,Tags,Billable\n\
+         2024-02-05T09:00:00Z,2024-02-05T10:30:00Z,Landing page,website relaunch,Globex,Meetings,yes\n",
+        &ColumnMapping::default(),
+        Tz::UTC,
+    );
+    let [body] = created(&plan)[..] else {
+        panic!("expected one entry");
+    };
+    assert_eq!(body.workspace_id, 4567890);
+    assert_eq!(body.start, utc("2024-02-05T09:00:00Z"));
+    assert_eq!(body.stop, Some(utc("2024-02-05T10:30:00Z")));
+    assert_eq!(body.duration, Some(Duration::minutes(90)));
+    assert_eq!(body.description.as_deref(), Some("Landing page"));
+    assert_eq!(body.project_id, Some(PROJECT_ID));
+    assert_eq!(body.tag_ids, Some(vec![TAG_ID]));
+    assert_eq!(body.billable, Some(true));
+}
+
+#[test]
+fn maps_custom_columns() {
+    let mapping = ColumnMapping {
+        start: String::from("Date"),
+        start_time: Some(String::from("From")),
+        stop: None,
+        duration: Some(String::from("Hours")),
+        description: Some(String::from("Task")),
+        project: Some(String::from("Job")),
+        tags: Some(String::from("Labels")),
+        tag_separator: ';',
+        datetime_format: Some(String::from("%d.%m.%Y %H:%M")),
+        ..Default::default()
+    };
+    let plan = plan(
+        "Date,From,Hours,Task,Job,Labels,Stop\n\
+         05.02.2024,09:15,1.5,Landing page,Website relaunch,meetings; ,ignored\n",
+        &mapping,
+        Tz::UTC,
+    );
+    let [body] = created(&plan)[..] else {
+        panic!("expected one entry");
+    };
+    assert_eq!(body.start, utc("2024-02-05T09:15:00Z"));
+    assert_eq!(body.duration, Some(Duration::minutes(90)));
+    assert_eq!(body.project_id, Some(PROJECT_ID));
+    assert_eq!(body.tag_ids, Some(vec![TAG_ID]));
+    assert_eq!(body.billable, None);
+}
+
+#[test]
+fn requires_the_start_column() {
+    let data = Data::load();
+    let err = ImportPlan::new(
+        "Date,Duration\n2024-02-05,1:00\n".as_bytes(),
+        &ColumnMapping::default(),
+        &data.context(Tz::UTC, &[]),
+    )
+    .unwrap_err();
+    assert!(matches!(err, Error::MissingColumn(column) if column == "Start"));
+}
+
+#[test]
+fn parses_durations() {
+    let plan = plan(
+        "Start,Duration,Description,Project\n\
+         2024-02-05 09:00,1:30,a,Website relaunch\n\
+         2024-02-05 11:00,0:45:30,b,Website relaunch\n\
+         2024-02-05 13:00,2.25,c,Website relaunch\n\
+         2024-02-05 16:00,\"0,5\",d,Website relaunch\n",
+        &ColumnMapping::default(),
+        Tz::UTC,
+    );
+    let durations: Vec<_> = created(&plan)
+        .iter()
+        .map(|body| body.duration.unwrap())
+        .collect();
+    assert_eq!(
+        durations,
+        [
+            Duration::minutes(90),
+            Duration::seconds(45 * 60 + 30),
+            Duration::minutes(135),
+            Duration::minutes(30),
+        ]
+    );
+}
+
+#[test]
+fn rejects_out_of_range_durations() {
+    let plan = plan(
+        "Start,Duration,Description,Project\n\
+         2024-02-05 09:00,99999999999999:00,a,Website relaunch\n\
+         2024-02-05 09:00,inf,b,Website relaunch\n\
+         2024-02-05 09:00,NaN,c,Website relaunch\n\
+         2024-02-05 09:00,1e20,d,Website relaunch\n\
+         2024-02-05 09:00,1e12,e,Website relaunch\n",
+        &ColumnMapping::default(),
+        Tz::UTC,
+    );
+    assert_eq!(
+        errors(&plan),
+        ["99999999999999:00", "inf", "NaN", "1e20", "1e12"]
+            .map(|value| vec![RowError::InvalidDuration(String::from(value))])
+    );
+}
+
+#[test]
+fn stop_times_roll_over_midnight() {
+    let plan = plan(
+        "Start,Stop,Description,Project\n\
+         2024-02-05 22:30,01:15,Deploy,Website relaunch\n\
+         2024-02-05 09:00,12:00,Review,Website relaunch\n",
+        &ColumnMapping::default(),
+        Tz::UTC,
+    );
+    let stops: Vec<_> = created(&plan).iter().map(|body| body.stop).collect();
+    assert_eq!(
+        stops,
+        [
+            Some(utc("2024-02-06T01:15:00Z")),
+            Some(utc("2024-02-05T12:00:00Z")),
+        ]
+    );
+}
+
+#[test]
+fn converts_local_times_to_utc() {
+    let berlin = plan(
+        "Start,Stop,Description,Project\n\
+         2024-02-05 09:00,10:00,Winter,Website relaunch\n\
+         2024-07-01 09:00,10:00,Summer,Website relaunch\n\
+         2024-07-01T09:00:00+02:00,2024-07-01T10:00:00+02:00,Offset,Website relaunch\n",
+        &ColumnMapping::default(),
+        Tz::Europe__Berlin,
+    );
+    let starts: Vec<_> = created(&berlin).iter().map(|body| body.start).collect();
+    assert_eq!(
+        starts,
+        [
+            utc("2024-02-05T08:00:00Z"),
+            utc("2024-07-01T07:00:00Z"),
+            utc("2024-07-01T07:00:00Z"),
+        ]
+    );
+
+    let gap_plan = plan(
+        "Start,Duration,Description,Project\n2024-03-31 02:30,1:00,Gap,Website relaunch\n",
+        &ColumnMapping::default(),
+        Tz::Europe__Berlin,
+    );
+    let gap = NaiveDate::from_ymd_opt(2024, 3, 31)
+        .unwrap()
+        .and_hms_opt(2, 30, 0)
+        .unwrap();
+    assert_eq!(
+        errors(&gap_plan),
+        [vec![RowError::NonexistentLocalTime(gap)]]
+    );
+}
+
+#[test]
+fn reports_malformed_rows() {
+    let plan = plan(
+        "Start,Stop,Duration,Description,Project,Client,Tags,Billable\n\
+         yesterday,,1:00,a,Website relaunch,,,\n\
+         2024-02-05 09:00,,,b,Website relaunch,,,\n\
+         2024-02-05 09:00,,1:xx,c,Website relaunch,,,\n\
+         2024-02-05 09:00,2024-02-05 08:00,,d,Website relaunch,,,\n\
+         2024-02-05 09:00,,1:00,e,Moonshot,,,\n\
+         2024-02-05 09:00,,1:00,f,Website relaunch,Initech,,\n\
+         2024-02-05 09:00,,1:00,g,Website relaunch,,meetings,maybe\n\
+         2024-02-05 09:00,,1:00,,,,urgent,\n",
+        &ColumnMapping::default(),
+        Tz::UTC,
+    );
+    assert_eq!(
+        errors(&plan),
+        [
+            vec![RowError::InvalidDateTime(String::from("yesterday"))],
+            vec![RowError::MissingDuration],
+            vec![RowError::InvalidDuration(String::from("1:xx"))],
+            vec![RowError::NegativeDuration],
+            vec![
+                RowError::UnknownProject(String::from("Moonshot")),
+                RowError::Constraint(Violation::MissingProject),
+            ],
+            vec![RowError::UnknownClient(String::from("Initech"))],
+            vec![RowError::InvalidBillable(String::from("maybe"))],
+            vec![
+                RowError::UnknownTag(String::from("urgent")),
+                RowError::Constraint(Violation::MissingDescription),
+                RowError::Constraint(Violation::MissingProject),
+            ],
+        ]
+    );
+    assert!(plan.has_errors());
+    assert_eq!(plan.to_create().count(), 0);
+    let lines: Vec<_> = plan.rows.iter().map(|row| row.line).collect();
+    assert_eq!(lines, [2, 3, 4, 5, 6, 7, 8, 9]);
+}
+
+#[test]
+fn detects_duplicates() {
+    let data = Data::load();
+    let mut existing = vec![recorded_entry(0)];
+    let entry = &mut existing[0];
+    entry.start = Utc.with_ymd_and_hms(2024, 2, 5, 9, 0, 0).unwrap();
+    entry.duration = Duration::hours(1);
+    entry.project_id = Some(PROJECT_ID);
+    entry.description = Some(String::from("Landing page"));
+    entry.server_deleted_at = None;
+
+    let csv = "Start,Duration,Description,Project\n\
+               2024-02-05 09:00,1:00,Landing page ,Website relaunch\n\
+               2024-02-05 11:00,1:00,Review,Website relaunch\n\
+               2024-02-05 11:00,1:00,Review,Website relaunch\n";
+    let plan = ImportPlan::new(
+        csv.as_bytes(),
+        &ColumnMapping::default(),
+        &data.context(Tz::UTC, &existing),
+    )
+    .unwrap();
+    let outcomes: Vec<_> = plan
+        .rows
+        .iter()
+        .map(|row| match &row.outcome {
+            RowOutcome::Duplicate(duplicate_of) => Some(*duplicate_of),
+            RowOutcome::Create(_) => None,
+            RowOutcome::Invalid(errors) => panic!("unexpected errors {:?}", errors),
+        })
+        .collect();
+    assert_eq!(
+        outcomes,
+        [
+            Some(DuplicateOf::TimeEntry(existing[0].id)),
+            None,
+            Some(DuplicateOf::Line(3)),
+        ]
+    );
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use chrono::{Duration, TimeZone, Utc};
+use togglrust::dashboard::status_board;
+use togglrust::endpoints::{
+    get_dashboard_all_activity, get_dashboard_most_active, get_workspace_users,
+};
+
+#[tokio::test]
+async fn joins_activity_with_projects_and_users() {
+    let client = common::replaying_client();
+    let workspace_id = 4567890;
+    let activities = client
+        .get_dashboard_all_activity(get_dashboard_all_activity::PathParams { workspace_id })
+        .await
+        .unwrap()
+        .0;
+    let most_active = client
+        .get_dashboard_most_active(get_dashboard_most_active::PathParams { workspace_id })
+        .await
+        .unwrap()
+        .0;
+    let projects = client.get_projects(Default::default()).await.unwrap().0;
+    let users = client
+        .get_workspace_users(get_workspace_users::PathParams { workspace_id })
+        .await
+        .unwrap()
+        .0;
+
+    let now = Utc.with_ymd_and_hms(2024, 2, 6, 10, 30, 0).unwrap();
+    let rows = status_board(&activities, &most_active, &projects, &users, now);
+    let users: Vec<_> = rows.iter().map(|row| row.user_id).collect();
+    assert_eq!(users, vec![1234567, 3456780, 2345678]);
+
+    let current = rows[0].current.as_ref().unwrap();
+    assert_eq!(rows[0].user_name.as_deref(), Some("Ada Lovelace"));
+    assert_eq!(current.time_entry_id, 3312349999);
+    assert_eq!(current.project_name.as_deref(), Some("Website relaunch"));
+    assert_eq!(current.elapsed, Duration::minutes(90));
+
+    // Someone who isn't in the workspace users anymore still gets a row
+    assert_eq!(rows[1].user_name, None);
+    assert_eq!(rows[1].recent, Duration::hours(10));
+    assert_eq!(rows[2].current, None);
+}
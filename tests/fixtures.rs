@@ -0,0 +1,381 @@
+mod common;
+
+use common::{fixtures_dir, replaying_client};
+use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
+use togglrust::client::{Client, Error};
+use togglrust::day_of_week::DayOfWeek;
+use togglrust::endpoints::{self, patch_organization_groups};
+use togglrust::fixtures::{Fixture, FixtureRequest, FixtureResponse, Replayer};
+use togglrust::models::UnknownFields;
+
+fn fixtures() -> Vec<(PathBuf, Fixture)> {
+    let mut paths: Vec<_> = std::fs::read_dir(fixtures_dir())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| {
+            let fixture = Fixture::load(&path).unwrap();
+            (path, fixture)
+        })
+        .collect()
+}
+
+fn check<T: DeserializeOwned + UnknownFields>(path: &Path, fixture: &Fixture) {
+    let body = match serde_json::from_value::<T>(fixture.response.body.clone()) {
+        Ok(body) => body,
+        Err(err) => panic!(
+            "{} doesn't deserialize into {}: {}",
+            path.display(),
+            std::any::type_name::<T>(),
+            err
+        ),
+    };
+    let mut unknown = Vec::new();
+    body.unknown_fields("", &mut unknown);
+    assert!(
+        unknown.is_empty(),
+        "{} has fields {} doesn't know about: {:?}",
+        path.display(),
+        std::any::type_name::<T>(),
+        unknown
+    );
+}
+
+#[test]
+fn every_fixture_deserializes_into_its_response_body() {
+    for (path, fixture) in fixtures() {
+        let segments: Vec<_> = fixture.request.path.trim_matches('/').split('/').collect();
+        match (fixture.request.method.as_str(), segments.as_slice()) {
+            ("GET", ["api", "v9", "me"]) => {
+                check::<endpoints::get_me::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "me", "clients"]) => {
+                check::<endpoints::get_clients::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "me", "features"]) => {
+                check::<endpoints::get_features::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "me", "location"]) => {
+                check::<endpoints::get_location::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "me", "organizations"]) => {
+                check::<endpoints::get_orgs::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "me", "preferences"]) => {
+                check::<endpoints::get_preferences::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "me", "projects"]) => {
+                check::<endpoints::get_projects::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "me", "tags"]) => {
+                check::<endpoints::get_tags::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "me", "tasks"]) => {
+                check::<endpoints::get_tasks::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "me", "time_entries"]) => {
+                check::<endpoints::get_time_entries::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "me", "time_entries", "current"]) => {
+                check::<endpoints::get_current_time_entry::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "me", "track_reminders"]) => {
+                check::<endpoints::get_track_reminders::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "me", "workspaces"]) => {
+                check::<endpoints::get_workspaces::ResponseBody>(&path, &fixture)
+            }
+            ("POST", ["api", "v9", "workspaces", _, "time_entries"]) => {
+                check::<endpoints::post_time_entries::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "organizations", _, "users"]) => {
+                check::<endpoints::get_organization_users::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "organizations", _, "groups"]) => {
+                check::<endpoints::get_organization_groups::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "workspaces", _, "workspace_users"]) => {
+                check::<endpoints::get_workspace_users::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "workspaces", _, "project_users"]) => {
+                check::<endpoints::get_project_users::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "workspaces", _, "preferences"]) => {
+                check::<endpoints::get_workspace_preferences::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "workspaces", _, "dashboard", "all_activity"]) => {
+                check::<endpoints::get_dashboard_all_activity::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "workspaces", _, "dashboard", "most_active"]) => {
+                check::<endpoints::get_dashboard_most_active::ResponseBody>(&path, &fixture)
+            }
+            ("PUT", ["api", "v9", "me"]) => {
+                check::<endpoints::put_me::ResponseBody>(&path, &fixture)
+            }
+            ("PUT", ["api", "v9", "workspaces", _]) => {
+                check::<endpoints::put_workspaces::ResponseBody>(&path, &fixture)
+            }
+            ("PUT", ["api", "v9", "workspaces", _, "time_entries", _]) => {
+                check::<endpoints::put_time_entries::ResponseBody>(&path, &fixture)
+            }
+            ("POST", ["api", "v9", "workspaces", _, "clients"]) => {
+                check::<endpoints::post_clients::ResponseBody>(&path, &fixture)
+            }
+            ("POST", ["api", "v9", "workspaces", _, "projects"]) => {
+                check::<endpoints::post_projects::ResponseBody>(&path, &fixture)
+            }
+            ("POST", ["api", "v9", "workspaces", _, "projects", _, "tasks"]) => {
+                check::<endpoints::post_tasks::ResponseBody>(&path, &fixture)
+            }
+            ("POST", ["api", "v9", "workspaces", _, "tags"]) => {
+                check::<endpoints::post_tags::ResponseBody>(&path, &fixture)
+            }
+            ("POST", ["api", "v9", "organizations", _, "invitation"]) => {
+                check::<endpoints::post_organization_invitation::ResponseBody>(&path, &fixture)
+            }
+            ("POST", ["api", "v9", "organizations", _, "groups"]) => {
+                check::<endpoints::post_organization_groups::ResponseBody>(&path, &fixture)
+            }
+            ("PUT", ["api", "v9", "organizations", _, "groups", _]) => {
+                check::<endpoints::put_organization_groups::ResponseBody>(&path, &fixture)
+            }
+            ("PATCH", ["api", "v9", "organizations", _, "groups", _]) => {
+                check::<endpoints::patch_organization_groups::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "workspaces", _, "groups"]) => {
+                check::<endpoints::get_workspace_groups::ResponseBody>(&path, &fixture)
+            }
+            ("PUT", ["api", "v9", "workspaces", _, "workspace_users", _]) => {
+                check::<endpoints::put_workspace_users::ResponseBody>(&path, &fixture)
+            }
+            ("POST", ["api", "v9", "workspaces", _, "project_users"]) => {
+                check::<endpoints::post_project_users::ResponseBody>(&path, &fixture)
+            }
+            ("PUT", ["api", "v9", "workspaces", _, "project_users", _]) => {
+                check::<endpoints::put_project_users::ResponseBody>(&path, &fixture)
+            }
+            ("PATCH", ["api", "v9", "workspaces", _, "project_users", _]) => {
+                check::<endpoints::patch_project_users::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["api", "v9", "workspaces", _, "track_reminders"]) => {
+                check::<endpoints::get_workspace_track_reminders::ResponseBody>(&path, &fixture)
+            }
+            ("POST", ["api", "v9", "workspaces", _, "track_reminders"]) => {
+                check::<endpoints::post_track_reminders::ResponseBody>(&path, &fixture)
+            }
+            ("PUT", ["api", "v9", "workspaces", _, "track_reminders", _]) => {
+                check::<endpoints::put_track_reminders::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["reports", "api", "v3", "workspace", _, "saved"]) => {
+                check::<endpoints::get_saved_reports::ResponseBody>(&path, &fixture)
+            }
+            ("POST", ["reports", "api", "v3", "workspace", _, "search", "time_entries"]) => {
+                check::<endpoints::post_reports_search_time_entries::ResponseBody>(&path, &fixture)
+            }
+            ("POST", ["reports", "api", "v3", "workspace", _, "saved"]) => {
+                check::<endpoints::post_saved_reports::ResponseBody>(&path, &fixture)
+            }
+            ("PUT", ["reports", "api", "v3", "workspace", _, "saved", _]) => {
+                check::<endpoints::put_saved_reports::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["reports", "api", "v3", "shared", _]) => {
+                check::<endpoints::get_shared_report::ResponseBody>(&path, &fixture)
+            }
+            ("POST", ["reports", "api", "v3", "shared", _]) => {
+                check::<endpoints::post_shared_report::ResponseBody>(&path, &fixture)
+            }
+            ("POST", ["reports", "api", "v3", "workspace", _, _, "time_entries.csv"]) => assert!(
+                fixture.response.body.is_string(),
+                "{} isn't a CSV export",
+                path.display()
+            ),
+            ("POST", ["reports", "api", "v3", "workspace", _, _, "time_entries.pdf"]) => assert!(
+                fixture
+                    .response
+                    .body_base64
+                    .as_deref()
+                    .is_some_and(|pdf| pdf.starts_with("JVBERi0")),
+                "{} isn't a PDF export",
+                path.display()
+            ),
+            ("GET", ["webhooks", "api", "v1", "subscriptions", _]) => {
+                check::<endpoints::get_webhook_subscriptions::ResponseBody>(&path, &fixture)
+            }
+            ("GET", ["webhooks", "api", "v1", "event_filters"]) => {
+                check::<endpoints::get_webhook_event_filters::ResponseBody>(&path, &fixture)
+            }
+            ("POST", ["webhooks", "api", "v1", "subscriptions", _]) => {
+                check::<endpoints::post_webhook_subscriptions::ResponseBody>(&path, &fixture)
+            }
+            ("PUT", ["webhooks", "api", "v1", "subscriptions", _, _]) => {
+                check::<endpoints::put_webhook_subscriptions::ResponseBody>(&path, &fixture)
+            }
+            ("PATCH", ["webhooks", "api", "v1", "subscriptions", _, _]) => {
+                check::<endpoints::patch_webhook_subscriptions::ResponseBody>(&path, &fixture)
+            }
+            ("DELETE", ["webhooks", "api", "v1", "subscriptions", _, _]) => {
+                check::<endpoints::delete_webhook_subscriptions::ResponseBody>(&path, &fixture)
+            }
+            _ => panic!("{} has no known response body", path.display()),
+        }
+    }
+}
+
+#[test]
+fn fixtures_are_redacted() {
+    for (path, fixture) in fixtures() {
+        let mut redacted = fixture.response.body.clone();
+        togglrust::fixtures::redact(&mut redacted);
+        assert_eq!(
+            redacted,
+            fixture.response.body,
+            "{} contains unredacted secrets",
+            path.display()
+        );
+    }
+}
+
+#[tokio::test]
+async fn client_replays_fixtures() {
+    let client = replaying_client();
+
+    let me = client.get_me().await.unwrap();
+    assert_eq!(me.timezone, "Europe/Berlin");
+    let current = client.get_current_time_entry().await.unwrap().0.unwrap();
+    assert_eq!(current.stop, None);
+    let entries = client.get_time_entries(Default::default()).await.unwrap();
+    assert_eq!(entries.0.len(), 2);
+    let preferences = client.get_preferences().await.unwrap();
+    assert_eq!(preferences.0.beginning_of_week, Some(DayOfWeek::Monday));
+    let params = endpoints::get_workspace_preferences::PathParams {
+        workspace_id: 4567890,
+    };
+    let preferences = client.get_workspace_preferences(params).await.unwrap();
+    assert_eq!(
+        preferences.0.report_locked_at.as_deref(),
+        Some("2024-01-31")
+    );
+
+    let body = endpoints::put_me::RequestBody {
+        fullname: Some(String::from("Dana Q. Example")),
+        ..Default::default()
+    };
+    let me = client.put_me(body).await.unwrap();
+    assert_eq!(me.fullname, "Dana Q. Example");
+    let options = me.options.unwrap();
+    assert_eq!(options.beginning_of_week, Some(DayOfWeek::Monday));
+    assert_eq!(options.alpha_features[0].code, "new_reports");
+
+    let params = endpoints::get_projects::RequestBody {
+        include_archived: Some(true),
+        ..Default::default()
+    };
+    let err = client.get_projects(params).await.unwrap_err();
+    assert!(matches!(err, Error::MissingFixture { .. }));
+}
+
+#[tokio::test]
+async fn tolerates_new_fields_and_nulls() {
+    let (_, mut fixture) = fixtures()
+        .into_iter()
+        .find(|(_, fixture)| fixture.request.path == "/api/v9/me/time_entries")
+        .unwrap();
+    let entry = &mut fixture.response.body[0];
+    entry["tag_ids"] = serde_json::Value::Null;
+    entry["tags"] = serde_json::Value::Null;
+    entry["expense_ids"] = serde_json::json!([1, 2]);
+
+    let client = Client::replaying(Replayer::new(vec![fixture.clone()]));
+    let entries = client.get_time_entries(Default::default()).await.unwrap();
+    assert!(entries.0[0].tags.is_empty());
+    assert_eq!(entries.0[0].extra["expense_ids"], serde_json::json!([1, 2]));
+    assert!(client.take_drift().is_empty());
+
+    let client = Client::replaying(Replayer::new(vec![fixture])).strict();
+    client.get_time_entries(Default::default()).await.unwrap();
+    let drift = client.take_drift();
+    assert_eq!(drift.len(), 1);
+    assert_eq!(drift[0].path, "/api/v9/me/time_entries");
+    assert_eq!(drift[0].fields, vec![String::from("[0].expense_ids")]);
+    assert!(client.take_drift().is_empty());
+}
+
+#[tokio::test]
+async fn client_replays_user_management() {
+    let (_, groups) = fixtures()
+        .into_iter()
+        .find(|(_, fixture)| fixture.request.path == "/api/v9/organizations/3456789/groups")
+        .unwrap();
+    let patched = Fixture {
+        request: FixtureRequest {
+            method: String::from("PATCH"),
+            path: String::from("/api/v9/organizations/3456789/groups/7002"),
+            query: None,
+            body: None,
+        },
+        response: FixtureResponse {
+            status: 200,
+            body: groups.response.body[1].clone(),
+            body_base64: None,
+        },
+    };
+    let mut replayed: Vec<_> = fixtures().into_iter().map(|(_, fixture)| fixture).collect();
+    replayed.push(patched);
+    let client = Client::replaying(Replayer::new(replayed));
+
+    let users = client
+        .get_organization_users(endpoints::get_organization_users::PathParams {
+            organization_id: 3456789,
+        })
+        .await
+        .unwrap();
+    let pending: Vec<_> = users.0.iter().filter(|user| !user.joined).collect();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].workspaces[0].workspace_id, 4567890);
+
+    let members = client
+        .get_workspace_users(endpoints::get_workspace_users::PathParams {
+            workspace_id: 4567890,
+        })
+        .await
+        .unwrap();
+    assert_eq!(members.0[0].rate, Some(95.0));
+    assert!(members.0[1].group_ids.is_empty());
+
+    let groups = client
+        .get_organization_groups(endpoints::get_organization_groups::PathParams {
+            organization_id: 3456789,
+        })
+        .await
+        .unwrap();
+    let new_hires = groups
+        .0
+        .iter()
+        .find(|group| group.name == "New hires")
+        .unwrap();
+    assert!(new_hires.users.is_empty());
+
+    let operations = vec![patch_organization_groups::Operation::add_users(vec![
+        pending[0].user_id,
+    ])];
+    assert_eq!(
+        serde_json::to_value(&operations).unwrap(),
+        serde_json::json!([{"op": "add", "path": "/users", "value": [2345678]}])
+    );
+    let group = client
+        .patch_organization_groups(
+            patch_organization_groups::PathParams {
+                organization_id: 3456789,
+                group_id: new_hires.group_id,
+            },
+            patch_organization_groups::RequestBody(operations),
+        )
+        .await
+        .unwrap();
+    assert_eq!(group.0.group_id, 7002);
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use chrono::{DateTime, Utc};
+use common::{recorded, recorded_entry};
+use togglrust::ical::to_icalendar;
+use togglrust::models::{Client, Project, TimeEntry};
+
+fn utc(value: &str) -> DateTime<Utc> {
+    value.parse().unwrap()
+}
+
+fn export(entries: &[TimeEntry], now: DateTime<Utc>) -> String {
+    let projects: Vec<Project> = recorded("0005-get-api_v9_me_projects.json");
+    let clients: Vec<Client> = recorded("0001-get-api_v9_me_clients.json");
+    to_icalendar(entries, &projects, &clients, now)
+}
+
+/// Content lines with folding undone
+fn unfolded(calendar: &str) -> Vec<String> {
+    calendar
+        .replace("\r\n ", "")
+        .split_terminator("\r\n")
+        .map(str::to_string)
+        .collect()
+}
+
+fn property<'a>(lines: &'a [String], name: &str) -> Vec<&'a str> {
+    lines
+        .iter()
+        .filter_map(|line| line.strip_prefix(name)?.strip_prefix(':'))
+        .collect()
+}
+
+#[test]
+fn escapes_text_values() {
+    let mut entry = recorded_entry(1);
+    entry.description = Some(String::from("C:\\temp; backup, then\r\nverify"));
+    entry.tags = Vec::new();
+    let lines = unfolded(&export(&[entry], Utc::now()));
+    assert_eq!(
+        property(&lines, "DESCRIPTION"),
+        [r"C:\\temp\; backup\, then\nverify"]
+    );
+    assert_eq!(
+        property(&lines, "SUMMARY"),
+        [r"C:\\temp\; backup\, then\nverify [Website relaunch / Globex]"]
+    );
+}
+
+#[test]
+fn folds_lines_at_75_octets() {
+    let mut entry = recorded_entry(1);
+    let description = "Überprüfung der Änderungen für München ".repeat(5);
+    entry.description = Some(description.clone());
+    let calendar = export(&[entry], Utc::now());
+
+    assert!(calendar.ends_with("\r\n"));
+    let physical: Vec<&str> = calendar.split_terminator("\r\n").collect();
+    assert!(physical.iter().any(|line| line.starts_with(' ')));
+    for line in physical {
+        assert!(line.len() <= 75, "{} octets: {:?}", line.len(), line);
+    }
+    let lines = unfolded(&calendar);
+    assert_eq!(property(&lines, "DESCRIPTION"), [description.as_str()]);
+}
+
+#[test]
+fn uids_are_stable() {
+    let entries = [recorded_entry(1)];
+    let first = unfolded(&export(&entries, utc("2024-02-05T18:00:00Z")));
+    let second = unfolded(&export(&entries, utc("2024-02-06T18:00:00Z")));
+    assert_eq!(
+        property(&first, "UID"),
+        ["time-entry-3312345678@track.toggl.com"]
+    );
+    assert_eq!(first, second);
+}
+
+#[test]
+fn running_entries_end_now() {
+    let now = utc("2024-02-05T18:00:00Z");
+    let mut running = recorded_entry(0);
+    running.server_deleted_at = None;
+    assert!(running.stop.is_none());
+    let lines = unfolded(&export(&[running, recorded_entry(1)], now));
+    assert_eq!(
+        property(&lines, "DTEND")[0],
+        now.format("%Y%m%dT%H%M%SZ").to_string()
+    );
+    assert_eq!(property(&lines, "STATUS"), ["TENTATIVE", "CONFIRMED"]);
+}
+
+#[test]
+fn categories_come_from_the_project_client_and_tags() {
+    let mut entry = recorded_entry(1);
+    entry.tags = vec![String::from("meetings"), String::from("q1, q2")];
+    let lines = unfolded(&export(&[entry], Utc::now()));
+    assert_eq!(
+        property(&lines, "CATEGORIES"),
+        [r"Website relaunch,Globex,meetings,q1\, q2"]
+    );
+}
@@ -0,0 +1,207 @@
+mod common;
+
+use chrono::{Duration, NaiveDate};
+use chrono_tz::Tz;
+use common::{recorded, recorded_entry};
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use togglrust::invoice::{Error, Invoice, InvoiceInput, InvoiceNumbering};
+use togglrust::models::{Client, Project, TimeEntry, Workspace};
+
+const PROJECT_ID: i64 = 198765432;
+
+/// The recorded workspace, which bills at 95 EUR an hour, rounded up to 15 minutes
+struct Data {
+    workspace: Workspace,
+    client: Client,
+    projects: Vec<Project>,
+}
+
+impl Data {
+    fn load() -> Self {
+        let workspaces: Vec<Workspace> = recorded("0011-get-api_v9_me_workspaces.json");
+        let clients: Vec<Client> = recorded("0001-get-api_v9_me_clients.json");
+        Self {
+            workspace: workspaces.into_iter().next().unwrap(),
+            client: clients.into_iter().next().unwrap(),
+            projects: recorded("0005-get-api_v9_me_projects.json"),
+        }
+    }
+
+    fn invoice(
+        &self,
+        entries: &[TimeEntry],
+        (period_start, period_end): (NaiveDate, NaiveDate),
+        timezone: Tz,
+        fixed_fees_billed: &BTreeSet<i64>,
+    ) -> Invoice {
+        Invoice::build(
+            String::from("INV-00001"),
+            period_end,
+            InvoiceInput {
+                workspace: &self.workspace,
+                client: &self.client,
+                projects: &self.projects,
+                project_users: &[],
+                workspace_users: &[],
+                tasks: &[],
+                time_entries: entries,
+                period_start,
+                period_end,
+                timezone,
+                fixed_fees_billed,
+            },
+        )
+    }
+}
+
+/// The recorded finished entry, moved to `start`
+fn entry(start: &str, minutes: i64, description: &str) -> TimeEntry {
+    let mut entry = recorded_entry(1);
+    entry.start = start.parse().unwrap();
+    entry.duration = Duration::minutes(minutes);
+    entry.stop = Some(entry.start + entry.duration);
+    entry.description = Some(description.to_string());
+    entry
+}
+
+fn month(year: i32, month: u32) -> (NaiveDate, NaiveDate) {
+    let start = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
+    let end = start
+        .checked_add_months(chrono::Months::new(1))
+        .unwrap()
+        .pred_opt()
+        .unwrap();
+    (start, end)
+}
+
+fn numbering_path(name: &str) -> PathBuf {
+    let dir =
+        std::env::temp_dir().join(format!("togglrust-invoice-{}-{}", name, std::process::id()));
+    std::fs::remove_dir_all(&dir).ok();
+    dir.join("numbering.json")
+}
+
+#[test]
+fn groups_entries_by_description() {
+    let data = Data::load();
+    let entries = [
+        entry("2024-02-05T09:00:00Z", 50, "Design"),
+        entry("2024-02-06T09:00:00Z", 60, "Design"),
+        entry("2024-02-07T09:00:00Z", 30, "Review"),
+        entry("2024-03-01T09:00:00Z", 60, "Design"),
+    ];
+    let invoice = data.invoice(&entries, month(2024, 2), Tz::UTC, &BTreeSet::new());
+    let items: Vec<_> = invoice
+        .line_items
+        .iter()
+        .map(|item| {
+            (
+                item.description.as_str(),
+                item.hours,
+                item.rate,
+                item.amount,
+            )
+        })
+        .collect();
+    assert_eq!(
+        items,
+        [
+            ("Design", 2.0, Some(95.0), 190.0),
+            ("Review", 0.5, Some(95.0), 47.5),
+        ]
+    );
+    assert_eq!(invoice.totals.get("EUR"), Some(&237.5));
+    assert_eq!(invoice.fixed_fee_projects().count(), 0);
+}
+
+#[test]
+fn periods_use_local_dates() {
+    let data = Data::load();
+    // 00:30 on March 1st in Berlin
+    let entries = [entry("2024-02-29T23:30:00Z", 60, "Late night")];
+
+    let invoice = data.invoice(
+        &entries,
+        month(2024, 3),
+        Tz::Europe__Berlin,
+        &BTreeSet::new(),
+    );
+    assert_eq!(invoice.line_items.len(), 1);
+    let invoice = data.invoice(
+        &entries,
+        month(2024, 2),
+        Tz::Europe__Berlin,
+        &BTreeSet::new(),
+    );
+    assert!(invoice.line_items.is_empty());
+
+    let invoice = data.invoice(&entries, month(2024, 2), Tz::UTC, &BTreeSet::new());
+    assert_eq!(invoice.line_items.len(), 1);
+}
+
+#[test]
+fn fixed_fees_are_billed_once() {
+    let mut data = Data::load();
+    data.projects[0].fixed_fee = Some(1200.0);
+    let path = numbering_path("fixed-fee");
+    let mut numbering = InvoiceNumbering::open(&path, "INV-").unwrap();
+
+    let january = [
+        entry("2024-01-08T09:00:00Z", 60, "Design"),
+        entry("2024-01-09T09:00:00Z", 90, "Review"),
+    ];
+    let invoice = data.invoice(
+        &january,
+        month(2024, 1),
+        Tz::UTC,
+        numbering.fixed_fees_billed(),
+    );
+    let [item] = &invoice.line_items[..] else {
+        panic!("expected a single fixed fee item");
+    };
+    assert_eq!((item.description.as_str(), item.hours), ("Fixed fee", 2.5));
+    assert_eq!((item.rate, item.amount), (None, 1200.0));
+    assert_eq!(
+        invoice.fixed_fee_projects().collect::<Vec<_>>(),
+        [PROJECT_ID]
+    );
+    numbering.record_fixed_fees(&invoice).unwrap();
+
+    let numbering = InvoiceNumbering::open(&path, "INV-").unwrap();
+    let february = [entry("2024-02-05T09:00:00Z", 60, "Fixes")];
+    let invoice = data.invoice(
+        &february,
+        month(2024, 2),
+        Tz::UTC,
+        numbering.fixed_fees_billed(),
+    );
+    let [item] = &invoice.line_items[..] else {
+        panic!("expected a single fixed fee item");
+    };
+    assert_eq!(
+        (item.description.as_str(), item.hours, item.amount),
+        ("Fixed fee, invoiced before", 1.0, 0.0)
+    );
+    assert_eq!(invoice.totals.get("EUR"), Some(&0.0));
+    assert_eq!(invoice.fixed_fee_projects().count(), 0);
+}
+
+#[test]
+fn numbering_keeps_its_prefix() {
+    let path = numbering_path("prefix");
+    let mut numbering = InvoiceNumbering::open(&path, "INV-").unwrap();
+    assert_eq!(numbering.next_number().unwrap(), "INV-00001");
+    assert_eq!(numbering.next_number().unwrap(), "INV-00002");
+
+    let mut numbering = InvoiceNumbering::open(&path, "INV-").unwrap();
+    assert_eq!(numbering.next_number().unwrap(), "INV-00003");
+    assert!(!path.with_extension("json.tmp").exists());
+
+    let err = InvoiceNumbering::open(&path, "ACME-").err().unwrap();
+    assert!(
+        matches!(&err, Error::PrefixMismatch { expected, found } if expected == "ACME-" && found == "INV-"),
+        "{}",
+        err
+    );
+}
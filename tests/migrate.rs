@@ -0,0 +1,35 @@
+use chrono::NaiveTime;
+use chrono_tz::Tz;
+use togglrust::migrate::{clockify, harvest, Error};
+
+fn harvest(hours: &str) -> Result<(), Error> {
+    let csv = format!("Date,Hours,Notes\n2024-02-05,{},Design\n", hours);
+    let day_start = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+    harvest::parse(csv.as_bytes(), Tz::UTC, day_start).map(|_| ())
+}
+
+#[test]
+fn rejects_unusable_harvest_hours() {
+    for hours in ["-1.5", "inf", "NaN", "1e20", "1e12"] {
+        let result = harvest(hours);
+        assert!(
+            matches!(result, Err(Error::Parse { line: 2, .. })),
+            "{}: {:?}",
+            hours,
+            result
+        );
+    }
+    assert!(harvest("1.5").is_ok());
+}
+
+#[test]
+fn rejects_clockify_entries_that_end_before_they_start() {
+    let csv = "Start Date,Start Time,End Date,End Time,Description\n\
+               02/05/2024,10:00,02/05/2024,09:00,Design\n";
+    let result = clockify::parse(csv.as_bytes(), Tz::UTC);
+    assert!(
+        matches!(result, Err(Error::Parse { line: 2, .. })),
+        "{:?}",
+        result
+    );
+}
@@ -0,0 +1,210 @@
+mod common;
+
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use common::{recorded, recorded_entry};
+use togglrust::endpoints::post_time_entries;
+use togglrust::models::{Project, TimeEntry};
+use togglrust::plaintext::{org, timeclock, Error, ImportTarget};
+
+const WORKSPACE_ID: i64 = 4567890;
+const PROJECT_ID: i64 = 198765432;
+
+fn projects() -> Vec<Project> {
+    recorded("0005-get-api_v9_me_projects.json")
+}
+
+fn utc(value: &str) -> DateTime<Utc> {
+    value.parse().unwrap()
+}
+
+/// A finished entry based on the recorded running one
+fn entry(start: &str, minutes: i64, description: Option<&str>, tags: &[&str]) -> TimeEntry {
+    let mut entry = recorded_entry(0);
+    entry.start = utc(start);
+    entry.duration = Duration::minutes(minutes);
+    entry.stop = Some(entry.start + entry.duration);
+    entry.description = description.map(str::to_string);
+    entry.tags = tags.iter().map(|tag| tag.to_string()).collect();
+    entry
+}
+
+fn entries() -> Vec<TimeEntry> {
+    let mut unassigned = entry("2024-02-05T16:00:00Z", 30, None, &[]);
+    unassigned.project_id = None;
+    unassigned.billable = false;
+    // Created with only a duration, so Toggl doesn't return a stop time
+    let mut duration_only = entry("2024-02-06T13:15:00Z", 45, Some("Review"), &[]);
+    duration_only.stop = None;
+    vec![
+        entry(
+            "2024-02-05T08:00:00Z",
+            90,
+            Some("Landing page"),
+            &["design", "urgent"],
+        ),
+        entry("2024-02-06T09:30:00Z", 60, Some("Landing page"), &[]),
+        unassigned,
+        duration_only,
+    ]
+}
+
+fn target(projects: &[Project]) -> ImportTarget<'_> {
+    ImportTarget {
+        workspace_id: WORKSPACE_ID,
+        timezone: Tz::Europe__Berlin,
+        projects,
+    }
+}
+
+type Summary = (
+    DateTime<Utc>,
+    Option<DateTime<Utc>>,
+    Option<i64>,
+    Option<String>,
+    Vec<String>,
+    bool,
+);
+
+fn exported(entries: &[TimeEntry]) -> Vec<Summary> {
+    let mut summaries: Vec<Summary> = entries
+        .iter()
+        .map(|entry| {
+            (
+                entry.start,
+                Some(entry.start + entry.duration),
+                entry.project_id,
+                entry.description.clone(),
+                entry.tags.clone(),
+                entry.billable,
+            )
+        })
+        .collect();
+    summaries.sort_by_key(|summary| summary.0);
+    summaries
+}
+
+fn imported(entries: &[post_time_entries::RequestBody]) -> Vec<Summary> {
+    let mut summaries: Vec<Summary> = entries
+        .iter()
+        .map(|body| {
+            assert_eq!(body.workspace_id, WORKSPACE_ID);
+            assert_eq!(body.duration, body.stop.map(|stop| stop - body.start));
+            (
+                body.start,
+                body.stop,
+                body.project_id,
+                body.description.clone(),
+                body.tags.clone().unwrap_or_default(),
+                body.billable.unwrap_or_default(),
+            )
+        })
+        .collect();
+    summaries.sort_by_key(|summary| summary.0);
+    summaries
+}
+
+#[test]
+fn org_round_trip() {
+    let projects = projects();
+    let entries = entries();
+    let text = org::export(&entries, &projects, Tz::Europe__Berlin);
+    assert!(text.contains("** Landing page :design:urgent:\n"));
+    assert!(text.contains("CLOCK: [2024-02-05 Mon 09:00]--[2024-02-05 Mon 10:30] =>  1:30"));
+    let round_trip = org::import(&text, &target(&projects)).unwrap();
+    assert_eq!(imported(&round_trip), exported(&entries));
+}
+
+#[test]
+fn timeclock_round_trip() {
+    let projects = projects();
+    let entries = entries();
+    let text = timeclock::export(&entries, &projects, Tz::Europe__Berlin);
+    assert!(text.starts_with(
+        "i 2024/02/05 09:00:00 Website relaunch  Landing page  ; design:, urgent:, billable:\n\
+         o 2024/02/05 10:30:00\n"
+    ));
+    let round_trip = timeclock::import(&text, &target(&projects)).unwrap();
+    assert_eq!(imported(&round_trip), exported(&entries));
+}
+
+#[test]
+fn running_entries_are_left_open() {
+    let projects = projects();
+    let mut running = entry("2024-02-05T09:00:00Z", 0, Some("Standup"), &[]);
+    running.stop = None;
+    running.duration = Duration::seconds(-running.start.timestamp());
+    let entries = vec![
+        running,
+        entry("2024-02-05T10:00:00Z", 15, Some("Email"), &[]),
+    ];
+
+    let text = timeclock::export(&entries, &projects, Tz::UTC);
+    assert!(text.ends_with("i 2024/02/05 09:00:00 Website relaunch  Standup  ; billable:\n"));
+    let imported = timeclock::import(&text, &target(&projects)).unwrap();
+    assert_eq!(imported.len(), 1);
+    assert_eq!(imported[0].description.as_deref(), Some("Email"));
+
+    let text = org::export(&entries, &projects, Tz::UTC);
+    assert!(text.contains("   CLOCK: [2024-02-05 Mon 09:00]\n"));
+    let imported = org::import(&text, &target(&projects)).unwrap();
+    assert_eq!(imported.len(), 1);
+    assert_eq!(imported[0].description.as_deref(), Some("Email"));
+}
+
+#[test]
+fn multi_line_descriptions_stay_in_the_heading() {
+    let projects = projects();
+    let entries = vec![entry(
+        "2024-02-05T08:00:00Z",
+        30,
+        Some("Landing page\n\n* copy review"),
+        &[],
+    )];
+    let text = org::export(&entries, &projects, Tz::UTC);
+    assert!(text.contains("** Landing page * copy review\n"));
+    let imported = org::import(&text, &target(&projects)).unwrap();
+    assert_eq!(imported.len(), 1);
+    assert_eq!(imported[0].project_id, Some(PROJECT_ID));
+    assert_eq!(
+        imported[0].description.as_deref(),
+        Some("Landing page * copy review")
+    );
+}
+
+#[test]
+fn rejects_clocks_that_stop_before_they_start() {
+    let projects = projects();
+    let text = "* Website relaunch\n\
+                ** Landing page\n   \
+                :LOGBOOK:\n   \
+                CLOCK: [2024-02-05 Mon 10:00]--[2024-02-05 Mon 09:00] => -1:00\n   \
+                :END:\n";
+    let err = org::import(text, &target(&projects)).unwrap_err();
+    assert!(matches!(err, Error::Parse { line: 4, .. }), "{}", err);
+
+    let text = "i 2024/02/05 10:00:00 Website relaunch  Landing page\n\
+                o 2024/02/05 09:00:00\n";
+    let err = timeclock::import(text, &target(&projects)).unwrap_err();
+    assert!(matches!(err, Error::Parse { line: 2, .. }), "{}", err);
+}
+
+#[test]
+fn org_placeholders_dont_clash_with_real_names() {
+    let mut projects = projects();
+    let mut lookalike = projects[0].clone();
+    lookalike.id = 198765433;
+    lookalike.name = String::from("No project");
+    projects.push(lookalike);
+    let mut in_lookalike = entry("2024-02-05T08:00:00Z", 30, Some("(no description)"), &[]);
+    in_lookalike.project_id = Some(198765433);
+    let mut unassigned = entry("2024-02-05T09:00:00Z", 30, None, &[]);
+    unassigned.project_id = None;
+    let entries = vec![in_lookalike, unassigned];
+
+    let text = org::export(&entries, &projects, Tz::Europe__Berlin);
+    assert!(text.contains(":NO_PROJECT: t\n"));
+    assert!(text.contains(":NO_DESCRIPTION: t\n"));
+    let round_trip = org::import(&text, &target(&projects)).unwrap();
+    assert_eq!(imported(&round_trip), exported(&entries));
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use chrono::{Duration, NaiveDate, TimeZone, Utc};
+use togglrust::client::{Client, Error};
+use togglrust::endpoints::{get_workspace_users, post_track_reminders};
+use togglrust::fixtures::Replayer;
+use togglrust::reminders::due_reminders;
+use togglrust::validation::ReminderViolation;
+
+#[tokio::test]
+async fn predicts_who_is_reminded_today() {
+    let client = common::replaying_client();
+    let mut reminders = client.get_track_reminders().await.unwrap().0;
+    let entries = client.get_time_entries(Default::default()).await.unwrap().0;
+    let mut users = client
+        .get_workspace_users(get_workspace_users::PathParams {
+            workspace_id: 4567890,
+        })
+        .await
+        .unwrap()
+        .0;
+    // Named users only get reminders while they are active members of the workspace
+    let mut me = users[0].clone();
+    me.uid = 7654321;
+    me.group_ids.clear();
+    let mut inactive = users[0].clone();
+    inactive.uid = 8765432;
+    inactive.inactive = true;
+    users.extend([me, inactive]);
+    reminders[0].user_ids.extend([8765432, 9999999]);
+
+    let mut weekly = reminders[0].clone();
+    weekly.reminder_id = 55502;
+    weekly.frequency = 7;
+    weekly.threshold = 4;
+    weekly.group_ids = vec![7001];
+    let mut everyone = reminders[0].clone();
+    everyone.reminder_id = 55503;
+    everyone.threshold = 2;
+    everyone.user_ids.clear();
+    reminders.extend([weekly, everyone]);
+
+    let today = NaiveDate::from_ymd_opt(2024, 2, 6).unwrap();
+    let now = Utc.with_ymd_and_hms(2024, 2, 6, 11, 30, 0).unwrap();
+    let reminded = due_reminders(
+        &reminders,
+        &entries,
+        &users,
+        today,
+        chrono_tz::Europe::Berlin,
+        now,
+    );
+    let who: Vec<_> = reminded
+        .iter()
+        .map(|reminded| {
+            (
+                reminded.reminder_id,
+                reminded.user_id,
+                reminded.group_ids.clone(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        who,
+        vec![
+            (55501, 7654321, vec![]),
+            (55502, 1234567, vec![7001]),
+            (55502, 7654321, vec![]),
+            (55503, 1234567, vec![]),
+            (55503, 2345678, vec![]),
+        ]
+    );
+    // The running entry counts up to now, the weekly reminder also sees yesterday's hour
+    assert_eq!(reminded[0].tracked, Duration::minutes(150));
+    assert_eq!(reminded[2].tracked, Duration::minutes(210));
+}
+
+#[tokio::test]
+async fn rejects_invalid_reminders_before_sending() {
+    let client = Client::replaying(Replayer::new(Vec::new()));
+    let body = post_track_reminders::RequestBody {
+        frequency: 3,
+        threshold: 0,
+        group_ids: Vec::new(),
+        user_ids: vec![7654321],
+    };
+    let params = post_track_reminders::PathParams {
+        workspace_id: 4567890,
+    };
+    let err = client.post_track_reminders(params, body).await.unwrap_err();
+    let Error::InvalidTrackReminder(violations) = &err else {
+        panic!("expected an invalid reminder, got {}", err);
+    };
+    assert_eq!(
+        violations,
+        &vec![
+            ReminderViolation::InvalidFrequency(3),
+            ReminderViolation::InvalidThreshold(0),
+        ]
+    );
+    assert_eq!(err.status(), None);
+}
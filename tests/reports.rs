@@ -0,0 +1,106 @@
+mod common;
+
+use chrono::{Duration, NaiveDate};
+use togglrust::client::Error;
+use togglrust::endpoints::{get_saved_reports, post_reports_search_time_entries};
+use togglrust::models::{ExportFormat, ReportFilters, ReportKind};
+
+#[tokio::test]
+async fn saved_report_filters_run_as_ad_hoc_reports() {
+    let client = common::replaying_client();
+    let saved = client
+        .get_saved_reports(get_saved_reports::PathParams {
+            workspace_id: 4567890,
+        })
+        .await
+        .unwrap()
+        .0;
+
+    // Stored as a JSON string
+    let filters = &saved[0].params;
+    assert_eq!(filters.start_date, NaiveDate::from_ymd_opt(2024, 1, 1));
+    assert_eq!(filters.project_ids, Some(vec![198765432]));
+    assert_eq!(filters.billable, Some(true));
+    // Stored as an object
+    assert_eq!(saved[1].params.user_ids, Some(vec![1234567, 2345678]));
+    assert_eq!(saved[1].params.project_ids, None);
+
+    assert_eq!(filters.rounding, Some(1));
+    assert!(serde_json::to_value(filters)
+        .unwrap()
+        .get("description")
+        .is_none());
+
+    // Filters without a field of their own are sent back as they were saved
+    let mut newer = serde_json::to_value(filters).unwrap();
+    newer["hide_amounts"] = true.into();
+    let newer: ReportFilters = serde_json::from_value(newer).unwrap();
+    assert_eq!(serde_json::to_value(&newer).unwrap()["hide_amounts"], true);
+
+    let rows = client
+        .post_reports_search_time_entries(
+            post_reports_search_time_entries::PathParams {
+                workspace_id: saved[0].workspace_id,
+            },
+            filters.clone(),
+        )
+        .await
+        .unwrap()
+        .0;
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].billable_amount_in_cents, Some(14250));
+    assert_eq!(rows[0].time_entries[0].seconds, Duration::minutes(90));
+}
+
+#[tokio::test]
+async fn exports_write_the_file_as_downloaded() {
+    let client = common::replaying_client();
+    let filters = ReportFilters {
+        start_date: NaiveDate::from_ymd_opt(2024, 1, 1),
+        end_date: NaiveDate::from_ymd_opt(2024, 1, 31),
+        ..Default::default()
+    };
+
+    let mut file = Vec::new();
+    let size = client
+        .export_report(
+            4567890,
+            ReportKind::Detailed,
+            ExportFormat::Csv,
+            &filters,
+            &mut file,
+        )
+        .await
+        .unwrap();
+    assert_eq!(size, file.len() as u64);
+    let csv = String::from_utf8(file).unwrap();
+    assert!(csv.starts_with("User,Email,Client,Project"), "{}", csv);
+    assert!(csv.ends_with("142.50\n"), "{}", csv);
+
+    let mut file = Vec::new();
+    let size = client
+        .export_report(
+            4567890,
+            ReportKind::Weekly,
+            ExportFormat::Pdf,
+            &filters,
+            &mut file,
+        )
+        .await
+        .unwrap();
+    assert_eq!(size, file.len() as u64);
+    assert!(file.starts_with(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n"));
+    assert!(file.ends_with(b"%%EOF\n"));
+
+    let err = client
+        .export_report(
+            4567890,
+            ReportKind::Summary,
+            ExportFormat::Pdf,
+            &filters,
+            &mut Vec::new(),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::MissingFixture { .. }));
+}
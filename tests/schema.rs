@@ -0,0 +1,128 @@
+#![cfg(feature = "schema")]
+
+use serde_json::json;
+use togglrust::schema::{compare, Difference, Kind, Spec};
+
+fn task_schema() -> serde_json::Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "active": {"type": "boolean"},
+            "at": {"type": "string"},
+            "estimated_seconds": {"type": "integer"},
+            "id": {"type": "integer"},
+            "integration_ext_id": {"type": "string"},
+            "name": {"type": "string", "x-nullable": true, "nullable": true},
+            "project_id": {"type": "string"},
+            "recurring": {"type": "boolean"},
+            "server_deleted_at": {"type": "string"},
+            "tracked_seconds": {"type": "integer"},
+            "user_id": {"type": "integer"},
+            "workspace_id": {"type": "integer"}
+        }
+    })
+}
+
+fn task_differences() -> Vec<Difference> {
+    vec![
+        Difference::MissingField {
+            model: String::from("Task"),
+            field: String::from("integration_ext_id"),
+        },
+        Difference::NotNullable {
+            location: String::from("Task.name"),
+        },
+        Difference::TypeMismatch {
+            location: String::from("Task.project_id"),
+            spec: Kind::String,
+            found: Kind::Integer,
+        },
+    ]
+}
+
+#[test]
+fn compares_swagger_documents() {
+    let spec = Spec::from_value(json!({
+        "swagger": "2.0",
+        "basePath": "/api/v9",
+        "paths": {
+            "/me/tasks": {
+                "get": {
+                    "responses": {
+                        "200": {
+                            "schema": {"type": "array", "items": {"$ref": "#/definitions/models.Task"}}
+                        }
+                    }
+                }
+            },
+            "/workspaces/{wid}/time_entries/{time_entry_id}/stop": {
+                "patch": {"responses": {"200": {"schema": {"type": "object"}}}}
+            }
+        },
+        "definitions": {"models.Task": task_schema()}
+    }))
+    .unwrap();
+
+    let differences = compare(&spec).unwrap();
+    for difference in task_differences() {
+        assert!(differences.contains(&difference), "{}", difference);
+    }
+    assert!(differences.contains(&Difference::MissingEndpoint {
+        method: String::from("PATCH"),
+        path: String::from("/api/v9/workspaces/{wid}/time_entries/{time_entry_id}/stop"),
+    }));
+    // Not in this spec, but placeholders are matched regardless of their names
+    assert!(differences.contains(&Difference::UnknownEndpoint {
+        method: String::from("POST"),
+        path: String::from("/api/v9/workspaces/{workspace_id}/time_entries"),
+    }));
+    assert!(!differences.iter().any(|difference| matches!(
+        difference,
+        Difference::UnknownEndpoint { path, .. } if path.starts_with("/webhooks")
+    )));
+    assert_eq!(
+        differences
+            .iter()
+            .filter(|difference| !matches!(difference, Difference::UnknownEndpoint { .. }))
+            .count(),
+        4
+    );
+}
+
+#[test]
+fn compares_openapi_documents() {
+    let spec = Spec::from_value(json!({
+        "openapi": "3.0.1",
+        "servers": [{"url": "https://api.track.toggl.com/api/v9"}],
+        "paths": {
+            "/me/tasks": {
+                "get": {
+                    "responses": {
+                        "200": {
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "type": "array",
+                                        "items": {"$ref": "#/components/schemas/models.Task"}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+        "components": {"schemas": {"models.Task": task_schema()}}
+    }))
+    .unwrap();
+
+    let differences = compare(&spec).unwrap();
+    for difference in task_differences() {
+        assert!(differences.contains(&difference), "{}", difference);
+    }
+}
+
+#[test]
+fn rejects_other_documents() {
+    assert!(Spec::from_value(json!({"paths": {}})).is_err());
+}
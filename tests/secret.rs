@@ -0,0 +1,38 @@
+mod common;
+
+use togglrust::client::Auth;
+use togglrust::models::Workspace;
+use togglrust::secret::Secret;
+
+const TOKEN: &str = "1971800d4d82861d8f2c1651fea4d212";
+
+#[test]
+fn prints_redacted() {
+    let secret = Secret::from(TOKEN);
+    assert_eq!(secret.expose(), TOKEN);
+    assert_eq!(format!("{}", secret), "[REDACTED]");
+    assert_eq!(format!("{:?}", Some(&secret)), "Some([REDACTED])");
+
+    let auth = Auth {
+        user: Secret::from(TOKEN),
+        password: Secret::from("api_token"),
+    };
+    let debug = format!("{:?}", auth);
+    assert!(!debug.contains(TOKEN), "{}", debug);
+}
+
+#[test]
+fn models_round_trip_but_print_redacted() {
+    let mut json = common::fixture_body("0011-get-api_v9_me_workspaces.json")[0].clone();
+    json["api_token"] = TOKEN.into();
+
+    let workspace: Workspace = serde_json::from_value(json).unwrap();
+    assert_eq!(workspace.api_token.as_ref().unwrap().expose(), TOKEN);
+    let debug = format!("{:#?}", workspace);
+    assert!(!debug.contains(TOKEN), "{}", debug);
+    assert!(debug.contains("[REDACTED]"), "{}", debug);
+    assert_eq!(
+        serde_json::to_value(&workspace).unwrap()["api_token"],
+        TOKEN
+    );
+}
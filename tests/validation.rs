@@ -0,0 +1,144 @@
+mod common;
+
+use chrono::{TimeZone, Utc};
+use common::recorded;
+use togglrust::client::{Client, Error};
+use togglrust::endpoints::post_time_entries;
+use togglrust::fixtures::Replayer;
+use togglrust::models::{Project, TeConstraints, Workspace};
+use togglrust::validation::{validate_time_entry, Violation};
+
+const WORKSPACE_ID: i64 = 4567890;
+const PROJECT_ID: i64 = 198765432;
+
+/// Requires a description and a project, like the recorded workspace
+fn workspace() -> Workspace {
+    let workspaces: Vec<Workspace> = recorded("0011-get-api_v9_me_workspaces.json");
+    workspaces.into_iter().next().unwrap()
+}
+
+fn projects() -> Vec<Project> {
+    recorded("0005-get-api_v9_me_projects.json")
+}
+
+fn constraints(workspace: &mut Workspace) -> &mut TeConstraints {
+    workspace.te_constraints.as_mut().unwrap()
+}
+
+fn entry() -> post_time_entries::RequestBody {
+    let start = Utc.with_ymd_and_hms(2024, 2, 5, 9, 0, 0).unwrap();
+    let mut entry = post_time_entries::RequestBody::new(WORKSPACE_ID, start, "tests");
+    entry.description = Some(String::from("Landing page"));
+    entry.project_id = Some(PROJECT_ID);
+    entry
+}
+
+#[test]
+fn accepts_entries_meeting_the_constraints() {
+    assert_eq!(validate_time_entry(&entry(), &workspace(), &projects()), []);
+}
+
+#[test]
+fn reports_missing_fields() {
+    let mut workspace = workspace();
+    constraints(&mut workspace).tag_present = true;
+    constraints(&mut workspace).task_present = true;
+    let mut draft = entry();
+    draft.description = Some(String::from("  "));
+    draft.project_id = None;
+    assert_eq!(
+        validate_time_entry(&draft, &workspace, &projects()),
+        [
+            Violation::MissingDescription,
+            Violation::MissingProject,
+            Violation::MissingTag,
+            Violation::MissingTask,
+        ]
+    );
+
+    draft.description = Some(String::from("Landing page"));
+    draft.project_id = Some(PROJECT_ID);
+    draft.tag_ids = Some(vec![1]);
+    draft.task_id = Some(2);
+    assert_eq!(validate_time_entry(&draft, &workspace, &projects()), []);
+
+    constraints(&mut workspace).time_entry_constraints_enabled = false;
+    let bare = post_time_entries::RequestBody::new(WORKSPACE_ID, Utc::now(), "tests");
+    assert_eq!(validate_time_entry(&bare, &workspace, &projects()), []);
+}
+
+#[test]
+fn reports_the_wrong_workspace() {
+    let mut elsewhere = entry();
+    elsewhere.workspace_id = 1;
+    assert_eq!(
+        validate_time_entry(&elsewhere, &workspace(), &projects()),
+        [Violation::WrongWorkspace {
+            expected: WORKSPACE_ID,
+            actual: 1,
+        },]
+    );
+}
+
+#[test]
+fn reports_unusable_projects() {
+    let mut unknown = entry();
+    unknown.project_id = Some(1);
+    assert_eq!(
+        validate_time_entry(&unknown, &workspace(), &projects()),
+        [Violation::UnknownProject(1)]
+    );
+
+    let mut other_workspace = projects();
+    other_workspace[0].workspace_id = 1;
+    assert_eq!(
+        validate_time_entry(&entry(), &workspace(), &other_workspace),
+        [Violation::UnknownProject(PROJECT_ID)]
+    );
+
+    let mut projects = projects();
+    projects[0].active = false;
+    assert_eq!(
+        validate_time_entry(&entry(), &workspace(), &projects),
+        [Violation::ArchivedProject(PROJECT_ID)]
+    );
+
+    projects[0].server_deleted_at = Some(Utc::now());
+    assert_eq!(
+        validate_time_entry(&entry(), &workspace(), &projects),
+        [Violation::DeletedProject(PROJECT_ID)]
+    );
+}
+
+#[tokio::test]
+async fn client_rejects_invalid_entries_before_sending() {
+    let client = Client::replaying(Replayer::new(Vec::new()));
+    let params = || post_time_entries::PathParams {
+        workspace_id: WORKSPACE_ID,
+    };
+    let mut undescribed = entry();
+    undescribed.description = None;
+    let err = client
+        .post_time_entries_validated(params(), undescribed, &workspace(), &projects())
+        .await
+        .unwrap_err();
+    let Error::InvalidTimeEntry(violations) = &err else {
+        panic!("expected an invalid time entry, got {}", err);
+    };
+    assert_eq!(violations, &[Violation::MissingDescription]);
+
+    let mut elsewhere = entry();
+    elsewhere.workspace_id = 1;
+    let err = client
+        .post_time_entries(params(), elsewhere)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::InvalidTimeEntry(_)), "{}", err);
+
+    // Valid entries are sent, and there's nothing to answer them
+    let err = client
+        .post_time_entries_validated(params(), entry(), &workspace(), &projects())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::MissingFixture { .. }), "{}", err);
+}
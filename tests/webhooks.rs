@@ -0,0 +1,186 @@
+mod common;
+
+use hmac::{Hmac, Mac};
+use reqwest::StatusCode;
+use sha2::Sha256;
+use togglrust::webhooks::{verify_signature, Delivery, Error, Payload, Receiver};
+
+const SECRET: &str = "webhook-secret";
+
+fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+fn ping() -> Vec<u8> {
+    serde_json::json!({
+        "event_id": 0,
+        "created_at": "2024-02-05T09:00:00Z",
+        "creator_id": 7654321,
+        "metadata": null,
+        "payload": "ping",
+        "subscription_id": 42,
+        "validation_code": "8c4d6b0e",
+    })
+    .to_string()
+    .into_bytes()
+}
+
+/// A `time_entry` event carrying the recorded finished entry
+fn event() -> Vec<u8> {
+    let entry = common::fixture_body("0008-get-api_v9_me_time_entries.json")[1].clone();
+    serde_json::json!({
+        "event_id": 1,
+        "created_at": "2024-02-05T17:31:02Z",
+        "creator_id": 7654321,
+        "metadata": { "action": "updated", "model": "time_entry", "path": "/time_entries" },
+        "payload": entry,
+        "subscription_id": 42,
+        "validation_code": null,
+    })
+    .to_string()
+    .into_bytes()
+}
+
+#[test]
+fn verifies_signatures() {
+    let body = event();
+    let signature = sign(SECRET, &body);
+    assert!(verify_signature(SECRET, &body, &signature));
+    assert!(verify_signature(SECRET, &body, &format!(" {} ", signature)));
+
+    assert!(!verify_signature("other-secret", &body, &signature));
+    let mut tampered = body.clone();
+    tampered.push(b' ');
+    assert!(!verify_signature(SECRET, &tampered, &signature));
+    assert!(!verify_signature(
+        SECRET,
+        &body,
+        signature.trim_start_matches("sha256=")
+    ));
+    assert!(!verify_signature(SECRET, &body, "sha256=not-hex"));
+}
+
+#[test]
+fn rejects_unsigned_requests() {
+    let receiver = Receiver::new(SECRET);
+    let body = event();
+
+    let result = receiver.receive(None, &body);
+    assert!(matches!(result, Err(Error::MissingSignature)));
+    assert_eq!(Receiver::response(&result).0, StatusCode::UNAUTHORIZED);
+
+    let result = receiver.receive(Some(&sign("other-secret", &body)), &body);
+    assert!(matches!(result, Err(Error::InvalidSignature)));
+    assert_eq!(Receiver::response(&result).0, StatusCode::UNAUTHORIZED);
+
+    let garbage = b"not json";
+    let result = receiver.receive(Some(&sign(SECRET, garbage)), garbage);
+    assert!(matches!(result, Err(Error::Json(_))));
+    assert_eq!(Receiver::response(&result).0, StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn answers_pings_with_the_validation_code() {
+    let receiver = Receiver::new(SECRET);
+    let body = ping();
+    let result = receiver.receive(Some(&sign(SECRET, &body)), &body);
+    assert!(matches!(
+        &result,
+        Ok(Delivery::Ping { validation_code: Some(code) }) if code == "8c4d6b0e"
+    ));
+    let (status, response) = Receiver::response(&result);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&response).unwrap(),
+        serde_json::json!({ "validation_code": "8c4d6b0e" })
+    );
+}
+
+#[test]
+fn decodes_events() {
+    let receiver = Receiver::new(SECRET);
+    let body = event();
+    let Ok(Delivery::Event(event)) = receiver.receive(Some(&sign(SECRET, &body)), &body) else {
+        panic!("expected an event");
+    };
+    assert_eq!((event.event_id, event.subscription_id), (1, 42));
+    assert_eq!(event.metadata.action, "updated");
+    assert_eq!(event.metadata.extra["path"], "/time_entries");
+    let Payload::TimeEntry(entry) = &event.payload else {
+        panic!("expected a time entry, got {:?}", event.payload);
+    };
+    assert_eq!(entry.id, 3312345678);
+}
+
+#[cfg(feature = "webhooks")]
+#[tokio::test]
+async fn serves_webhook_requests() {
+    use togglrust::webhooks::MAX_BODY_SIZE;
+
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let (events, mut received) = tokio::sync::mpsc::unbounded_channel();
+    let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+    // Handlers only get to run once the test has seen the response
+    let gate = std::sync::Arc::new(tokio::sync::Semaphore::new(0));
+    let handler_gate = gate.clone();
+    let server = tokio::spawn(Receiver::new(SECRET).serve(
+        addr,
+        move |event| {
+            let events = events.clone();
+            let gate = handler_gate.clone();
+            async move {
+                gate.acquire().await.unwrap().forget();
+                events.send(event).unwrap();
+            }
+        },
+        async {
+            stopped.await.ok();
+        },
+    ));
+
+    let client = reqwest::Client::new();
+    let url = format!("http://{}/", addr);
+    let post = |body: Vec<u8>| {
+        client
+            .post(&url)
+            .header(togglrust::webhooks::SIGNATURE_HEADER, sign(SECRET, &body))
+            .body(body)
+    };
+    // The server may still be binding
+    let mut response = None;
+    for _ in 0..50 {
+        match post(ping()).send().await {
+            Ok(ok) => {
+                response = Some(ok);
+                break;
+            }
+            Err(_) => tokio::time::sleep(std::time::Duration::from_millis(20)).await,
+        }
+    }
+    let response = response.expect("server didn't start");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.json::<serde_json::Value>().await.unwrap(),
+        serde_json::json!({ "validation_code": "8c4d6b0e" })
+    );
+
+    let response = post(event()).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    gate.add_permits(1);
+    assert_eq!(received.recv().await.unwrap().event_id, 1);
+
+    let response = post(vec![b' '; MAX_BODY_SIZE + 1]).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+    let response = client.get(&url).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+
+    stop.send(()).unwrap();
+    server.await.unwrap().unwrap();
+    assert!(received.try_recv().is_err());
+}